@@ -0,0 +1,85 @@
+//! Compares the allocation-free [`FromStr`] impl of [`Amount`] against the previous
+//! implementation, which collected digits into [`Vec`]s and went through a [`String`] before
+//! handing off to [`FromStr`] on the backing type.
+
+use core::str::FromStr;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use currencies_core::{currency::*, Amount, Currency};
+use quoth::{ParseStream, Span};
+
+/// The `quoth`-based parser that preceded the current implementation, kept as a baseline.
+fn legacy_parse<C: Currency>(s: &str) -> quoth::Result<Amount<C>> {
+    let mut stream = ParseStream::from(s);
+    let stream = &mut stream;
+    if C::STYLE == FormatStyle::PrefixAttached || C::STYLE == FormatStyle::PrefixSpaced {
+        let _symbol = stream.parse_istr(C::SYMBOL)?;
+    }
+    if C::STYLE == FormatStyle::PrefixSpaced {
+        let _space = stream.parse_str(" ")?;
+    }
+    let whole_start_position = stream.position;
+    let mut whole_digits = Vec::new();
+    loop {
+        if stream.next_digit().is_ok() {
+            whole_digits.push(stream.parse_digit()?);
+        } else if !whole_digits.is_empty() && stream.next_char() == Ok(',') {
+            stream.consume(1)?;
+            whole_digits.push(stream.parse_digit()?);
+        } else {
+            break;
+        }
+    }
+    let _dot = stream.parse_str(".")?;
+    let mut decimal_digits = Vec::new();
+    let decimal_start_position = stream.position;
+    while stream.next_digit().is_ok() {
+        decimal_digits.push(stream.parse_digit()?);
+    }
+    if decimal_digits.len() > C::decimal_digits() {
+        return Err(quoth::Error::new(
+            Span::new(stream.source().clone(), decimal_start_position..stream.position),
+            "too many decimal digits",
+        ));
+    }
+    while decimal_digits.len() < C::decimal_digits() {
+        decimal_digits.push(0);
+    }
+    let dec_end_position = stream.position;
+    let raw = format!(
+        "{}{}",
+        whole_digits.into_iter().map(|d| d.to_string()).collect::<String>(),
+        decimal_digits.into_iter().map(|d| d.to_string()).collect::<String>()
+    )
+    .replace(",", "");
+    let backing = C::Backing::from_str(&raw).map_err(|_| {
+        quoth::Error::new(
+            Span::new(stream.source().clone(), whole_start_position..dec_end_position),
+            "invalid amount",
+        )
+    })?;
+    if C::STYLE == FormatStyle::SuffixSpaced {
+        let _space = stream.parse_str(" ")?;
+    }
+    if C::STYLE == FormatStyle::SuffixAttached || C::STYLE == FormatStyle::SuffixSpaced {
+        let _symbol = stream.parse_istr(C::SYMBOL)?;
+    }
+    Ok(Amount::from_raw(backing))
+}
+
+fn bench_currency<C: Currency>(c: &mut Criterion, input: &str) {
+    assert_eq!(legacy_parse::<C>(input).unwrap(), input.parse::<Amount<C>>().unwrap());
+    let mut group = c.benchmark_group(C::CODE);
+    group.bench_function("from_str", |b| b.iter(|| black_box(input).parse::<Amount<C>>()));
+    group.bench_function("legacy", |b| b.iter(|| legacy_parse::<C>(black_box(input))));
+    group.finish();
+}
+
+fn parsing(c: &mut Criterion) {
+    bench_currency::<USD>(c, "$2,748,972.98");
+    bench_currency::<DOT>(c, "57622449841.0000000004 DOT");
+    bench_currency::<ETH>(c, "123456789012.345678901234567890 ETH");
+}
+
+criterion_group!(benches, parsing);
+criterion_main!(benches);
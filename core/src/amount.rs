@@ -6,19 +6,20 @@ use core::{
     str::FromStr,
 };
 use num_integer::Integer;
-use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, Num, One, PrimInt, ToPrimitive, Unsigned, Zero};
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, FromPrimitive, Num, One, PrimInt, ToPrimitive, Zero};
 
 use crate::currency::*;
+use crate::digits::{DigitSystem, DigitWriter, WithDigits};
 use crate::safety::{self, *};
 
 /// Automatically implemented on types capable of being used as the "base" / backing type for
 /// an [`Amount`] of [`Currency`].
 ///
-/// Must be [`Unsigned`] and implement common [`num_traits`] and basic [`core::ops`] traits
-/// (see bounds).
+/// Must implement common [`num_traits`] and basic [`core::ops`] traits (see bounds). Both
+/// signed and unsigned types are supported, however only [`Amount`]s with a signed backing can
+/// represent negative values.
 pub trait Backing:
     Num
-    + Unsigned
     + Zero
     + One
     + Integer
@@ -26,6 +27,7 @@ pub trait Backing:
     + CheckedSub
     + CheckedDiv
     + CheckedMul
+    + CheckedRem
     + Mul
     + Add
     + Sub
@@ -56,7 +58,6 @@ pub trait Backing:
 
 impl<
         T: Num
-            + Unsigned
             + Zero
             + One
             + Integer
@@ -64,6 +65,7 @@ impl<
             + CheckedSub
             + CheckedDiv
             + CheckedMul
+            + CheckedRem
             + Mul
             + Add
             + Sub
@@ -117,7 +119,7 @@ impl<T: PrimInt> TrailingZeros for T {
 /// situations where panicking is dangerous.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Amount<C: Currency = USD, Safety: safety::Safety = Unchecked>(
-    C::Backing,
+    pub(crate) C::Backing,
     PhantomData<C>,
     PhantomData<Safety>,
     // TODO: eventually we could add Signedness here
@@ -137,47 +139,147 @@ impl<C: Currency, Safety: safety::Safety> Amount<C, Safety> {
     pub fn raw_backing_mut(&mut self) -> &mut C::Backing {
         &mut self.0
     }
+
+    /// Returns a wrapper that displays this [`Amount`] with its digits written in the specified
+    /// [`DigitSystem`], e.g. `$١٠.٥٠` for [`DigitSystem::ArabicIndic`].
+    pub fn with_digits(&self, system: DigitSystem) -> WithDigits<&Self> {
+        WithDigits::new(self, system)
+    }
 }
 
 impl<C: Currency, Safety: safety::Safety> core::fmt::Display for Amount<C, Safety> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let decimals = C::BASE.trailing_zeros() as usize;
+        fmt_raw(f, self.0, C::BASE, C::SYMBOL, C::STYLE, DigitSystem::Ascii)
+    }
+}
 
-        let major = self.0 / C::BASE;
-        let minor = self.0 % C::BASE;
+impl<C: Currency, Safety: safety::Safety> core::fmt::Display for WithDigits<&Amount<C, Safety>> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_raw(f, self.value.0, C::BASE, C::SYMBOL, C::STYLE, self.system)
+    }
+}
 
-        // handle formatting for prefix-style currencies
-        match C::STYLE {
-            FormatStyle::PrefixAttached => write!(f, "{}", C::SYMBOL)?,
-            FormatStyle::PrefixSpaced => write!(f, "{} ", C::SYMBOL)?,
-            _ => (),
-        }
+/// Writes `raw` to `f` as an amount with the given `base`, `symbol` and `style`, writing the
+/// digits of the number (but not those of the symbol) in the given [`DigitSystem`].
+///
+/// This is the formatting logic behind the [`core::fmt::Display`] impl of [`Amount`], exposed
+/// within the crate so that amounts whose [`Currency`] is only known at runtime can be
+/// displayed identically.
+pub(crate) fn fmt_raw<B: Backing>(
+    f: &mut core::fmt::Formatter<'_>,
+    raw: B,
+    base: B,
+    symbol: &str,
+    style: FormatStyle,
+    system: DigitSystem,
+) -> core::fmt::Result {
+    if raw < B::zero() {
+        core::fmt::Write::write_char(f, '-')?;
+    }
 
-        // avoids allocation
-        write!(f, "{}.", major)?;
-
-        // Collect the minor digits into an array, and then print them in reverse order
-        let mut minor_digits = [0u8; 64]; // HACK: max size needed for a U512, increase this to support larger types
-        let mut minor_val = minor;
-        for i in 0..decimals {
-            let digit = minor_val % C::Backing::from(10);
-            minor_digits[decimals - 1 - i] = digit.to_u64().unwrap() as u8;
-            minor_val /= C::Backing::from(10);
+    // handle formatting for prefix-style currencies
+    match style {
+        FormatStyle::PrefixAttached => write!(f, "{}", symbol)?,
+        FormatStyle::PrefixSpaced => write!(f, "{} ", symbol)?,
+        _ => (),
+    }
+
+    fmt_magnitude(&mut DigitWriter { inner: f, system }, raw, base)?;
+
+    // handle formatting for suffix-style currencies
+    match style {
+        FormatStyle::SuffixAttached => write!(f, "{}", symbol)?,
+        FormatStyle::SuffixSpaced => write!(f, " {}", symbol)?,
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// Writes `raw` to `f` as a plain decimal number with the given `base`, such as `-123.45`.
+///
+/// The decimal point is omitted if `base` has no decimal digits.
+#[cfg(feature = "serde")]
+pub(crate) fn fmt_decimal<B: Backing>(f: &mut impl core::fmt::Write, raw: B, base: B) -> core::fmt::Result {
+    if raw < B::zero() {
+        f.write_char('-')?;
+    }
+    fmt_magnitude(f, raw, base)
+}
+
+/// Returns the number of digits after the decimal point of amounts of a currency with the
+/// given `base`, i.e. the smallest `n` such that `base` divides `10^n`.
+pub(crate) fn minor_digits<B: Backing>(base: B) -> usize {
+    minor_scale(base).0
+}
+
+/// Returns [`minor_digits`] of `base` along with `10^n / base`, the number of units in the
+/// last decimal place that each minor unit is worth, e.g. 2 for the fifths of a base of 5.
+///
+/// `base` must divide a power of ten, as checked by [`define_currency!`](crate::define_currency).
+pub(crate) fn minor_scale<B: Backing>(mut base: B) -> (usize, B) {
+    let (two, five, ten) = (B::from(2), B::from(5), B::from(10));
+    let mut digits = 0;
+    let mut factor = B::one();
+    while base > B::one() {
+        // once no factor of ten is left, only twos or only fives remain
+        if (base % ten).is_zero() {
+            base /= ten;
+        } else if (base % five).is_zero() {
+            base /= five;
+            factor *= two;
+        } else {
+            base /= two;
+            factor *= five;
         }
-        for &digit in &minor_digits[..decimals] {
-            // avoids allocation
-            core::fmt::Write::write_char(f, char::from_digit(digit as u32, 10).unwrap())?;
+        digits += 1;
+    }
+    (digits, factor)
+}
+
+/// Writes the digits of the absolute value of `raw` to `f`, with a decimal point before the
+/// minor digits if `base` has any.
+fn fmt_magnitude<B: Backing>(f: &mut impl core::fmt::Write, raw: B, base: B) -> core::fmt::Result {
+    let (decimals, factor) = minor_scale(base);
+    let negative = raw < B::zero();
+
+    let major = raw / base;
+    // the minor units in units of the last decimal place, e.g. 4 for 2 fifths
+    let minor = raw % base * factor;
+
+    // avoids allocation
+    if negative {
+        // negating `major` directly would overflow for the minimum value of the backing when
+        // `base` is one, so the last digit is split off first
+        let rest = major / B::from(10);
+        if !rest.is_zero() {
+            write!(f, "{}", B::zero() - rest)?;
         }
-
-        // handle formatting for suffix-style currencies
-        match C::STYLE {
-            FormatStyle::SuffixAttached => write!(f, "{}", C::SYMBOL)?,
-            FormatStyle::SuffixSpaced => write!(f, " {}", C::SYMBOL)?,
-            _ => (),
+        write!(f, "{}", B::zero() - major % B::from(10))?;
+    } else {
+        write!(f, "{}", major)?;
+    }
+    if decimals == 0 {
+        return Ok(());
+    }
+    f.write_char('.')?;
+
+    // Collect the minor digits into an array, and then print them in reverse order
+    let mut minor_digits = [0u8; 64]; // HACK: max size needed for a U512, increase this to support larger types
+    let mut minor_val = minor;
+    for i in 0..decimals {
+        let mut digit = minor_val % B::from(10);
+        if negative {
+            digit = B::zero() - digit;
         }
-
-        Ok(())
+        minor_digits[decimals - 1 - i] = digit.to_u64().unwrap() as u8;
+        minor_val /= B::from(10);
     }
+    for &digit in &minor_digits[..decimals] {
+        // avoids allocation
+        f.write_char(char::from_digit(digit as u32, 10).unwrap())?;
+    }
+    Ok(())
 }
 
 impl<C: Currency, Safety: safety::Safety> core::fmt::Debug for Amount<C, Safety> {
@@ -230,10 +332,7 @@ impl<C: Currency> Sub for Amount<C, Checked> {
     type Output = Option<Self>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        match self.0.checked_sub(&rhs.0) {
-            Some(res) => Some(Self::from_raw(res)),
-            None => None,
-        }
+        self.0.checked_sub(&rhs.0).map(Self::from_raw)
     }
 }
 
@@ -255,10 +354,7 @@ impl<C: Currency> Add for Amount<C, Checked> {
     type Output = Option<Self>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        match self.0.checked_add(&rhs.0) {
-            Some(res) => Some(Self::from_raw(res)),
-            None => None,
-        }
+        self.0.checked_add(&rhs.0).map(Self::from_raw)
     }
 }
 
@@ -399,6 +495,7 @@ impl<C: Currency> From<Amount<C, Unchecked>> for Amount<C, Checked> {
 use crate::*;
 
 #[test]
+#[allow(clippy::inconsistent_digit_grouping)]
 fn test_from_raw() {
     let a: Amount = Amount::from_raw(1000_00);
     let b: Amount = Amount::from_raw(200_00);
@@ -410,6 +507,7 @@ fn test_from_raw() {
 }
 
 #[test]
+#[allow(clippy::inconsistent_digit_grouping, clippy::zero_prefixed_literal)]
 fn test_basic_ops_unchecked() {
     let a = Amount::<USD>::from_raw(100_00);
     let b = Amount::<USD>::from_raw(50_00);
@@ -431,6 +529,7 @@ fn test_basic_ops_unchecked() {
 }
 
 #[test]
+#[allow(clippy::inconsistent_digit_grouping)]
 fn test_basic_ops_checked() {
     let a = Amount::<USD, Checked>::from_raw(33_26);
     let b = Amount::<USD, Checked>::from_raw(245_23);
@@ -451,6 +550,7 @@ extern crate alloc;
 use alloc::format;
 
 #[test]
+#[allow(clippy::inconsistent_digit_grouping)]
 fn test_display() {
     assert_eq!(ETH::BASE.trailing_zeros(), 18);
     let a = Amount::<USD>::from_raw(124_27);
@@ -463,4 +563,9 @@ fn test_display() {
     assert_eq!(format!("{}", c), "$365000000.23");
     assert_eq!(format!("{}", d), "457.0000000003 DOT");
     assert_eq!(format!("{}", e), "249879873.700000000004 KSM");
+    // the minor units of currencies with a base other than a power of ten are written as the
+    // decimal fraction they are worth, e.g. 2 of the 5 iraimbilanja to the ariary as 0.4
+    let f = Amount::<MGA>::from_raw(17);
+    assert_eq!(format!("{}", f), "3.4 Ar");
+    assert_eq!("3.4 Ar".parse::<Amount<MGA>>(), Ok(f));
 }
@@ -0,0 +1,58 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::amount::Amount;
+use crate::currency::Currency;
+use crate::encoding::LeBytes;
+use crate::safety;
+use crate::u256::U256;
+
+/// Builds a [`U256`] from 32 little-endian bytes of the input.
+impl<'a> Arbitrary<'a> for U256 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(U256::from_le_bytes(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 32] as Arbitrary>::size_hint(depth)
+    }
+}
+
+/// Builds an [`Amount`] from an arbitrary raw [`Backing`](crate::Backing) value, so every
+/// representable amount can be produced.
+impl<'a, C: Currency, Safety: safety::Safety> Arbitrary<'a> for Amount<C, Safety>
+where
+    C::Backing: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Amount::from_raw(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        C::Backing::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+use crate::currency::{ETH, USD};
+
+#[test]
+fn test_arbitrary_u256() {
+    let mut bytes = [0u8; 32];
+    bytes[0] = 0x34;
+    bytes[1] = 0x12;
+    let mut u = Unstructured::new(&bytes);
+    assert_eq!(U256::arbitrary(&mut u).unwrap(), U256::from(0x1234u32));
+    let mut u = Unstructured::new(&[0xff; 32]);
+    assert_eq!(U256::arbitrary(&mut u).unwrap(), U256::MAX_VALUE);
+    assert_eq!(U256::size_hint(0), (32, Some(32)));
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_arbitrary_amount() {
+    let mut u = Unstructured::new(&[0x39, 0x30, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(Amount::<USD>::arbitrary(&mut u).unwrap(), Amount::from_raw(123_45));
+    let mut u = Unstructured::new(&[0xff; 32]);
+    assert_eq!(Amount::<ETH>::arbitrary(&mut u).unwrap(), Amount::from_raw(U256::MAX_VALUE));
+    assert_eq!(Amount::<ETH>::size_hint(0), (32, Some(32)));
+}
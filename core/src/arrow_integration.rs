@@ -0,0 +1,269 @@
+use arrow_array::types::{ArrowPrimitiveType, Decimal128Type, Decimal256Type, DecimalType};
+use arrow_array::{Array, ArrowNativeTypeOp, PrimitiveArray};
+use arrow_buffer::i256;
+use arrow_schema::DataType;
+use num_traits::ToPrimitive;
+
+use crate::amount::{Amount, Backing};
+use crate::conversion::{ConversionError, DecimalParts};
+use crate::currency::Currency;
+use crate::parsing::is_signed;
+use crate::safety;
+use crate::u256::{backing_to_u256, u256_to_backing, U256};
+
+/// The native value type of the Arrow decimal column used for a [`Backing`] `B`, i.e. [`i128`]
+/// or [`i256`].
+pub type ArrowNative<B> = <<B as ArrowDecimal>::DecimalType as ArrowPrimitiveType>::Native;
+
+/// A [`Backing`] that can be stored in an Arrow decimal column.
+///
+/// The primitive backings are stored as [`Decimal128Type`] and [`U256`] as [`Decimal256Type`],
+/// in both cases with the maximum precision of the column type, so e.g. [`u128`] amounts with
+/// more than 38 digits cannot be stored.
+pub trait ArrowDecimal: Backing {
+    /// The Arrow decimal type that amounts with this backing are stored as.
+    type DecimalType: DecimalType;
+
+    /// Converts this value into the native type of [`ArrowDecimal::DecimalType`], or returns
+    /// [`None`] if it doesn't fit.
+    fn to_native(self) -> Option<ArrowNative<Self>>;
+
+    /// Converts a value of the native type of [`ArrowDecimal::DecimalType`] into this type, or
+    /// returns [`None`] if it doesn't fit.
+    fn from_native(native: ArrowNative<Self>) -> Option<Self>;
+}
+
+macro_rules! impl_arrow_decimal {
+    ($($ty:ty),*) => {
+        $(
+            impl ArrowDecimal for $ty {
+                type DecimalType = Decimal128Type;
+
+                fn to_native(self) -> Option<i128> {
+                    self.to_i128()
+                }
+
+                fn from_native(native: i128) -> Option<Self> {
+                    <$ty>::try_from(native).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_arrow_decimal!(u32, u64, u128, i64, i128);
+
+impl ArrowDecimal for U256 {
+    type DecimalType = Decimal256Type;
+
+    fn to_native(self) -> Option<i256> {
+        // values with the top bit set would be negative
+        match self.0.bit(255) {
+            true => None,
+            false => Some(i256::from_le_bytes(crate::LeBytes::to_le_bytes(&self))),
+        }
+    }
+
+    fn from_native(native: i256) -> Option<Self> {
+        match native.is_negative() {
+            true => None,
+            false => Some(crate::LeBytes::from_le_bytes(native.to_le_bytes())),
+        }
+    }
+}
+
+/// Returns the number of digits after the decimal point of amounts of `C` and the number to
+/// multiply minor units by to get the digits of such decimals.
+fn decimal_scale<C: Currency>() -> Result<(u32, U256), ConversionError> {
+    let parts = DecimalParts::from_minor(false, U256::from(1u8), backing_to_u256(C::BASE))?;
+    Ok((parts.scale, parts.mantissa))
+}
+
+/// Returns `10^exp` in the native type `N`.
+fn pow10<N: ArrowNativeTypeOp>(exp: u32) -> Result<N, ConversionError> {
+    N::usize_as(10).pow_checked(exp).map_err(|_| ConversionError::Overflow)
+}
+
+impl<C: Currency, Safety: safety::Safety> Amount<C, Safety>
+where
+    C::Backing: ArrowDecimal,
+{
+    /// Returns the Arrow [`DataType`] that [`Amount::to_arrow`] produces, for use in schemas,
+    /// e.g. `Decimal128(38, 2)` for [`USD`](crate::currency::USD) and `Decimal256(76, 18)` for
+    /// [`ETH`](crate::currency::ETH).
+    pub fn arrow_data_type() -> Result<DataType, ConversionError> {
+        let (scale, _) = decimal_scale::<C>()?;
+        Ok(<C::Backing as ArrowDecimal>::DecimalType::TYPE_CONSTRUCTOR(
+            <C::Backing as ArrowDecimal>::DecimalType::MAX_PRECISION,
+            arrow_scale::<C>(scale)?,
+        ))
+    }
+
+    /// Converts a slice of [`Amount`]s into an Arrow decimal array whose scale is the number
+    /// of digits after the decimal point of `C`, operating directly on the minor units.
+    ///
+    /// Fails with [`ConversionError::Overflow`] if an amount has more digits than the column
+    /// type supports, and with [`ConversionError::Inexact`] if the [`Currency::BASE`] does not
+    /// divide a power of ten.
+    pub fn to_arrow(amounts: &[Self]) -> Result<PrimitiveArray<<C::Backing as ArrowDecimal>::DecimalType>, ConversionError> {
+        type D<B> = <B as ArrowDecimal>::DecimalType;
+        let (scale, factor) = decimal_scale::<C>()?;
+        let factor = u256_to_backing::<C::Backing>(factor, false)
+            .and_then(ArrowDecimal::to_native)
+            .ok_or(ConversionError::Overflow)?;
+        let precision = D::<C::Backing>::MAX_PRECISION;
+        let values = amounts
+            .iter()
+            .map(|amount| {
+                let value = amount.raw_backing().to_native().ok_or(ConversionError::Overflow)?;
+                let value = value.mul_checked(factor).map_err(|_| ConversionError::Overflow)?;
+                match D::<C::Backing>::is_valid_decimal_precision(value, precision) {
+                    true => Ok(value),
+                    false => Err(ConversionError::Overflow),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        PrimitiveArray::from_iter_values(values)
+            .with_precision_and_scale(precision, arrow_scale::<C>(scale)?)
+            .map_err(|_| ConversionError::Overflow)
+    }
+
+    /// Converts an Arrow decimal array into [`Amount`]s exactly, honouring the scale of the
+    /// array, so e.g. a `Decimal128(20, 4)` column holding `123.4500` yields `123.45` USD.
+    ///
+    /// Fails with [`ConversionError::Inexact`] if a value is not a whole number of minor units
+    /// and with [`ConversionError::Invalid`] if the array contains nulls.
+    pub fn from_arrow(array: &PrimitiveArray<<C::Backing as ArrowDecimal>::DecimalType>) -> Result<Vec<Self>, ConversionError> {
+        if array.null_count() > 0 {
+            return Err(ConversionError::Invalid);
+        }
+        let (scale, factor) = decimal_scale::<C>()?;
+        let factor: ArrowNative<C::Backing> = u256_to_backing::<C::Backing>(factor, false)
+            .and_then(ArrowDecimal::to_native)
+            .ok_or(ConversionError::Overflow)?;
+        let shift = scale as i32 - array.scale() as i32;
+        array
+            .values()
+            .iter()
+            .map(|&value| {
+                // rescale to the digits of `C`, then divide out the factor to get minor units
+                let value = match shift {
+                    0 => value,
+                    1.. => value.mul_checked(pow10(shift as u32)?).map_err(|_| ConversionError::Overflow)?,
+                    _ => match pow10(shift.unsigned_abs()) {
+                        Ok(divisor) => exact_div(value, divisor)?,
+                        Err(_) if value.is_zero() => value,
+                        Err(_) => return Err(ConversionError::Inexact),
+                    },
+                };
+                let value = exact_div(value, factor)?;
+                match C::Backing::from_native(value) {
+                    Some(raw) => Ok(Amount::from_raw(raw)),
+                    None if value < ArrowNative::<C::Backing>::ZERO && !is_signed::<C::Backing>() => Err(ConversionError::Negative),
+                    None => Err(ConversionError::Overflow),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns the scale of the Arrow column for a currency with `scale` digits after the decimal
+/// point.
+fn arrow_scale<C: Currency>(scale: u32) -> Result<i8, ConversionError>
+where
+    C::Backing: ArrowDecimal,
+{
+    match i8::try_from(scale) {
+        Ok(scale) if scale <= <C::Backing as ArrowDecimal>::DecimalType::MAX_SCALE => Ok(scale),
+        _ => Err(ConversionError::Inexact),
+    }
+}
+
+/// Divides `value` by `divisor`, failing with [`ConversionError::Inexact`] if there is a
+/// remainder.
+fn exact_div<N: ArrowNativeTypeOp>(value: N, divisor: N) -> Result<N, ConversionError> {
+    if divisor == N::ONE {
+        return Ok(value);
+    }
+    match value.mod_checked(divisor) {
+        Ok(remainder) if remainder.is_zero() => value.div_checked(divisor).map_err(|_| ConversionError::Overflow),
+        _ => Err(ConversionError::Inexact),
+    }
+}
+
+#[cfg(test)]
+use crate::currency::{DOT, ETH, MGA, SIGNEDUSD, USD};
+
+#[cfg(test)]
+use arrow_array::{Decimal128Array, Decimal256Array};
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_amounts_to_arrow() {
+    let array = Amount::<USD>::to_arrow(&[Amount::from_raw(123_45), Amount::from_raw(0), Amount::from_raw(u64::MAX)]).unwrap();
+    assert_eq!(array.data_type(), &DataType::Decimal128(38, 2));
+    assert_eq!(array.values().as_ref(), [123_45, 0, u64::MAX as i128]);
+    assert_eq!(array.value_as_string(0), "123.45");
+    assert_eq!(Amount::<USD>::arrow_data_type(), Ok(DataType::Decimal128(38, 2)));
+
+    let array = Amount::<SIGNEDUSD>::to_arrow(&[Amount::from_raw(-5), Amount::from_raw(i64::MIN)]).unwrap();
+    assert_eq!(array.value_as_string(0), "-0.05");
+    assert_eq!(array.value_as_string(1), "-92233720368547758.08");
+
+    let array = Amount::<MGA>::to_arrow(&[Amount::from_raw(17)]).unwrap();
+    assert_eq!(array.data_type(), &DataType::Decimal128(38, 1));
+    assert_eq!(array.value_as_string(0), "3.4");
+
+    let eth = Amount::<ETH>::from_raw(U256::from(1_500000000000000000u64));
+    let array = Amount::<ETH>::to_arrow(&[eth, Amount::from_raw(U256(primitive_types::U256::exp10(75)))]).unwrap();
+    assert_eq!(array.data_type(), &DataType::Decimal256(76, 18));
+    assert_eq!(array.value_as_string(0), "1.500000000000000000");
+
+    assert_eq!(Amount::<DOT>::to_arrow(&[Amount::from_raw(u128::MAX)]), Err(ConversionError::Overflow));
+    assert_eq!(
+        Amount::<ETH>::to_arrow(&[Amount::from_raw(U256::MAX_VALUE)]),
+        Err(ConversionError::Overflow)
+    );
+    let _: Decimal256Array = Amount::<ETH>::to_arrow(&[]).unwrap();
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_arrow_to_amounts() {
+    let array = Decimal128Array::from(vec![123_45, 7_00]).with_precision_and_scale(38, 2).unwrap();
+    assert_eq!(
+        Amount::<USD>::from_arrow(&array),
+        Ok(vec![Amount::from_raw(123_45), Amount::from_raw(7_00)])
+    );
+    let array = Decimal128Array::from(vec![123_4500]).with_precision_and_scale(20, 4).unwrap();
+    assert_eq!(Amount::<USD>::from_arrow(&array), Ok(vec![Amount::from_raw(123_45)]));
+    let array = Decimal128Array::from(vec![7]).with_precision_and_scale(10, -3).unwrap();
+    assert_eq!(Amount::<USD>::from_arrow(&array), Ok(vec![Amount::from_raw(7000_00)]));
+    let array = Decimal128Array::from(vec![34]).with_precision_and_scale(10, 1).unwrap();
+    assert_eq!(Amount::<MGA>::from_arrow(&array), Ok(vec![Amount::from_raw(17)]));
+    let array = Decimal128Array::from(vec![-5]).with_precision_and_scale(10, 2).unwrap();
+    assert_eq!(Amount::<SIGNEDUSD>::from_arrow(&array), Ok(vec![Amount::from_raw(-5)]));
+
+    let array = Decimal128Array::from(vec![123_456]).with_precision_and_scale(20, 3).unwrap();
+    assert_eq!(Amount::<USD>::from_arrow(&array), Err(ConversionError::Inexact));
+    let array = Decimal128Array::from(vec![-1_00]).with_precision_and_scale(20, 2).unwrap();
+    assert_eq!(Amount::<USD>::from_arrow(&array), Err(ConversionError::Negative));
+    let array = Decimal128Array::from(vec![i128::MAX]).with_precision_and_scale(38, 2).unwrap();
+    assert_eq!(Amount::<USD>::from_arrow(&array), Err(ConversionError::Overflow));
+    let array = Decimal128Array::from(vec![Some(1_00), None]).with_precision_and_scale(20, 2).unwrap();
+    assert_eq!(Amount::<USD>::from_arrow(&array), Err(ConversionError::Invalid));
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_arrow_round_trip() {
+    let usd = [Amount::<USD>::from_raw(0), Amount::from_raw(123_45), Amount::from_raw(u64::MAX)];
+    assert_eq!(Amount::from_arrow(&Amount::to_arrow(&usd).unwrap()).unwrap(), usd);
+    let dot = [Amount::<DOT>::from_raw(12_0000000004), Amount::from_raw(u128::MAX / 10)];
+    assert_eq!(Amount::from_arrow(&Amount::to_arrow(&dot).unwrap()).unwrap(), dot);
+    let eth = [
+        Amount::<ETH>::from_raw(U256::from(1_500000000000000000u64)),
+        Amount::from_raw(U256(primitive_types::U256::exp10(75) - 1)),
+    ];
+    assert_eq!(Amount::from_arrow(&Amount::to_arrow(&eth).unwrap()).unwrap(), eth);
+}
@@ -0,0 +1,118 @@
+use bigdecimal::num_bigint::{BigInt, BigUint, Sign};
+use bigdecimal::BigDecimal;
+use num_traits::{CheckedMul, Zero};
+
+use crate::amount::Amount;
+use crate::conversion::{ConversionError, DecimalParts};
+use crate::currency::Currency;
+use crate::encoding::LeBytes;
+use crate::safety;
+use crate::u256::{backing_to_u256, U256};
+
+fn to_bigint(negative: bool, magnitude: U256) -> BigInt {
+    let sign = if negative { Sign::Minus } else { Sign::Plus };
+    BigInt::from_biguint(sign, BigUint::from_bytes_le(&magnitude.to_le_bytes()))
+}
+
+/// Converts a [`BigDecimal`] into an [`Amount`] exactly, failing with
+/// [`ConversionError::Inexact`] if it is not a whole number of minor units.
+impl<C: Currency, Safety: safety::Safety> TryFrom<BigDecimal> for Amount<C, Safety> {
+    type Error = ConversionError;
+
+    fn try_from(value: BigDecimal) -> Result<Self, ConversionError> {
+        let (digits, exponent) = value.normalized().into_bigint_and_exponent();
+        let bytes = digits.magnitude().to_bytes_le();
+        if bytes.len() > 32 {
+            return Err(ConversionError::Overflow);
+        }
+        let mut mantissa = U256(primitive_types::U256::from_little_endian(&bytes));
+        // a negative exponent means the digits are followed by that many zeros
+        for _ in exponent..0 {
+            mantissa = mantissa.checked_mul(&U256::from(10u8)).ok_or(ConversionError::Overflow)?;
+        }
+        Amount::from_decimal_parts(DecimalParts {
+            negative: digits.sign() == Sign::Minus,
+            mantissa,
+            scale: u32::try_from(exponent.max(0)).map_err(|_| ConversionError::Inexact)?,
+        })
+    }
+}
+
+/// Converts an [`Amount`] into a [`BigDecimal`] with as many digits after the decimal point as
+/// the [`Currency`] has.
+///
+/// This is exact for every [`Currency`] whose [`Currency::BASE`] divides a power of ten, which
+/// includes all built-in currencies. Otherwise the quotient is rounded to the default precision
+/// of [`BigDecimal`] division.
+impl<C: Currency, Safety: safety::Safety> From<Amount<C, Safety>> for BigDecimal {
+    fn from(amount: Amount<C, Safety>) -> Self {
+        match amount.decimal_parts() {
+            Ok(parts) => BigDecimal::new(to_bigint(parts.negative, parts.mantissa), parts.scale.into()),
+            Err(_) => {
+                let raw = amount.raw_backing();
+                let raw = to_bigint(raw < C::Backing::zero(), backing_to_u256(raw));
+                BigDecimal::from(raw) / BigDecimal::from(to_bigint(false, backing_to_u256(C::BASE)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::currency::{DOT, ETH, MGA, SIGNEDUSD, USD};
+
+#[cfg(test)]
+use alloc::string::ToString;
+#[cfg(test)]
+use core::str::FromStr;
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_bigdecimal_to_amount() {
+    let decimal = |s: &str| BigDecimal::from_str(s).unwrap();
+    assert_eq!(Amount::<USD>::try_from(decimal("123.45")), Ok(Amount::from_raw(123_45)));
+    assert_eq!(Amount::<USD>::try_from(decimal("7.000000")), Ok(Amount::from_raw(7_00)));
+    assert_eq!(Amount::<USD>::try_from(decimal("1e3")), Ok(Amount::from_raw(1000_00)));
+    assert_eq!(Amount::<MGA>::try_from(decimal("3.4")), Ok(Amount::from_raw(17)));
+    assert_eq!(Amount::<SIGNEDUSD>::try_from(decimal("-0.05")), Ok(Amount::from_raw(-5)));
+    assert_eq!(
+        Amount::<SIGNEDUSD>::try_from(decimal("-92233720368547758.08")),
+        Ok(Amount::from_raw(i64::MIN))
+    );
+    assert_eq!(Amount::<DOT>::try_from(decimal("12.0000000004")), Ok(Amount::from_raw(12_0000000004)));
+    assert_eq!(
+        Amount::<ETH>::try_from(decimal("115792089237316195423570985008687907853269984665640564039457.584007913129639935")),
+        Ok(Amount::from_raw(U256::MAX_VALUE))
+    );
+
+    assert_eq!(Amount::<USD>::try_from(decimal("123.456")), Err(ConversionError::Inexact));
+    assert_eq!(Amount::<USD>::try_from(decimal("1e-400")), Err(ConversionError::Inexact));
+    assert_eq!(Amount::<USD>::try_from(decimal("-1")), Err(ConversionError::Negative));
+    assert_eq!(Amount::<USD>::try_from(decimal("1e400")), Err(ConversionError::Overflow));
+    assert_eq!(
+        Amount::<ETH>::try_from(decimal("115792089237316195423570985008687907853269984665640564039457.584007913129639936")),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_amount_to_bigdecimal() {
+    assert_eq!(BigDecimal::from(Amount::<USD>::from_raw(123_45)).to_string(), "123.45");
+    assert_eq!(BigDecimal::from(Amount::<USD>::from_raw(7_00)).to_string(), "7.00");
+    assert_eq!(BigDecimal::from(Amount::<MGA>::from_raw(17)).to_string(), "3.4");
+    assert_eq!(
+        BigDecimal::from(Amount::<SIGNEDUSD>::from_raw(i64::MIN)).to_string(),
+        "-92233720368547758.08"
+    );
+    assert_eq!(
+        BigDecimal::from(Amount::<DOT>::from_raw(u128::MAX)),
+        BigDecimal::from_str("34028236692093846346337460743.1768211455").unwrap()
+    );
+
+    let eth = Amount::<ETH>::from_raw(U256::MAX_VALUE);
+    assert_eq!(
+        BigDecimal::from(eth).to_string(),
+        "115792089237316195423570985008687907853269984665640564039457.584007913129639935"
+    );
+    assert_eq!(Amount::<ETH>::try_from(BigDecimal::from(eth)), Ok(eth));
+}
@@ -0,0 +1,60 @@
+use borsh::io::{Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::amount::Amount;
+use crate::currency::Currency;
+use crate::encoding::LeBytes;
+use crate::safety;
+use crate::u256::U256;
+
+/// Serializes a [`U256`] as 32 little-endian bytes, like borsh does for other integers.
+impl BorshSerialize for U256 {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&self.to_le_bytes())
+    }
+}
+
+impl BorshDeserialize for U256 {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut bytes = [0; 32];
+        reader.read_exact(&mut bytes)?;
+        Ok(U256::from_le_bytes(bytes))
+    }
+}
+
+/// Serializes an [`Amount`] exactly like its raw [`Backing`](crate::Backing) value.
+impl<C: Currency, Safety: safety::Safety> BorshSerialize for Amount<C, Safety>
+where
+    C::Backing: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.raw_backing().serialize(writer)
+    }
+}
+
+impl<C: Currency, Safety: safety::Safety> BorshDeserialize for Amount<C, Safety>
+where
+    C::Backing: BorshDeserialize,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        C::Backing::deserialize_reader(reader).map(Amount::from_raw)
+    }
+}
+
+#[cfg(test)]
+use crate::currency::{ETH, SOL};
+
+#[test]
+fn test_borsh_encoding() {
+    let amount = Amount::<SOL>::from_raw(1_500000000);
+    let encoded = borsh::to_vec(&amount).unwrap();
+    assert_eq!(encoded, borsh::to_vec(&1_500000000u64).unwrap());
+    assert_eq!(borsh::from_slice::<Amount<SOL>>(&encoded).unwrap(), amount);
+
+    let amount = Amount::<ETH>::from_raw(U256::MAX_VALUE - U256::from(1u8));
+    let encoded = borsh::to_vec(&amount).unwrap();
+    assert_eq!(encoded.len(), 32);
+    assert_eq!(encoded[0], 0xfe);
+    assert_eq!(borsh::from_slice::<Amount<ETH>>(&encoded).unwrap(), amount);
+    assert!(borsh::from_slice::<Amount<ETH>>(&encoded[..31]).is_err());
+}
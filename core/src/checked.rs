@@ -0,0 +1,294 @@
+//! Home of [`ArithmeticError`] and the traits that the `checked!` macro rewrites arithmetic
+//! operators into.
+//!
+//! `checked!` turns an expression such as `a + b * 3 - fee` into the corresponding chain of
+//! [`TryAdd`], [`TryMul`] and [`TrySub`] calls and evaluates to a single
+//! `Result<_, ArithmeticError>`, whose error names the subexpression that failed. Operator
+//! precedence is that of the expression as written.
+//!
+//! Every operation must have an [`Amount`] operand. Amounts can be added to, subtracted from,
+//! multiplied by, divided by and taken the remainder of amounts of the same currency, and
+//! multiplied or divided by any primitive integer. Dividing two amounts yields the raw
+//! [`Backing`] quotient, as with the `/` operator.
+//!
+//! The operations are checked for amounts of either [`Safety`](safety::Safety) and return
+//! [`None`] on overflow, on division by zero and when a scalar does not fit in the
+//! [`Backing`] of the currency.
+
+use core::fmt;
+
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, PrimInt, ToPrimitive, Zero};
+
+use crate::amount::{Amount, Backing};
+use crate::currency::Currency;
+use crate::safety;
+
+/// Returned by `checked!` when one of the operations in its expression overflows, divides by
+/// zero or is given a scalar that does not fit in the [`Backing`] of the currency.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ArithmeticError {
+    expression: &'static str,
+}
+
+impl ArithmeticError {
+    /// Constructs an [`ArithmeticError`] for the specified failed subexpression.
+    pub const fn new(expression: &'static str) -> Self {
+        ArithmeticError { expression }
+    }
+
+    /// The source text of the subexpression that failed, such as `b * 3`.
+    pub const fn expression(&self) -> &'static str {
+        self.expression
+    }
+}
+
+impl fmt::Display for ArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "arithmetic overflow or division by zero in `{}`", self.expression)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ArithmeticError {}
+
+/// Checked `+`, returning [`None`] on overflow.
+pub trait TryAdd<Rhs = Self> {
+    /// The type of the sum.
+    type Output;
+
+    /// Adds `rhs` to `self`.
+    fn try_add(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Checked `-`, returning [`None`] on overflow.
+pub trait TrySub<Rhs = Self> {
+    /// The type of the difference.
+    type Output;
+
+    /// Subtracts `rhs` from `self`.
+    fn try_sub(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Checked `*`, returning [`None`] on overflow.
+pub trait TryMul<Rhs = Self> {
+    /// The type of the product.
+    type Output;
+
+    /// Multiplies `self` by `rhs`.
+    fn try_mul(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Checked `/`, returning [`None`] on overflow or division by zero.
+pub trait TryDiv<Rhs = Self> {
+    /// The type of the quotient.
+    type Output;
+
+    /// Divides `self` by `rhs`.
+    fn try_div(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Checked `%`, returning [`None`] on overflow or division by zero.
+pub trait TryRem<Rhs = Self> {
+    /// The type of the remainder.
+    type Output;
+
+    /// Returns the remainder of dividing `self` by `rhs`.
+    fn try_rem(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Checked unary `-`, returning [`None`] if the negation cannot be represented.
+pub trait TryNeg {
+    /// The type of the negation.
+    type Output;
+
+    /// Negates `self`.
+    fn try_neg(self) -> Option<Self::Output>;
+}
+
+impl<C: Currency, Safety: safety::Safety> TryAdd for Amount<C, Safety> {
+    type Output = Self;
+
+    fn try_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(&rhs.0).map(Self::from_raw)
+    }
+}
+
+impl<C: Currency, Safety: safety::Safety> TrySub for Amount<C, Safety> {
+    type Output = Self;
+
+    fn try_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(&rhs.0).map(Self::from_raw)
+    }
+}
+
+impl<C: Currency, Safety: safety::Safety> TryMul for Amount<C, Safety> {
+    type Output = Self;
+
+    fn try_mul(self, rhs: Self) -> Option<Self> {
+        Some(Self::from_raw(self.0.checked_mul(&rhs.0)?.checked_div(&C::BASE)?))
+    }
+}
+
+impl<C: Currency, Safety: safety::Safety> TryDiv for Amount<C, Safety> {
+    type Output = C::Backing;
+
+    fn try_div(self, rhs: Self) -> Option<C::Backing> {
+        self.0.checked_div(&rhs.0)
+    }
+}
+
+impl<C: Currency, Safety: safety::Safety> TryRem for Amount<C, Safety> {
+    type Output = Self;
+
+    fn try_rem(self, rhs: Self) -> Option<Self> {
+        self.0.checked_rem(&rhs.0).map(Self::from_raw)
+    }
+}
+
+impl<C: Currency, Safety: safety::Safety> TryNeg for Amount<C, Safety> {
+    type Output = Self;
+
+    fn try_neg(self) -> Option<Self> {
+        C::Backing::zero().checked_sub(&self.0).map(Self::from_raw)
+    }
+}
+
+impl<C: Currency, Safety: safety::Safety, T: PrimInt> TryMul<T> for Amount<C, Safety> {
+    type Output = Self;
+
+    fn try_mul(self, rhs: T) -> Option<Self> {
+        self.0.checked_mul(&scalar(rhs)?).map(Self::from_raw)
+    }
+}
+
+impl<C: Currency, Safety: safety::Safety, T: PrimInt> TryMul<Amount<C, Safety>> for T {
+    type Output = Amount<C, Safety>;
+
+    fn try_mul(self, rhs: Amount<C, Safety>) -> Option<Amount<C, Safety>> {
+        rhs.try_mul(self)
+    }
+}
+
+impl<C: Currency, Safety: safety::Safety, T: PrimInt> TryDiv<T> for Amount<C, Safety> {
+    type Output = Self;
+
+    fn try_div(self, rhs: T) -> Option<Self> {
+        self.0.checked_div(&scalar(rhs)?).map(Self::from_raw)
+    }
+}
+
+/// Converts a primitive integer to a [`Backing`], or returns [`None`] if it does not fit.
+fn scalar<B: Backing, T: ToPrimitive>(value: T) -> Option<B> {
+    let (negative, magnitude) = match value.to_u128() {
+        Some(value) => (false, value),
+        None => (true, value.to_i128()?.unsigned_abs()),
+    };
+    // accumulates the value 32 bits at a time, subtracting the bits of negative values so that
+    // the minimum of a signed backing does not overflow
+    let mut result = B::zero();
+    for shift in [96, 64, 32, 0] {
+        let chunk = B::from((magnitude >> shift) as u32);
+        result = result.checked_mul(&B::from(1 << 16))?.checked_mul(&B::from(1 << 16))?;
+        result = match negative {
+            true => result.checked_sub(&chunk)?,
+            false => result.checked_add(&chunk)?,
+        };
+    }
+    Some(result)
+}
+
+/// Applies the checked binary operation `op` to the results of its operands, naming
+/// `expression` in the error if it fails. Called by the code generated by `checked!`.
+#[doc(hidden)]
+pub fn binary<L, R, T>(
+    lhs: Result<L, ArithmeticError>,
+    rhs: Result<R, ArithmeticError>,
+    op: impl FnOnce(L, R) -> Option<T>,
+    expression: &'static str,
+) -> Result<T, ArithmeticError> {
+    op(lhs?, rhs?).ok_or(ArithmeticError::new(expression))
+}
+
+/// Applies the checked unary operation `op` to the result of its operand, naming `expression`
+/// in the error if it fails. Called by the code generated by `checked!`.
+#[doc(hidden)]
+pub fn unary<V, T>(value: Result<V, ArithmeticError>, op: impl FnOnce(V) -> Option<T>, expression: &'static str) -> Result<T, ArithmeticError> {
+    op(value?).ok_or(ArithmeticError::new(expression))
+}
+
+#[cfg(test)]
+use alloc::format;
+
+#[cfg(test)]
+use crate::currency::{ETH, USD};
+
+#[cfg(test)]
+crate::define_currency!(CHECKEDGEM, i64, 1_000, "gem", "Gem", SuffixSpaced, false, false);
+
+#[test]
+fn test_try_ops_on_amounts() {
+    let a = Amount::<USD, safety::Checked>::from_raw(3_00);
+    let b = Amount::<USD, safety::Checked>::from_raw(1_50);
+    assert_eq!(a.try_add(b), Some(Amount::from_raw(4_50)));
+    assert_eq!(a.try_sub(b), Some(Amount::from_raw(1_50)));
+    assert_eq!(b.try_sub(a), None);
+    assert_eq!(a.try_mul(b), Some(Amount::from_raw(4_50)));
+    assert_eq!(a.try_div(b), Some(2));
+    assert_eq!(a.try_div(Amount::from_raw(0)), None);
+    assert_eq!(a.try_rem(Amount::from_raw(1_00)), Some(Amount::from_raw(0)));
+    assert_eq!(a.try_rem(Amount::from_raw(0)), None);
+    assert_eq!(Amount::<USD>::from_raw(u64::MAX).try_add(Amount::from_raw(1)), None);
+    assert_eq!(a.try_neg(), None);
+    assert_eq!(Amount::<USD>::from_raw(0).try_neg(), Some(Amount::from_raw(0)));
+    let gem = Amount::<CHECKEDGEM>::from_raw(1_500);
+    assert_eq!(gem.try_neg(), Some(Amount::from_raw(-1_500)));
+    assert_eq!(Amount::<CHECKEDGEM>::from_raw(i64::MIN).try_neg(), None);
+    assert_eq!(Amount::<CHECKEDGEM>::from_raw(i64::MIN).try_rem(Amount::from_raw(-1)), None);
+    assert_eq!(gem.try_rem(Amount::from_raw(-1_000)), Some(Amount::from_raw(500)));
+}
+
+#[test]
+fn test_try_ops_with_scalars() {
+    let a = Amount::<USD, safety::Checked>::from_raw(3_00);
+    assert_eq!(a.try_mul(3), Some(Amount::from_raw(9_00)));
+    assert_eq!(3u8.try_mul(a), Some(Amount::from_raw(9_00)));
+    assert_eq!(a.try_div(4u128), Some(Amount::from_raw(75)));
+    assert_eq!(a.try_div(0), None);
+    assert_eq!(a.try_mul(-1), None);
+    assert_eq!(a.try_mul(u128::MAX), None);
+    assert_eq!(a.try_mul(u64::MAX), None);
+    let gem = Amount::<CHECKEDGEM>::from_raw(1_500);
+    assert_eq!(gem.try_mul(-2i8), Some(Amount::from_raw(-3_000)));
+    assert_eq!(gem.try_div(-3isize), Some(Amount::from_raw(-500)));
+    assert_eq!(gem.try_mul(i128::MIN), None);
+    let eth = Amount::<ETH>::from_raw(crate::U256::from(2u8));
+    assert_eq!(
+        eth.try_mul(u128::MAX),
+        Some(Amount::from_raw(crate::U256::from(u128::MAX) * crate::U256::from(2u8)))
+    );
+    assert_eq!(eth.try_mul(-1), None);
+}
+
+#[test]
+fn test_scalar() {
+    assert_eq!(scalar::<u32, _>(u32::MAX), Some(u32::MAX));
+    assert_eq!(scalar::<u32, _>(u32::MAX as u64 + 1), None);
+    assert_eq!(scalar::<i64, _>(i64::MIN), Some(i64::MIN));
+    assert_eq!(scalar::<i128, _>(u128::MAX), None);
+    assert_eq!(scalar::<u64, _>(-1), None);
+    assert_eq!(scalar::<crate::U256, _>(u128::MAX), Some(crate::U256::from(u128::MAX)));
+}
+
+#[test]
+fn test_arithmetic_error() {
+    let lhs = Ok(Amount::<USD>::from_raw(u64::MAX));
+    let error = binary(lhs, Ok(Amount::from_raw(1)), TryAdd::try_add, "a + b").unwrap_err();
+    assert_eq!(error.expression(), "a + b");
+    assert_eq!(format!("{}", error), "arithmetic overflow or division by zero in `a + b`");
+    let inner = Err::<Amount<USD>, _>(ArithmeticError::new("a * 2"));
+    assert_eq!(binary(inner, Ok(2u8), TryMul::try_mul, "a * 2 * 2"), inner);
+    assert_eq!(
+        unary(Ok(Amount::<USD>::from_raw(1)), TryNeg::try_neg, "-a"),
+        Err(ArithmeticError::new("-a"))
+    );
+}
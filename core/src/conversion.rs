@@ -0,0 +1,269 @@
+//! Home of [`ConversionError`], returned by the exact conversions between [`Amount`]s and the
+//! decimal types of other crates, such as `rust_decimal::Decimal` and `bigdecimal::BigDecimal`
+//! (available behind the `rust_decimal` and `bigdecimal` features).
+//!
+//! These conversions never round. An [`Amount`] is expressed as a decimal with as many digits
+//! after the decimal point as its [`Currency`] has (see [`Currency::decimal_digits`]), and a
+//! decimal is only accepted if it is a whole number of minor units that fits in the
+//! [`Backing`] of the [`Currency`].
+//!
+//! [`Amount`]: crate::Amount
+//! [`Backing`]: crate::Backing
+//! [`Currency`]: crate::Currency
+//! [`Currency::decimal_digits`]: crate::Currency::decimal_digits
+
+use core::fmt;
+
+/// Describes why a value could not be converted to or from an [`Amount`] exactly.
+///
+/// [`Amount`]: crate::Amount
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ConversionError {
+    /// The value is too large to be represented by the target type.
+    Overflow,
+    /// The value is negative, but the [`Backing`] of the target currency is unsigned.
+    ///
+    /// [`Backing`]: crate::Backing
+    Negative,
+    /// The value cannot be represented without losing precision, e.g. it is not a whole number
+    /// of minor units.
+    Inexact,
+    /// The value is denominated in a currency other than the target currency.
+    CurrencyMismatch,
+    /// The value is denominated in a currency that is not in the [`registry`].
+    ///
+    /// [`registry`]: crate::registry
+    UnknownCurrency,
+    /// The value is malformed, e.g. its parts are out of range or have inconsistent signs.
+    Invalid,
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ConversionError::Overflow => "value is too large for the target type",
+            ConversionError::Negative => "negative amounts require a signed backing",
+            ConversionError::Inexact => "value cannot be represented without losing precision",
+            ConversionError::CurrencyMismatch => "currency does not match the target currency",
+            ConversionError::UnknownCurrency => "unknown currency code",
+            ConversionError::Invalid => "value is malformed",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+#[cfg(any(
+    feature = "rust_decimal",
+    feature = "bigdecimal",
+    feature = "postgres",
+    feature = "prost",
+    feature = "arrow",
+    test
+))]
+pub(crate) use parts::DecimalParts;
+
+/// Exact decimal representations of [`Amount`]s, shared by the integrations with decimal types.
+///
+/// [`Amount`]: crate::Amount
+#[cfg(any(
+    feature = "rust_decimal",
+    feature = "bigdecimal",
+    feature = "postgres",
+    feature = "prost",
+    feature = "arrow",
+    test
+))]
+mod parts {
+    use num_integer::Integer;
+    use num_traits::{CheckedMul, Zero};
+
+    use super::ConversionError;
+    use crate::u256::U256;
+
+    /// The exact decimal value `±mantissa × 10^-scale`, used as the common ground between
+    /// [`Amount`]s and the decimal types of other crates.
+    #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+    pub(crate) struct DecimalParts {
+        pub negative: bool,
+        pub mantissa: U256,
+        pub scale: u32,
+    }
+
+    impl DecimalParts {
+        /// Removes trailing zeros from the mantissa while decreasing the scale to no less than
+        /// `min_scale`.
+        #[cfg(any(feature = "rust_decimal", feature = "bigdecimal", feature = "postgres", feature = "prost", test))]
+        pub fn trim(mut self, min_scale: u32) -> Self {
+            let ten = U256::from(10u8);
+            while self.scale > min_scale && !self.mantissa.is_zero() && (self.mantissa % ten).is_zero() {
+                self.mantissa /= ten;
+                self.scale -= 1;
+            }
+            self
+        }
+
+        /// Expresses the amount of `magnitude` minor units of a currency with the specified
+        /// `base` exactly as a decimal with as many digits after the decimal point as the
+        /// currency has.
+        ///
+        /// Fails with [`ConversionError::Inexact`] if `base` does not divide a power of ten,
+        /// since such amounts have no finite decimal representation.
+        pub fn from_minor(negative: bool, magnitude: U256, base: U256) -> Result<Self, ConversionError> {
+            let (scale, factor) = decimal_scale(base).ok_or(ConversionError::Inexact)?;
+            let mantissa = magnitude.checked_mul(&factor).ok_or(ConversionError::Overflow)?;
+            Ok(DecimalParts { negative, mantissa, scale })
+        }
+
+        /// Returns the magnitude of this decimal in minor units of a currency with the
+        /// specified `base`, failing rather than rounding.
+        #[cfg(any(feature = "rust_decimal", feature = "bigdecimal", feature = "postgres", feature = "prost", test))]
+        pub fn to_minor(self, base: U256) -> Result<U256, ConversionError> {
+            let parts = self.trim(0);
+            if parts.mantissa.is_zero() {
+                return Ok(U256::zero());
+            }
+            let mut denominator = U256::from(1u8);
+            for _ in 0..parts.scale {
+                // the mantissa has no trailing zeros, so it can't be a multiple of such a power
+                denominator = denominator.checked_mul(&U256::from(10u8)).ok_or(ConversionError::Inexact)?;
+            }
+            // minor = mantissa * base / 10^scale, with the fraction reduced first to avoid overflow
+            let divisor = base.gcd(&denominator);
+            let (quotient, remainder) = parts.mantissa.div_rem(&(denominator / divisor));
+            if !remainder.is_zero() {
+                return Err(ConversionError::Inexact);
+            }
+            quotient.checked_mul(&(base / divisor)).ok_or(ConversionError::Overflow)
+        }
+    }
+
+    /// Returns the smallest `scale` such that `base` divides `10^scale`, along with the quotient,
+    /// or [`None`] if there is no such scale (i.e. `base` has a prime factor other than 2 or 5).
+    fn decimal_scale(base: U256) -> Option<(u32, U256)> {
+        let ten = U256::from(10u8);
+        let mut power = U256::from(1u8);
+        let mut scale = 0;
+        loop {
+            let (quotient, remainder) = power.div_rem(&base);
+            if remainder.is_zero() {
+                return Some((scale, quotient));
+            }
+            power = power.checked_mul(&ten)?;
+            scale += 1;
+        }
+    }
+
+    /// Conversions between [`Amount`]s and [`DecimalParts`], which the arrow integration does
+    /// not need.
+    #[cfg(any(feature = "rust_decimal", feature = "bigdecimal", feature = "postgres", feature = "prost", test))]
+    mod amount {
+        use num_traits::Zero;
+
+        use super::{ConversionError, DecimalParts};
+        use crate::amount::Amount;
+        use crate::currency::Currency;
+        use crate::parsing::is_signed;
+        use crate::safety;
+        use crate::u256::{backing_to_u256, u256_to_backing};
+
+        impl<C: Currency, Safety: safety::Safety> Amount<C, Safety> {
+            /// Expresses this [`Amount`] exactly as a decimal with [`Currency::decimal_digits`]
+            /// digits after the decimal point.
+            ///
+            /// Fails with [`ConversionError::Inexact`] if the [`Currency::BASE`] does not divide
+            /// a power of ten, since such amounts have no finite decimal representation.
+            pub(crate) fn decimal_parts(&self) -> Result<DecimalParts, ConversionError> {
+                let raw = self.raw_backing();
+                DecimalParts::from_minor(raw < C::Backing::zero(), backing_to_u256(raw), backing_to_u256(C::BASE))
+            }
+
+            /// Converts an exact decimal value into an [`Amount`], failing rather than rounding.
+            pub(crate) fn from_decimal_parts(parts: DecimalParts) -> Result<Self, ConversionError> {
+                let raw = parts.to_minor(backing_to_u256(C::BASE))?;
+                match u256_to_backing(raw, parts.negative) {
+                    Some(raw) => Ok(Amount::from_raw(raw)),
+                    None if parts.negative && !is_signed::<C::Backing>() => Err(ConversionError::Negative),
+                    None => Err(ConversionError::Overflow),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::{
+    amount::Amount,
+    currency::{Currency, ETH, MGA, MRU, SIGNEDUSD, USD},
+    dynamic::DynAmount,
+    u256::U256,
+};
+
+#[cfg(test)]
+use alloc::{format, string::ToString};
+
+#[cfg(test)]
+fn parts(negative: bool, mantissa: u64, scale: u32) -> DecimalParts {
+    DecimalParts {
+        negative,
+        mantissa: mantissa.into(),
+        scale,
+    }
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_decimal_parts() {
+    assert_eq!(Amount::<USD>::from_raw(123_45).decimal_parts(), Ok(parts(false, 12345, 2)));
+    assert_eq!(Amount::<SIGNEDUSD>::from_raw(-5).decimal_parts(), Ok(parts(true, 5, 2)));
+    assert_eq!(Amount::<MGA>::from_raw(7).decimal_parts(), Ok(parts(false, 14, 1)));
+    assert_eq!(Amount::<ETH>::from_raw(U256::MAX_VALUE).decimal_parts().unwrap().scale, 18);
+
+    assert_eq!(Amount::<USD>::from_decimal_parts(parts(false, 12345, 2)), Ok(Amount::from_raw(123_45)));
+    assert_eq!(Amount::<USD>::from_decimal_parts(parts(false, 1230000, 5)), Ok(Amount::from_raw(12_30)));
+    assert_eq!(Amount::<USD>::from_decimal_parts(parts(false, 7, 0)), Ok(Amount::from_raw(7_00)));
+    assert_eq!(Amount::<MGA>::from_decimal_parts(parts(false, 14, 1)), Ok(Amount::from_raw(7)));
+    assert_eq!(Amount::<SIGNEDUSD>::from_decimal_parts(parts(true, 5, 2)), Ok(Amount::from_raw(-5)));
+    assert_eq!(Amount::<SIGNEDUSD>::from_decimal_parts(parts(true, 0, 2)), Ok(Amount::from_raw(0)));
+    assert_eq!(
+        Amount::<SIGNEDUSD>::from_decimal_parts(parts(true, 9223372036854775808, 2)),
+        Ok(Amount::from_raw(i64::MIN))
+    );
+
+    assert_eq!(Amount::<USD>::from_decimal_parts(parts(false, 12345, 3)), Err(ConversionError::Inexact));
+    assert_eq!(Amount::<MGA>::from_decimal_parts(parts(false, 1, 1)), Err(ConversionError::Inexact));
+    assert_eq!(Amount::<USD>::from_decimal_parts(parts(false, 1, 100)), Err(ConversionError::Inexact));
+    assert_eq!(Amount::<USD>::from_decimal_parts(parts(true, 1, 2)), Err(ConversionError::Negative));
+    assert_eq!(
+        Amount::<USD>::from_decimal_parts(parts(false, u64::MAX, 0)),
+        Err(ConversionError::Overflow)
+    );
+}
+
+/// Checks that every representation of the first amounts of `C` means the same value, by
+/// writing their [`DecimalParts`] out as a decimal string.
+#[cfg(test)]
+fn check_representations_agree<C: Currency<Backing = u64>>() {
+    for raw in 0..=20 {
+        let amount = Amount::<C>::from_raw(raw);
+        let parts = amount.decimal_parts().unwrap();
+        let digits = format!("{:0>width$}", parts.mantissa.to_string(), width = parts.scale as usize + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - parts.scale as usize);
+        let decimal = format!("{}.{}", whole, fraction);
+        assert_eq!(amount.to_string(), format!("{} {}", decimal, C::SYMBOL));
+        assert_eq!(DynAmount::from(amount).to_string(), amount.to_string());
+        assert_eq!(Amount::<C>::from_decimal_str(&decimal), Ok(amount), "{}", decimal);
+        assert_eq!(amount.to_string().parse::<Amount<C>>(), Ok(amount));
+        assert_eq!(Amount::<C>::from_decimal_parts(parts), Ok(amount));
+    }
+}
+
+#[test]
+fn test_radix_representations_agree() {
+    check_representations_agree::<MGA>();
+    check_representations_agree::<MRU>();
+    assert_eq!(Amount::<MGA>::from_raw(17).to_string(), "3.4 Ar");
+    assert_eq!(Amount::<MGA>::from_decimal_str("3.4"), Ok(Amount::from_raw(17)));
+    assert_eq!(Amount::<MGA>::from_decimal_parts(parts(false, 34, 1)), Ok(Amount::from_raw(17)));
+}
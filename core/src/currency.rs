@@ -6,16 +6,17 @@
 //! cryptocurrencies.
 
 use crate::amount::*;
-use crate::u256::{u64_to_u256, U256};
+use crate::u256::{backing_to_u256, u64_to_u256, U256};
 
 /// Determines how an [`Amount`] in this [`Currency`] should be displayed when it is sent to a
 /// [`core::fmt::Debug`] or [`core::fmt::Display`] impl.
 ///
 /// See individual variants for a concise description.
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
 pub enum FormatStyle {
     /// Specifies that the symbol should prefix the amount with no space, like "$40.00". Common
     /// for major English-speaking locales.
+    #[default]
     PrefixAttached,
     /// Specifies that the symbol should suffix the amount with no space, like  "40.00€".
     /// Commonly used in non-English locales.
@@ -27,12 +28,6 @@ pub enum FormatStyle {
     SuffixSpaced,
 }
 
-impl Default for FormatStyle {
-    fn default() -> Self {
-        Self::PrefixAttached
-    }
-}
-
 /// Uniquely defines a particular currency, such as [`USD`], [`BTC`], or [`ETH`].
 pub trait Currency: Copy + Clone + PartialEq + Eq + PartialOrd + Ord + core::hash::Hash {
     /// Represents the underlying (signed or un-signed) primitive integer type used to
@@ -46,6 +41,10 @@ pub trait Currency: Copy + Clone + PartialEq + Eq + PartialOrd + Ord + core::has
     ///
     /// Some very rare currencies use a base other than 10, such as Malagasy ariary. For these
     /// you should use an appropriate base.
+    ///
+    /// The built-in [`MGA`] and [`MRU`] deliberately deviate from ISO-4217 here: ISO lists both
+    /// with two decimal digits, but their minor units are fifths, so their `BASE` is `5` and
+    /// they are written with the single decimal digit that fifths need (`0.4 Ar`).
     const BASE: Self::Backing;
 
     /// Specifies a 3-4 digit acronym or "code" that can be used as a short name for this
@@ -89,15 +88,66 @@ pub trait Currency: Copy + Clone + PartialEq + Eq + PartialOrd + Ord + core::has
     /// cryptocurrencies are included in ISO-4217.
     const IS_CRYPTO: bool;
 
+    /// Specifies the three-digit ISO-4217 numeric code of this [`Currency`], such as `840` for
+    /// [`USD`], which some systems (e.g. card networks and ISO 8583 messages) transmit instead of
+    /// the alphabetic [`CODE`](Currency::CODE).
+    ///
+    /// Defaults to the numeric code listed in [`iso4217`](crate::iso4217) for
+    /// [`CODE`](Currency::CODE) if `IS_ISO` is set, and to [`None`] otherwise.
+    const NUMERIC_CODE: Option<u16> = match Self::IS_ISO {
+        true => crate::iso4217::numeric_code(Self::CODE),
+        false => None,
+    };
+
     /// Returns the number of digits to the right of the decimal point for this [`Currency`].
     fn decimal_digits() -> usize {
-        let mut base = Self::BASE;
-        let mut digits = 0;
-        while base > 1.into() {
-            base /= 10.into();
-            digits += 1;
+        minor_digits(Self::BASE)
+    }
+}
+
+/// A runtime snapshot of the associated consts of a particular [`Currency`].
+///
+/// This is what the [`registry`](crate::registry) stores and what a
+/// [`DynAmount`](crate::DynAmount) carries around in place of a [`Currency`] type parameter.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct CurrencyInfo {
+    /// Corresponds with [`Currency::CODE`].
+    pub code: &'static str,
+    /// Corresponds with [`Currency::SYMBOL`].
+    pub symbol: &'static str,
+    /// Corresponds with [`Currency::PROPER_NAME`].
+    pub proper_name: &'static str,
+    /// Corresponds with [`Currency::STYLE`].
+    pub style: FormatStyle,
+    /// Corresponds with [`Currency::IS_ISO`].
+    pub is_iso: bool,
+    /// Corresponds with [`Currency::IS_CRYPTO`].
+    pub is_crypto: bool,
+    /// Corresponds with [`Currency::NUMERIC_CODE`].
+    pub numeric_code: Option<u16>,
+    /// Corresponds with [`Currency::BASE`], widened to a [`U256`].
+    pub base: U256,
+}
+
+impl CurrencyInfo {
+    /// Captures the [`CurrencyInfo`] of the [`Currency`] `C`.
+    pub fn of<C: Currency>() -> Self {
+        CurrencyInfo {
+            code: C::CODE,
+            symbol: C::SYMBOL,
+            proper_name: C::PROPER_NAME,
+            style: C::STYLE,
+            is_iso: C::IS_ISO,
+            is_crypto: C::IS_CRYPTO,
+            numeric_code: C::NUMERIC_CODE,
+            base: backing_to_u256(C::BASE),
         }
-        digits
+    }
+
+    /// Returns the number of digits to the right of the decimal point for this currency. See
+    /// [`Currency::decimal_digits`].
+    pub fn decimal_digits(&self) -> usize {
+        minor_digits(self.base)
     }
 }
 
@@ -127,8 +177,20 @@ pub trait Currency: Copy + Clone + PartialEq + Eq + PartialOrd + Ord + core::has
 ///   [`Currency`] should be displayed.
 /// - the seventh argument should be a boolean expression specifying whether or not this
 ///   [`Currency`] is part of ISO-4217.
-/// - the eighth and final argument should be a boolean expression specifying whether or not
-///   this [`Currency`] is a cryptocurrency.
+/// - the eighth argument should be a boolean expression specifying whether or not this
+///   [`Currency`] is a cryptocurrency.
+/// - an optional trailing `radix` declares that the base is not a power of ten but divides one,
+///   as for the Malagasy ariary, which is divided into five iraimbilanja. Amounts are still
+///   written as decimals, so 2 iraimbilanja are displayed and parsed as `0.4 Ar`.
+///
+/// The definition fails to compile if the base is not positive, if it is not a power of ten
+/// (or, with `radix`, a divisor of one), if the name of the currency is not made of ASCII
+/// uppercase letters and digits, or if it is marked as part of ISO-4217 but its code is not one
+/// of the [`iso4217::CODES`](crate::iso4217::CODES).
+///
+/// When the `serde` feature is enabled, the generated type serializes as its [`Currency::CODE`]
+/// and only deserializes from that same code, ignoring ASCII case, so e.g. a config field of type
+/// `EUR` accepts `"eur"` but rejects `"USD"`.
 #[macro_export]
 macro_rules! define_currency {
     (
@@ -139,7 +201,34 @@ macro_rules! define_currency {
         $proper_name:expr,
         $style:ident,
         $is_iso:expr,
-        $is_crypto:expr
+        $is_crypto:expr $(,)?
+    ) => {
+        $crate::define_currency!(@define $currency_name, $base_type, $base, $symbol, $proper_name, $style, $is_iso, $is_crypto, false);
+    };
+    (
+        $currency_name:ident,
+        $base_type:ty,
+        $base:expr,
+        $symbol:expr,
+        $proper_name:expr,
+        $style:ident,
+        $is_iso:expr,
+        $is_crypto:expr,
+        radix $(,)?
+    ) => {
+        $crate::define_currency!(@define $currency_name, $base_type, $base, $symbol, $proper_name, $style, $is_iso, $is_crypto, true);
+    };
+    (
+        @define
+        $currency_name:ident,
+        $base_type:ty,
+        $base:expr,
+        $symbol:expr,
+        $proper_name:expr,
+        $style:ident,
+        $is_iso:expr,
+        $is_crypto:expr,
+        $radix:literal
     ) => {
         #[doc = concat!($proper_name, " (", $symbol, ")")]
         #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -155,196 +244,485 @@ macro_rules! define_currency {
             const IS_ISO: bool = $is_iso;
             const IS_CRYPTO: bool = $is_crypto;
         }
+
+        const _: () = $crate::currency::__private::check::<$currency_name>(
+            <$base_type as $crate::currency::__private::ConstBase<$currency_name>>::BASE,
+            $radix,
+        );
+
+        $crate::__impl_currency_serde!($currency_name);
     };
 }
 
-define_currency!(USDC, u64, 1_000000, "USDC", "USD Coin", SuffixAttached, false, true);
-
-define_currency!(BTC, u64, 1_00000000, "BTC", "Bitcoin", SuffixSpaced, false, true);
-define_currency!(ETH, U256, u64_to_u256(1_000000000000000000), "ETH", "Ethereum", SuffixSpaced, false, true);
-
-define_currency!(SOL, u64, 1_000000000, "SOL", "Solana", SuffixSpaced, false, true);
-define_currency!(ALGO, u64, 1_000000, "ALGO", "Algorand", SuffixSpaced, false, true);
-define_currency!(ORCA, u64, 1_000000, "ORCA", "Orca", SuffixSpaced, false, true);
-define_currency!(AVAX, u64, 1_000000000, "AVAX", "Avalanche", SuffixSpaced, false, true);
-define_currency!(ZEC, u64, 1_00000000, "ZEC", "Zcash", SuffixSpaced, false, true);
-define_currency!(XMR, u64, 1_000000000000, "XMR", "Monero", SuffixSpaced, false, true);
-define_currency!(DOGE, u64, 1_000000, "DOGE", "Dogecoin", SuffixSpaced, false, true);
-define_currency!(LTC, u64, 1_000000, "LTC", "Litecoin", SuffixSpaced, false, true);
-define_currency!(MATIC, u64, 1_000000000000000000, "MATIC", "Polygon", SuffixSpaced, false, true);
-define_currency!(XLM, u64, 1_0000000, "XLM", "Stellar", SuffixSpaced, false, true);
-define_currency!(TAO, u128, 1000000000u128, "TAO", "Bittensor", SuffixSpaced, false, true);
-define_currency!(NEAR, u64, 1_000000000000000000, "NEAR", "Near Protocol", SuffixSpaced, false, true);
-
-define_currency!(ADA, u64, 1_000000, "ADA", "Cardano", SuffixSpaced, false, true);
-define_currency!(BOOK, u64, 1_000000, "BOOK", "$BOOK", SuffixSpaced, false, true);
-
-define_currency!(DOT, u128, 10000000000u128, "DOT", "Polkadot", SuffixSpaced, false, true);
-define_currency!(KSM, u128, 1000000000000u128, "KSM", "Kusama", SuffixSpaced, false, true);
-
-define_currency!(AAVE, U256, ETH::BASE, "AAVE", "Aave Token", SuffixSpaced, false, true);
-
-define_currency!(USD, u64, 1_00, "$", "United States Dollar", PrefixAttached, true, false);
-define_currency!(BAM, u64, 1_00, "KM", "Bosnia and Herzegovina Convertible Mark", SuffixSpaced, true, false);
-define_currency!(AED, u64, 1_00, "Dh", "United Arab Emirates Dirham", SuffixSpaced, true, false);
-define_currency!(AFN, u64, 1_00, "Af", "Afgan Afghani", SuffixSpaced, true, false);
-define_currency!(ALL, u64, 1_00, "Lek", "Albanien Lek", SuffixSpaced, true, false);
-define_currency!(AMD, u64, 1_00, "֏", "Armenian Dram", PrefixAttached, true, false);
-define_currency!(ANG, u64, 1_00, "ƒ", "Netherlands Antillean Guilder", PrefixAttached, true, false);
-define_currency!(AOA, u64, 1_00, "Kz", "Angolan Kwanza", SuffixSpaced, true, false);
-define_currency!(ARS, u64, 1_00, "$", "Argentine Peso", PrefixAttached, true, false);
-define_currency!(AUD, u64, 1_00, "$", "Australian Dollar", PrefixAttached, true, false);
-define_currency!(AWG, u64, 1_00, "ƒ", "Aruban Florin", PrefixAttached, true, false);
-define_currency!(AZN, u64, 1_00, "₼", "Azerbaijani Manat", PrefixAttached, true, false);
-define_currency!(BBD, u64, 1_00, "$", "Barbados Dollar", PrefixAttached, true, false);
-define_currency!(BDT, u64, 1_00, "৳", "Bangladeshi Taka", PrefixAttached, true, false);
-define_currency!(BGN, u64, 1_00, "Lev", "Bulgarian Lev", SuffixSpaced, true, false);
-define_currency!(BHD, u64, 1_000, "BD", "Bahraini Dinar", SuffixSpaced, true, false);
-define_currency!(BIF, u64, 1_00, "Fr", "Burundian Franc", SuffixSpaced, true, false);
-define_currency!(BMD, u64, 1_00, "$", "Berumdian Dollar", PrefixAttached, true, false);
-define_currency!(BND, u64, 1_00, "$", "Brunei Dollar", PrefixAttached, true, false);
-define_currency!(BOB, u64, 1_00, "Bs", "Boliviano", SuffixSpaced, true, false);
-define_currency!(BOV, u64, 1_00, "BOV", "Bolivian Mvdol", SuffixSpaced, true, false);
-define_currency!(BRL, u64, 1_00, "R$", "Brazilian Real", SuffixSpaced, true, false);
-define_currency!(BSD, u64, 1_00, "$", "Bahamian Dollar", PrefixAttached, true, false);
-define_currency!(BTN, u64, 1_00, "Nu", "Bhutanese Ngultrum", SuffixSpaced, true, false);
-define_currency!(BWP, u64, 1_00, "P", "Botswanna Pula", SuffixSpaced, true, false);
-define_currency!(BYN, u64, 1_00, "Rbl", "Belarusian Ruble", SuffixSpaced, true, false);
-define_currency!(BZD, u64, 1_00, "$", "Belize Dollar", PrefixAttached, true, false);
-define_currency!(CAD, u64, 1_00, "$", "Canadian Dollar", PrefixAttached, true, false);
-define_currency!(CDF, u64, 1_00, "Fr", "Congloese Franc", SuffixSpaced, true, false);
-define_currency!(CHE, u64, 1_00, "CHE", "WIR Euro", SuffixSpaced, true, false);
-define_currency!(CHF, u64, 1_00, "Fr", "Swiss Franc", SuffixSpaced, true, false);
-define_currency!(CHW, u64, 1_00, "CHW", "WIR Franc", SuffixSpaced, true, false);
-define_currency!(CLF, u64, 1_00, "CLF", "Unidad de Fomento", SuffixSpaced, true, false);
-define_currency!(CLP, u64, 1_00, "$", "Chilean Peso", PrefixAttached, true, false);
-define_currency!(COP, u64, 1_00, "$", "Colombian Peso", PrefixAttached, true, false);
-define_currency!(COU, u64, 1_00, "COU", "Unidad de Valor Real (UVR)", SuffixSpaced, true, false);
-define_currency!(CRC, u64, 1_00, "₡", "Costa Rican Colon", PrefixAttached, true, false);
-define_currency!(CUC, u64, 1_00, "CUC", "Cuban Convertible Peso", SuffixSpaced, true, false);
-define_currency!(CUP, u64, 1_00, "$", "Cuban Peso", PrefixAttached, true, false);
-define_currency!(CVE, u64, 1_00, "$", "Cape Verdean Escudo", PrefixAttached, true, false);
-define_currency!(CZK, u64, 1_00, "Kč", "Czech Koruna", SuffixSpaced, true, false);
-define_currency!(DJF, u64, 1_00, "Fr", "Dijiboutian Franc", SuffixSpaced, true, false);
-define_currency!(DKK, u64, 1_00, "kr", "Danish Krone", SuffixSpaced, true, false);
-define_currency!(DOP, u64, 1_00, "$", "Dominican Peso", PrefixAttached, true, false);
-define_currency!(DZD, u64, 1_00, "DA", "Algerian Dinar", SuffixSpaced, true, false);
-define_currency!(EGP, u64, 1_00, "LE", "Egyptian Pound", SuffixSpaced, true, false);
-define_currency!(ERN, u64, 1_00, "Nkf", "Eritrean Nakfa", SuffixSpaced, true, false);
-define_currency!(ETB, u64, 1_00, "Br", "Ethiopian Birr", SuffixSpaced, true, false);
-define_currency!(EUR, u64, 1_00, "€", "Euro", SuffixAttached, true, false);
-define_currency!(FJD, u64, 1_00, "$", "Fiji Dollar", PrefixAttached, true, false);
-define_currency!(FKP, u64, 1_00, "£", "Falkland Islands Pound", PrefixAttached, true, false);
-define_currency!(GBP, u64, 1_00, "£", "Pound Sterling", PrefixAttached, true, false);
-define_currency!(GEL, u64, 1_00, "₾", "Georgian Iari", PrefixAttached, true, false);
-define_currency!(GHS, u64, 1_00, "₵", "Ghanaian Cedi", PrefixAttached, true, false);
-define_currency!(GIP, u64, 1_00, "£", "Gibralter Pound", PrefixAttached, true, false);
-define_currency!(GMD, u64, 1_00, "D", "Gambian Dalasi", SuffixSpaced, true, false);
-define_currency!(GNF, u64, 1_00, "Fr", "Guinean Franc", SuffixSpaced, true, false);
-define_currency!(GTQ, u64, 1_00, "Q", "Guatemalan Quetzal", SuffixSpaced, true, false);
-define_currency!(HKD, u64, 1_00, "$", "Hong Kong Dollar", PrefixAttached, true, false);
-define_currency!(HNL, u64, 1_00, "L", "Honduran Lempira", SuffixSpaced, true, false);
-define_currency!(HTG, u64, 1_00, "G", "Haitian Gourde", SuffixSpaced, true, false);
-define_currency!(HUF, u64, 1_00, "Ft", "Hungarian Forint", SuffixSpaced, true, false);
-define_currency!(IDR, u64, 1_00, "Rp", "Indonesian Rupiah", SuffixSpaced, true, false);
-define_currency!(ILS, u64, 1_00, "₪", "Israeli New Shekel", PrefixAttached, true, false);
-define_currency!(INR, u64, 1_00, "₹", "Indian Rupee", PrefixAttached, true, false);
-define_currency!(IQD, u64, 1_000, "ID", "Iraqi Dinar", SuffixSpaced, true, false);
-define_currency!(IRR, u64, 1, "Rl", "Iranian Rial", SuffixSpaced, true, false);
-define_currency!(ISK, u64, 1_00, "kr", "Icelandic Króna", SuffixSpaced, true, false);
-define_currency!(JMD, u64, 1_00, "$", "Jamaican Dollar", PrefixAttached, true, false);
-define_currency!(JOD, u64, 1_00, "JD", "Jordanian Dinar", SuffixSpaced, true, false);
-define_currency!(JPY, u64, 1_00, "¥", "Japanese Yen", SuffixAttached, true, false);
-define_currency!(KES, u64, 1_00, "Sh", "Kenyan Shilling", SuffixSpaced, true, false);
-define_currency!(KGS, u64, 1_00, "som", "Kyrgyzstani Som", SuffixSpaced, true, false);
-define_currency!(KHR, u64, 1_00, "CR", "Cambodian Riel", SuffixSpaced, true, false);
-define_currency!(KMF, u64, 1_00, "Fr", "Comoro Franc", SuffixSpaced, true, false);
-define_currency!(KPW, u64, 1_00, "₩", "North Korean Won", SuffixSpaced, true, false);
-define_currency!(KRW, u64, 1_00, "₩", "South Korean Won", SuffixSpaced, true, false);
-define_currency!(KWD, u64, 1_000, "KD", "Kuwaiti Dinar", SuffixSpaced, true, false);
-define_currency!(KYD, u64, 1_00, "$", "Caymen Islands Dollar", PrefixAttached, true, false);
-define_currency!(KZT, u64, 1_00, "₸", "Kazakhstani Tenge", PrefixAttached, true, false);
-define_currency!(LAK, u64, 1_00, "₭", "Lao Kip", PrefixAttached, true, false);
-define_currency!(LBP, u64, 1_00, "LL", "Lebanese Pound", SuffixSpaced, true, false);
-define_currency!(LKR, u64, 1_00, "Re", "Sri Lankan Rupee", SuffixSpaced, true, false);
-define_currency!(LRD, u64, 1_00, "$", "Liberian Dollar", PrefixAttached, true, false);
-define_currency!(LSL, u64, 1_00, "L", "Lesotho Loti", SuffixSpaced, true, false);
-define_currency!(LYD, u64, 1_000, "LD", "Libyan Dinar", SuffixSpaced, true, false);
-define_currency!(MAD, u64, 1_00, "DH", "Moroccan Dirham", SuffixSpaced, true, false);
-define_currency!(MDL, u64, 1_00, "Leu", "Moldovan Leu", SuffixSpaced, true, false);
-define_currency!(MGA, u64, 5, "Ar", "Malagasy Ariary", SuffixSpaced, true, false);
-define_currency!(MKD, u64, 1_00, "DEN", "Macedonian Denar", SuffixSpaced, true, false);
-define_currency!(MMK, u64, 1_00, "K", "Myanmar Kyat", SuffixSpaced, true, false);
-define_currency!(MNT, u64, 1_00, "₮", "Mongolian Tögrög", PrefixAttached, true, false);
-define_currency!(MOP, u64, 1_00, "MOP$", "Macanese Pataca", SuffixSpaced, true, false);
-define_currency!(MRU, u64, 5, "UM", "Mauritanian Ouguiya", SuffixSpaced, true, false);
-define_currency!(MUR, u64, 1_00, "Re", "Mauritian Rupee", SuffixSpaced, true, false);
-define_currency!(MVR, u64, 1_00, "Rf", "Maldivian Rufiyaa", SuffixSpaced, true, false);
-define_currency!(MWK, u64, 1_00, "K", "Malawian Kwacha", SuffixSpaced, true, false);
-define_currency!(MXN, u64, 1_00, "$", "Mexican Peso", SuffixSpaced, true, false);
-define_currency!(MXV, u64, 1_00, "MXV", "Mexican Unidad de Inversion (UDI)", SuffixSpaced, true, false);
-define_currency!(MYR, u64, 1_00, "RM", "Malaysian Ringgit", SuffixSpaced, true, false);
-define_currency!(MZN, u64, 1_00, "Mt", "Mozambican Metical", SuffixSpaced, true, false);
-define_currency!(NAD, u64, 1_00, "$", "Namibian Dollar", PrefixAttached, true, false);
-define_currency!(NGN, u64, 1_00, "₦", "Nigerian Naira", SuffixSpaced, true, false);
-define_currency!(NIO, u64, 1_00, "C$", "Nicaraguan Córdoba", SuffixSpaced, true, false);
-define_currency!(NOK, u64, 1_00, "kr", "Norwegian Krone", SuffixSpaced, true, false);
-define_currency!(NPR, u64, 1_00, "Re", "Nepalese Rupee", SuffixSpaced, true, false);
-define_currency!(NZD, u64, 1_00, "$", "New Zealand Dollar", PrefixAttached, true, false);
-define_currency!(OMR, u64, 1_000, "RO", "Omani Rial", SuffixSpaced, true, false);
-define_currency!(PAB, u64, 1_00, "B/", "Panamanian Balboa", SuffixSpaced, true, false);
-define_currency!(PEN, u64, 1_00, "S/", "Peruvian Sol", SuffixSpaced, true, false);
-define_currency!(PGK, u64, 1_00, "K", "Papua New Guinean Kina", SuffixSpaced, true, false);
-define_currency!(PHP, u64, 1_00, "₱", "Philippine Peso", PrefixAttached, true, false);
-define_currency!(PKR, u64, 1_00, "Re", "Pakistani Rupee", SuffixSpaced, true, false);
-define_currency!(PLN, u64, 1_00, "zł", "Polish Złoty", SuffixSpaced, true, false);
-define_currency!(PYG, u64, 1_00, "₲", "Paraguayan Guarani", SuffixSpaced, true, false);
-define_currency!(QAR, u64, 1_00, "QR", "Qatari Riyal", SuffixSpaced, true, false);
-define_currency!(RON, u64, 1_00, "Leu", "Romanian Leu", SuffixSpaced, true, false);
-define_currency!(RSD, u64, 1_00, "DIN", "Serbian Dinar", SuffixSpaced, true, false);
-define_currency!(CNY, u64, 1_0, "¥", "Chinese Yuan", PrefixAttached, true, false);
-define_currency!(RUB, u64, 1_00, "₽", "Russian Ruble", PrefixAttached, true, false);
-define_currency!(RWF, u64, 1_00, "Fr", "Rwandan Franc", SuffixSpaced, true, false);
-define_currency!(SAR, u64, 1_00, "Rl", "Saudi Riyal", SuffixSpaced, true, false);
-define_currency!(SBD, u64, 1_00, "$", "Solomon Islands Dollar", PrefixAttached, true, false);
-define_currency!(SCR, u64, 1_00, "Re", "Seychelles Rupee", SuffixSpaced, true, false);
-define_currency!(SDG, u64, 1_00, "LS", "Sudanese Pound", SuffixSpaced, true, false);
-define_currency!(SEK, u64, 1_00, "kr", "Swedish Krona", SuffixSpaced, true, false);
-define_currency!(SGD, u64, 1_00, "$", "Singapore Dollar", PrefixAttached, true, false);
-define_currency!(SHP, u64, 1_00, "£", "Saint Helena Pound", PrefixAttached, true, false);
-define_currency!(SLE, u64, 1_00, "Le", "Sierra Leonean Leone", SuffixSpaced, true, false);
-define_currency!(SOS, u64, 1_00, "Sh", "Somali Shilling", SuffixSpaced, true, false);
-define_currency!(SRD, u64, 1_00, "$", "Surinamese Dollar", PrefixAttached, true, false);
-define_currency!(SSP, u64, 1_00, "SSP", "South Sudanese Pound", SuffixSpaced, true, false);
-define_currency!(STN, u64, 1_00, "Db", "São Tomé and Príncipe Dobra", SuffixSpaced, true, false);
-define_currency!(SYP, u64, 1_00, "LS", "Syrian Pound", SuffixSpaced, true, false);
-define_currency!(SZL, u64, 1_00, "L", "Swazi Lilangeni", SuffixSpaced, true, false);
-define_currency!(THB, u64, 1_00, "฿", "Thai Baht", PrefixAttached, true, false);
-define_currency!(TJS, u64, 1_00, "SM", "Tajikistani Somoni", SuffixSpaced, true, false);
-define_currency!(TMT, u64, 1_00, "m", "Turkmenistan Manat", SuffixSpaced, true, false);
-define_currency!(TND, u64, 1_000, "DT", "Tunisian Dinar", SuffixSpaced, true, false);
-define_currency!(TOP, u64, 1_00, "T$", "Tongan Paʻanga", SuffixSpaced, true, false);
-define_currency!(TRY, u64, 1_00, "₺", "Turkish Lira", SuffixAttached, true, false);
-define_currency!(TTD, u64, 1_00, "$", "Trinidad and Tobago Dollar", PrefixAttached, true, false);
-define_currency!(TWD, u64, 1_00, "$", "New Taiwan Dollar", PrefixAttached, true, false);
-define_currency!(TZS, u64, 1_00, "Sh", "Tanzanian Shilling", SuffixSpaced, true, false);
-define_currency!(UAH, u64, 1_00, "₴", "Ukrainian Hryvnia", SuffixAttached, true, false);
-define_currency!(UGX, u64, 1, "Sh", "Ugandan Shilling", SuffixSpaced, true, false);
-define_currency!(UYU, u64, 1_00, "$", "Uruguayan Peso", PrefixAttached, true, false);
-define_currency!(UZS, u64, 1_00, "soum", "Uzbekistan Sum", SuffixSpaced, true, false);
-define_currency!(VED, u64, 1_00, "Bs.D", "Venezuelan Digital Bolívar", SuffixSpaced, true, false);
-define_currency!(VES, u64, 1_00, "Bs.S", "Venezuelan Sovereign Bolívar", SuffixSpaced, true, false);
-define_currency!(VND, u64, 1_0, "₫", "Vietnamese đồng", SuffixSpaced, true, false);
-define_currency!(VUV, u64, 1_00, "VT", "Vanuatu Vatu", SuffixSpaced, true, false);
-define_currency!(WST, u64, 1_00, "$", "Samoan Tālā", PrefixAttached, true, false);
-define_currency!(XAF, u64, 1_00, "Fr", "Central African CFA Franc", SuffixSpaced, true, false);
-define_currency!(XAG, u64, 1_00, "t oz", "Silver (Troy Ounce)", SuffixSpaced, true, false);
-define_currency!(XAU, u64, 1_00, "t oz", "Gold (Troy Ounce", SuffixSpaced, true, false);
-define_currency!(XCD, u64, 1_00, "$", "East Caribbean Dollar", SuffixSpaced, true, false);
-define_currency!(XOF, u64, 1_00, "Fr", "West African CFA Franc", SuffixSpaced, true, false);
-define_currency!(XPD, u64, 1_00, "t oz", "Palladium (Troy Ounce)", SuffixSpaced, true, false);
-define_currency!(XPF, u64, 1_00, "Fr", "CFP Franc", SuffixSpaced, true, false);
-define_currency!(XPT, u64, 1_00, "t oz", "Platinum (Troy Ounce)", SuffixSpaced, true, false);
-define_currency!(YER, u64, 1_00, "Rl", "Yemeni Rial", SuffixSpaced, true, false);
-define_currency!(ZAR, u64, 1_00, "R", "South African Rand", SuffixSpaced, true, false);
-define_currency!(ZMW, u64, 1_00, "K", "Zambian Kwacha", SuffixSpaced, true, false);
+/// Support for the checks that [`define_currency!`](crate::define_currency) performs at compile
+/// time.
+#[doc(hidden)]
+pub mod __private {
+    use super::Currency;
+    use crate::iso4217::is_iso_code;
+    use crate::literal::{div_rem_small, from_u128};
+    use crate::u256::U256;
+
+    /// Exposes the [`Currency::BASE`] of `C` to constant evaluation as little-endian 64-bit
+    /// limbs, or [`None`] if it is negative.
+    #[diagnostic::on_unimplemented(
+        message = "the base of `{C}` cannot be checked at compile time",
+        note = "`define_currency!` supports currencies backed by `u32`, `u64`, `u128`, `i64`, `i128` or `U256`"
+    )]
+    pub trait ConstBase<C: Currency> {
+        /// The magnitude of [`Currency::BASE`], if it is not negative.
+        const BASE: Option<[u64; 4]>;
+    }
+
+    macro_rules! impl_const_base {
+        ($($ty:ty),*) => {
+            $(
+                impl<C: Currency<Backing = $ty>> ConstBase<C> for $ty {
+                    #[allow(unused_comparisons)]
+                    const BASE: Option<[u64; 4]> = match C::BASE < 0 {
+                        true => None,
+                        false => Some(from_u128(C::BASE as u128)),
+                    };
+                }
+            )*
+        };
+    }
+
+    impl_const_base!(u32, u64, u128, i64, i128);
+
+    impl<C: Currency<Backing = U256>> ConstBase<C> for U256 {
+        const BASE: Option<[u64; 4]> = Some(C::BASE.0 .0);
+    }
+
+    const fn is_one(n: [u64; 4]) -> bool {
+        n[0] == 1 && n[1] == 0 && n[2] == 0 && n[3] == 0
+    }
+
+    /// Fails to compile (when evaluated in a constant) unless the [`Currency::BASE`] of `C`
+    /// is positive and a power of ten, or at least two and a divisor of a power of ten if it is a
+    /// non-decimal `radix`, its
+    /// [`Currency::CODE`] consists of ASCII uppercase letters and digits, and it is an ISO-4217
+    /// code if [`Currency::IS_ISO`] is set.
+    pub const fn check<C: Currency>(base: Option<[u64; 4]>, radix: bool) {
+        let Some(mut base) = base else {
+            panic!("the BASE of a currency must be positive");
+        };
+        assert!(base[0] | base[1] | base[2] | base[3] != 0, "the BASE of a currency must be positive");
+        if radix {
+            assert!(!is_one(base), "the BASE of a currency with a radix must be at least 2");
+            // amounts are written as decimals, so each minor unit must have a finite expansion
+            while !is_one(base) {
+                base = match (div_rem_small(base, 2), div_rem_small(base, 5)) {
+                    ((quotient, 0), _) | (_, (quotient, 0)) => quotient,
+                    _ => panic!("the BASE of a currency with a radix must divide a power of ten"),
+                };
+            }
+        } else {
+            while !is_one(base) {
+                let (quotient, remainder) = div_rem_small(base, 10);
+                assert!(remainder == 0, "the BASE of a currency must be a power of ten, or declared with `radix`");
+                base = quotient;
+            }
+        }
+        let code = C::CODE.as_bytes();
+        assert!(!code.is_empty(), "currency codes must consist of ASCII uppercase letters and digits");
+        let mut i = 0;
+        while i < code.len() {
+            assert!(
+                code[i].is_ascii_uppercase() || code[i].is_ascii_digit(),
+                "currency codes must consist of ASCII uppercase letters and digits"
+            );
+            i += 1;
+        }
+        assert!(!C::IS_ISO || is_iso_code(C::CODE), "IS_ISO is set but CODE is not an ISO-4217 code");
+    }
+}
+
+/// Implements `Serialize` and `Deserialize` for a type generated by
+/// [`define_currency!`](crate::define_currency), or nothing when the `serde` feature is
+/// disabled. This is a separate macro so the feature is checked in this crate rather than in the
+/// crate invoking [`define_currency!`](crate::define_currency).
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_currency_serde {
+    ($currency_name:ident) => {
+        impl $crate::serde_integration::__private::Serialize for $currency_name {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::serde_integration::__private::Serializer,
+            {
+                $crate::serde_integration::serialize_currency::<Self, S>(serializer)
+            }
+        }
+
+        impl<'de> $crate::serde_integration::__private::Deserialize<'de> for $currency_name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::serde_integration::__private::Deserializer<'de>,
+            {
+                $crate::serde_integration::deserialize_currency::<Self, D>(deserializer).map(|()| $currency_name)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_currency_serde {
+    ($currency_name:ident) => {};
+}
+
+/// Invokes the macro `$callback` with the definition of every currency built into this crate,
+/// as a comma-separated list of parenthesized [`define_currency!`](crate::define_currency)
+/// arguments such as `(USD, u64, 1_00, "$", "United States Dollar", PrefixAttached, true, false)`.
+///
+/// This table is the single source of truth for the built-in currencies: it generates their
+/// types, the [`registry`](crate::registry) of their [`CurrencyInfo`] and the currencies that
+/// the `amt!` macro parses at expansion time. A base may be written in terms of items of
+/// [`currency`](crate::currency) such as `u64_to_u256` or another currency's `BASE`, so
+/// callbacks elsewhere should only rely on the names and literal fields of the rows.
+#[macro_export]
+macro_rules! currencies_table {
+    ($callback:ident) => {
+        $callback! {
+            (USDC, u64, 1_000000, "USDC", "USD Coin", SuffixAttached, false, true),
+
+            (BTC, u64, 1_00000000, "BTC", "Bitcoin", SuffixSpaced, false, true),
+            (ETH, U256, u64_to_u256(1_000000000000000000), "ETH", "Ethereum", SuffixSpaced, false, true),
+
+            (SOL, u64, 1_000000000, "SOL", "Solana", SuffixSpaced, false, true),
+            (ALGO, u64, 1_000000, "ALGO", "Algorand", SuffixSpaced, false, true),
+            (ORCA, u64, 1_000000, "ORCA", "Orca", SuffixSpaced, false, true),
+            (AVAX, u64, 1_000000000, "AVAX", "Avalanche", SuffixSpaced, false, true),
+            (ZEC, u64, 1_00000000, "ZEC", "Zcash", SuffixSpaced, false, true),
+            (XMR, u64, 1_000000000000, "XMR", "Monero", SuffixSpaced, false, true),
+            (DOGE, u64, 1_000000, "DOGE", "Dogecoin", SuffixSpaced, false, true),
+            (LTC, u64, 1_000000, "LTC", "Litecoin", SuffixSpaced, false, true),
+            (MATIC, u64, 1_000000000000000000, "MATIC", "Polygon", SuffixSpaced, false, true),
+            (XLM, u64, 1_0000000, "XLM", "Stellar", SuffixSpaced, false, true),
+            (TAO, u128, 1000000000u128, "TAO", "Bittensor", SuffixSpaced, false, true),
+            (NEAR, u64, 1_000000000000000000, "NEAR", "Near Protocol", SuffixSpaced, false, true),
+
+            (ADA, u64, 1_000000, "ADA", "Cardano", SuffixSpaced, false, true),
+            (BOOK, u64, 1_000000, "BOOK", "$BOOK", SuffixSpaced, false, true),
+
+            (DOT, u128, 10000000000u128, "DOT", "Polkadot", SuffixSpaced, false, true),
+            (KSM, u128, 1000000000000u128, "KSM", "Kusama", SuffixSpaced, false, true),
+
+            (AAVE, U256, ETH::BASE, "AAVE", "Aave Token", SuffixSpaced, false, true),
+
+            (USD, u64, 1_00, "$", "United States Dollar", PrefixAttached, true, false),
+            (BAM, u64, 1_00, "KM", "Bosnia and Herzegovina Convertible Mark", SuffixSpaced, true, false),
+            (AED, u64, 1_00, "Dh", "United Arab Emirates Dirham", SuffixSpaced, true, false),
+            (AFN, u64, 1_00, "Af", "Afgan Afghani", SuffixSpaced, true, false),
+            (ALL, u64, 1_00, "Lek", "Albanien Lek", SuffixSpaced, true, false),
+            (AMD, u64, 1_00, "֏", "Armenian Dram", PrefixAttached, true, false),
+            (ANG, u64, 1_00, "ƒ", "Netherlands Antillean Guilder", PrefixAttached, true, false),
+            (AOA, u64, 1_00, "Kz", "Angolan Kwanza", SuffixSpaced, true, false),
+            (ARS, u64, 1_00, "$", "Argentine Peso", PrefixAttached, true, false),
+            (AUD, u64, 1_00, "$", "Australian Dollar", PrefixAttached, true, false),
+            (AWG, u64, 1_00, "ƒ", "Aruban Florin", PrefixAttached, true, false),
+            (AZN, u64, 1_00, "₼", "Azerbaijani Manat", PrefixAttached, true, false),
+            (BBD, u64, 1_00, "$", "Barbados Dollar", PrefixAttached, true, false),
+            (BDT, u64, 1_00, "৳", "Bangladeshi Taka", PrefixAttached, true, false),
+            (BGN, u64, 1_00, "Lev", "Bulgarian Lev", SuffixSpaced, true, false),
+            (BHD, u64, 1_000, "BD", "Bahraini Dinar", SuffixSpaced, true, false),
+            (BIF, u64, 1, "Fr", "Burundian Franc", SuffixSpaced, true, false),
+            (BMD, u64, 1_00, "$", "Berumdian Dollar", PrefixAttached, true, false),
+            (BND, u64, 1_00, "$", "Brunei Dollar", PrefixAttached, true, false),
+            (BOB, u64, 1_00, "Bs", "Boliviano", SuffixSpaced, true, false),
+            (BOV, u64, 1_00, "BOV", "Bolivian Mvdol", SuffixSpaced, true, false),
+            (BRL, u64, 1_00, "R$", "Brazilian Real", SuffixSpaced, true, false),
+            (BSD, u64, 1_00, "$", "Bahamian Dollar", PrefixAttached, true, false),
+            (BTN, u64, 1_00, "Nu", "Bhutanese Ngultrum", SuffixSpaced, true, false),
+            (BWP, u64, 1_00, "P", "Botswanna Pula", SuffixSpaced, true, false),
+            (BYN, u64, 1_00, "Rbl", "Belarusian Ruble", SuffixSpaced, true, false),
+            (BZD, u64, 1_00, "$", "Belize Dollar", PrefixAttached, true, false),
+            (CAD, u64, 1_00, "$", "Canadian Dollar", PrefixAttached, true, false),
+            (CDF, u64, 1_00, "Fr", "Congloese Franc", SuffixSpaced, true, false),
+            (CHE, u64, 1_00, "CHE", "WIR Euro", SuffixSpaced, true, false),
+            (CHF, u64, 1_00, "Fr", "Swiss Franc", SuffixSpaced, true, false),
+            (CHW, u64, 1_00, "CHW", "WIR Franc", SuffixSpaced, true, false),
+            (CLF, u64, 1_0000, "CLF", "Unidad de Fomento", SuffixSpaced, true, false),
+            (CLP, u64, 1, "$", "Chilean Peso", PrefixAttached, true, false),
+            (CNY, u64, 1_00, "¥", "Chinese Yuan", PrefixAttached, true, false),
+            (COP, u64, 1_00, "$", "Colombian Peso", PrefixAttached, true, false),
+            (COU, u64, 1_00, "COU", "Unidad de Valor Real (UVR)", SuffixSpaced, true, false),
+            (CRC, u64, 1_00, "₡", "Costa Rican Colon", PrefixAttached, true, false),
+            (CUC, u64, 1_00, "CUC", "Cuban Convertible Peso", SuffixSpaced, true, false),
+            (CUP, u64, 1_00, "$", "Cuban Peso", PrefixAttached, true, false),
+            (CVE, u64, 1_00, "$", "Cape Verdean Escudo", PrefixAttached, true, false),
+            (CZK, u64, 1_00, "Kč", "Czech Koruna", SuffixSpaced, true, false),
+            (DJF, u64, 1, "Fr", "Dijiboutian Franc", SuffixSpaced, true, false),
+            (DKK, u64, 1_00, "kr", "Danish Krone", SuffixSpaced, true, false),
+            (DOP, u64, 1_00, "$", "Dominican Peso", PrefixAttached, true, false),
+            (DZD, u64, 1_00, "DA", "Algerian Dinar", SuffixSpaced, true, false),
+            (EGP, u64, 1_00, "LE", "Egyptian Pound", SuffixSpaced, true, false),
+            (ERN, u64, 1_00, "Nkf", "Eritrean Nakfa", SuffixSpaced, true, false),
+            (ETB, u64, 1_00, "Br", "Ethiopian Birr", SuffixSpaced, true, false),
+            (EUR, u64, 1_00, "€", "Euro", SuffixAttached, true, false),
+            (FJD, u64, 1_00, "$", "Fiji Dollar", PrefixAttached, true, false),
+            (FKP, u64, 1_00, "£", "Falkland Islands Pound", PrefixAttached, true, false),
+            (GBP, u64, 1_00, "£", "Pound Sterling", PrefixAttached, true, false),
+            (GEL, u64, 1_00, "₾", "Georgian Iari", PrefixAttached, true, false),
+            (GHS, u64, 1_00, "₵", "Ghanaian Cedi", PrefixAttached, true, false),
+            (GIP, u64, 1_00, "£", "Gibralter Pound", PrefixAttached, true, false),
+            (GMD, u64, 1_00, "D", "Gambian Dalasi", SuffixSpaced, true, false),
+            (GNF, u64, 1, "Fr", "Guinean Franc", SuffixSpaced, true, false),
+            (GTQ, u64, 1_00, "Q", "Guatemalan Quetzal", SuffixSpaced, true, false),
+            (GYD, u64, 1_00, "$", "Guyanese Dollar", PrefixAttached, true, false),
+            (HKD, u64, 1_00, "$", "Hong Kong Dollar", PrefixAttached, true, false),
+            (HNL, u64, 1_00, "L", "Honduran Lempira", SuffixSpaced, true, false),
+            (HTG, u64, 1_00, "G", "Haitian Gourde", SuffixSpaced, true, false),
+            (HUF, u64, 1_00, "Ft", "Hungarian Forint", SuffixSpaced, true, false),
+            (IDR, u64, 1_00, "Rp", "Indonesian Rupiah", SuffixSpaced, true, false),
+            (ILS, u64, 1_00, "₪", "Israeli New Shekel", PrefixAttached, true, false),
+            (INR, u64, 1_00, "₹", "Indian Rupee", PrefixAttached, true, false),
+            (IQD, u64, 1_000, "ID", "Iraqi Dinar", SuffixSpaced, true, false),
+            (IRR, u64, 1_00, "Rl", "Iranian Rial", SuffixSpaced, true, false),
+            (ISK, u64, 1, "kr", "Icelandic Króna", SuffixSpaced, true, false),
+            (JMD, u64, 1_00, "$", "Jamaican Dollar", PrefixAttached, true, false),
+            (JOD, u64, 1_000, "JD", "Jordanian Dinar", SuffixSpaced, true, false),
+            (JPY, u64, 1, "¥", "Japanese Yen", SuffixAttached, true, false),
+            (KES, u64, 1_00, "Sh", "Kenyan Shilling", SuffixSpaced, true, false),
+            (KGS, u64, 1_00, "som", "Kyrgyzstani Som", SuffixSpaced, true, false),
+            (KHR, u64, 1_00, "CR", "Cambodian Riel", SuffixSpaced, true, false),
+            (KMF, u64, 1, "Fr", "Comoro Franc", SuffixSpaced, true, false),
+            (KPW, u64, 1_00, "₩", "North Korean Won", SuffixSpaced, true, false),
+            (KRW, u64, 1, "₩", "South Korean Won", SuffixSpaced, true, false),
+            (KWD, u64, 1_000, "KD", "Kuwaiti Dinar", SuffixSpaced, true, false),
+            (KYD, u64, 1_00, "$", "Caymen Islands Dollar", PrefixAttached, true, false),
+            (KZT, u64, 1_00, "₸", "Kazakhstani Tenge", PrefixAttached, true, false),
+            (LAK, u64, 1_00, "₭", "Lao Kip", PrefixAttached, true, false),
+            (LBP, u64, 1_00, "LL", "Lebanese Pound", SuffixSpaced, true, false),
+            (LKR, u64, 1_00, "Re", "Sri Lankan Rupee", SuffixSpaced, true, false),
+            (LRD, u64, 1_00, "$", "Liberian Dollar", PrefixAttached, true, false),
+            (LSL, u64, 1_00, "L", "Lesotho Loti", SuffixSpaced, true, false),
+            (LYD, u64, 1_000, "LD", "Libyan Dinar", SuffixSpaced, true, false),
+            (MAD, u64, 1_00, "DH", "Moroccan Dirham", SuffixSpaced, true, false),
+            (MDL, u64, 1_00, "Leu", "Moldovan Leu", SuffixSpaced, true, false),
+            // divided into fifths rather than the hundredths ISO-4217 lists, see `Currency::BASE`
+            (MGA, u64, 5, "Ar", "Malagasy Ariary", SuffixSpaced, true, false, radix),
+            (MKD, u64, 1_00, "DEN", "Macedonian Denar", SuffixSpaced, true, false),
+            (MMK, u64, 1_00, "K", "Myanmar Kyat", SuffixSpaced, true, false),
+            (MNT, u64, 1_00, "₮", "Mongolian Tögrög", PrefixAttached, true, false),
+            (MOP, u64, 1_00, "MOP$", "Macanese Pataca", SuffixSpaced, true, false),
+            // divided into fifths rather than the hundredths ISO-4217 lists, see `Currency::BASE`
+            (MRU, u64, 5, "UM", "Mauritanian Ouguiya", SuffixSpaced, true, false, radix),
+            (MUR, u64, 1_00, "Re", "Mauritian Rupee", SuffixSpaced, true, false),
+            (MVR, u64, 1_00, "Rf", "Maldivian Rufiyaa", SuffixSpaced, true, false),
+            (MWK, u64, 1_00, "K", "Malawian Kwacha", SuffixSpaced, true, false),
+            (MXN, u64, 1_00, "$", "Mexican Peso", SuffixSpaced, true, false),
+            (MXV, u64, 1_00, "MXV", "Mexican Unidad de Inversion (UDI)", SuffixSpaced, true, false),
+            (MYR, u64, 1_00, "RM", "Malaysian Ringgit", SuffixSpaced, true, false),
+            (MZN, u64, 1_00, "Mt", "Mozambican Metical", SuffixSpaced, true, false),
+            (NAD, u64, 1_00, "$", "Namibian Dollar", PrefixAttached, true, false),
+            (NGN, u64, 1_00, "₦", "Nigerian Naira", SuffixSpaced, true, false),
+            (NIO, u64, 1_00, "C$", "Nicaraguan Córdoba", SuffixSpaced, true, false),
+            (NOK, u64, 1_00, "kr", "Norwegian Krone", SuffixSpaced, true, false),
+            (NPR, u64, 1_00, "Re", "Nepalese Rupee", SuffixSpaced, true, false),
+            (NZD, u64, 1_00, "$", "New Zealand Dollar", PrefixAttached, true, false),
+            (OMR, u64, 1_000, "RO", "Omani Rial", SuffixSpaced, true, false),
+            (PAB, u64, 1_00, "B/", "Panamanian Balboa", SuffixSpaced, true, false),
+            (PEN, u64, 1_00, "S/", "Peruvian Sol", SuffixSpaced, true, false),
+            (PGK, u64, 1_00, "K", "Papua New Guinean Kina", SuffixSpaced, true, false),
+            (PHP, u64, 1_00, "₱", "Philippine Peso", PrefixAttached, true, false),
+            (PKR, u64, 1_00, "Re", "Pakistani Rupee", SuffixSpaced, true, false),
+            (PLN, u64, 1_00, "zł", "Polish Złoty", SuffixSpaced, true, false),
+            (PYG, u64, 1, "₲", "Paraguayan Guarani", SuffixSpaced, true, false),
+            (QAR, u64, 1_00, "QR", "Qatari Riyal", SuffixSpaced, true, false),
+            (RON, u64, 1_00, "Leu", "Romanian Leu", SuffixSpaced, true, false),
+            (RSD, u64, 1_00, "DIN", "Serbian Dinar", SuffixSpaced, true, false),
+            (RUB, u64, 1_00, "₽", "Russian Ruble", PrefixAttached, true, false),
+            (RWF, u64, 1, "Fr", "Rwandan Franc", SuffixSpaced, true, false),
+            (SAR, u64, 1_00, "Rl", "Saudi Riyal", SuffixSpaced, true, false),
+            (SBD, u64, 1_00, "$", "Solomon Islands Dollar", PrefixAttached, true, false),
+            (SCR, u64, 1_00, "Re", "Seychelles Rupee", SuffixSpaced, true, false),
+            (SDG, u64, 1_00, "LS", "Sudanese Pound", SuffixSpaced, true, false),
+            (SEK, u64, 1_00, "kr", "Swedish Krona", SuffixSpaced, true, false),
+            (SGD, u64, 1_00, "$", "Singapore Dollar", PrefixAttached, true, false),
+            (SHP, u64, 1_00, "£", "Saint Helena Pound", PrefixAttached, true, false),
+            (SLE, u64, 1_00, "Le", "Sierra Leonean Leone", SuffixSpaced, true, false),
+            (SOS, u64, 1_00, "Sh", "Somali Shilling", SuffixSpaced, true, false),
+            (SRD, u64, 1_00, "$", "Surinamese Dollar", PrefixAttached, true, false),
+            (SSP, u64, 1_00, "SSP", "South Sudanese Pound", SuffixSpaced, true, false),
+            (STN, u64, 1_00, "Db", "São Tomé and Príncipe Dobra", SuffixSpaced, true, false),
+            (SVC, u64, 1_00, "₡", "Salvadoran Colón", PrefixAttached, true, false),
+            (SYP, u64, 1_00, "LS", "Syrian Pound", SuffixSpaced, true, false),
+            (SZL, u64, 1_00, "L", "Swazi Lilangeni", SuffixSpaced, true, false),
+            (THB, u64, 1_00, "฿", "Thai Baht", PrefixAttached, true, false),
+            (TJS, u64, 1_00, "SM", "Tajikistani Somoni", SuffixSpaced, true, false),
+            (TMT, u64, 1_00, "m", "Turkmenistan Manat", SuffixSpaced, true, false),
+            (TND, u64, 1_000, "DT", "Tunisian Dinar", SuffixSpaced, true, false),
+            (TOP, u64, 1_00, "T$", "Tongan Paʻanga", SuffixSpaced, true, false),
+            (TRY, u64, 1_00, "₺", "Turkish Lira", SuffixAttached, true, false),
+            (TTD, u64, 1_00, "$", "Trinidad and Tobago Dollar", PrefixAttached, true, false),
+            (TWD, u64, 1_00, "$", "New Taiwan Dollar", PrefixAttached, true, false),
+            (TZS, u64, 1_00, "Sh", "Tanzanian Shilling", SuffixSpaced, true, false),
+            (UAH, u64, 1_00, "₴", "Ukrainian Hryvnia", SuffixAttached, true, false),
+            (UGX, u64, 1, "Sh", "Ugandan Shilling", SuffixSpaced, true, false),
+            (USN, u64, 1_00, "USN", "US Dollar (Next Day)", SuffixSpaced, true, false),
+            (UYI, u64, 1, "UYI", "Uruguay Peso en Unidades Indexadas (UI)", SuffixSpaced, true, false),
+            (UYU, u64, 1_00, "$", "Uruguayan Peso", PrefixAttached, true, false),
+            (UYW, u64, 1_0000, "UYW", "Unidad Previsional", SuffixSpaced, true, false),
+            (UZS, u64, 1_00, "soum", "Uzbekistan Sum", SuffixSpaced, true, false),
+            (VED, u64, 1_00, "Bs.D", "Venezuelan Digital Bolívar", SuffixSpaced, true, false),
+            (VES, u64, 1_00, "Bs.S", "Venezuelan Sovereign Bolívar", SuffixSpaced, true, false),
+            (VND, u64, 1, "₫", "Vietnamese đồng", SuffixSpaced, true, false),
+            (VUV, u64, 1, "VT", "Vanuatu Vatu", SuffixSpaced, true, false),
+            (WST, u64, 1_00, "$", "Samoan Tālā", PrefixAttached, true, false),
+            (XAF, u64, 1, "Fr", "Central African CFA Franc", SuffixSpaced, true, false),
+            (XAG, u64, 1, "t oz", "Silver (Troy Ounce)", SuffixSpaced, true, false),
+            (XAU, u64, 1, "t oz", "Gold (Troy Ounce)", SuffixSpaced, true, false),
+            (XBA, u64, 1, "XBA", "European Composite Unit (EURCO)", SuffixSpaced, true, false),
+            (XBB, u64, 1, "XBB", "European Monetary Unit (E.M.U.-6)", SuffixSpaced, true, false),
+            (XBC, u64, 1, "XBC", "European Unit of Account 9 (E.U.A.-9)", SuffixSpaced, true, false),
+            (XBD, u64, 1, "XBD", "European Unit of Account 17 (E.U.A.-17)", SuffixSpaced, true, false),
+            (XCD, u64, 1_00, "$", "East Caribbean Dollar", SuffixSpaced, true, false),
+            (XCG, u64, 1_00, "Cg", "Caribbean Guilder", SuffixSpaced, true, false),
+            (XDR, u64, 1, "SDR", "Special Drawing Right", SuffixSpaced, true, false),
+            (XOF, u64, 1, "Fr", "West African CFA Franc", SuffixSpaced, true, false),
+            (XPD, u64, 1, "t oz", "Palladium (Troy Ounce)", SuffixSpaced, true, false),
+            (XPF, u64, 1, "Fr", "CFP Franc", SuffixSpaced, true, false),
+            (XPT, u64, 1, "t oz", "Platinum (Troy Ounce)", SuffixSpaced, true, false),
+            (XSU, u64, 1, "XSU", "Sucre", SuffixSpaced, true, false),
+            (XTS, u64, 1, "XTS", "Testing Code", SuffixSpaced, true, false),
+            (XUA, u64, 1, "XUA", "ADB Unit of Account", SuffixSpaced, true, false),
+            (XXX, u64, 1, "XXX", "No Currency", SuffixSpaced, true, false),
+            (YER, u64, 1_00, "Rl", "Yemeni Rial", SuffixSpaced, true, false),
+            (ZAR, u64, 1_00, "R", "South African Rand", SuffixSpaced, true, false),
+            (ZMW, u64, 1_00, "K", "Zambian Kwacha", SuffixSpaced, true, false),
+            (ZWG, u64, 1_00, "ZiG", "Zimbabwe Gold", SuffixSpaced, true, false),
+        }
+    };
+}
+
+/// Expands each row of [`currencies_table!`](crate::currencies_table) into a
+/// [`define_currency!`](crate::define_currency) invocation.
+macro_rules! define_currencies {
+    ($(($($row:tt)*)),* $(,)?) => {
+        $(define_currency!($($row)*);)*
+    };
+}
+
+currencies_table!(define_currencies);
+
+#[cfg(test)]
+define_currency!(CHECKFUND, i64, 1_00, "F", "Checked Fund", SuffixSpaced, false, false);
+
+// A signed dollar for the tests that need negative amounts.
+#[cfg(test)]
+define_currency!(SIGNEDUSD, i64, 1_00, "$", "Signed Dollar", PrefixAttached, false, false);
+
+/// Implements [`Currency`] directly, bypassing the checks of [`define_currency!`] so that they
+/// can be exercised.
+#[cfg(test)]
+macro_rules! unchecked_currency {
+    ($name:ident, $code:literal, $is_iso:literal) => {
+        #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        struct $name;
+
+        impl Currency for $name {
+            type Backing = u64;
+            const BASE: u64 = 1_00;
+            const CODE: &'static str = $code;
+            const SYMBOL: &'static str = $code;
+            const PROPER_NAME: &'static str = $code;
+            const STYLE: FormatStyle = FormatStyle::SuffixSpaced;
+            const IS_ISO: bool = $is_iso;
+            const IS_CRYPTO: bool = false;
+        }
+    };
+}
+
+#[cfg(test)]
+unchecked_currency!(LowercaseCode, "usd", false);
+
+#[cfg(test)]
+unchecked_currency!(EmptyCode, "", false);
+
+#[cfg(test)]
+unchecked_currency!(NotIso, "BTC", true);
+
+#[test]
+fn test_check_accepts_valid_definitions() {
+    __private::check::<USD>(<u64 as __private::ConstBase<USD>>::BASE, false);
+    __private::check::<ETH>(<U256 as __private::ConstBase<ETH>>::BASE, false);
+    __private::check::<MGA>(<u64 as __private::ConstBase<MGA>>::BASE, true);
+    __private::check::<CHECKFUND>(Some([1, 0, 0, 0]), false);
+    assert_eq!(<i64 as __private::ConstBase<CHECKFUND>>::BASE, Some([1_00, 0, 0, 0]));
+    assert_eq!(XAU::PROPER_NAME, "Gold (Troy Ounce)");
+}
+
+#[test]
+#[should_panic(expected = "the BASE of a currency must be positive")]
+fn test_check_rejects_zero_base() {
+    __private::check::<CHECKFUND>(Some([0; 4]), false);
+}
+
+#[test]
+#[should_panic(expected = "the BASE of a currency must be positive")]
+fn test_check_rejects_negative_base() {
+    __private::check::<CHECKFUND>(None, false);
+}
+
+#[test]
+#[should_panic(expected = "must be a power of ten, or declared with `radix`")]
+fn test_check_rejects_undeclared_radix() {
+    __private::check::<CHECKFUND>(Some([5, 0, 0, 0]), false);
+}
+
+#[test]
+#[should_panic(expected = "the BASE of a currency with a radix must be at least 2")]
+fn test_check_rejects_unit_radix() {
+    __private::check::<CHECKFUND>(Some([1, 0, 0, 0]), true);
+}
+
+#[test]
+#[should_panic(expected = "the BASE of a currency with a radix must divide a power of ten")]
+fn test_check_rejects_non_decimal_radix() {
+    __private::check::<CHECKFUND>(Some([12, 0, 0, 0]), true);
+}
+
+#[test]
+#[should_panic(expected = "currency codes must consist of ASCII uppercase letters and digits")]
+fn test_check_rejects_lowercase_code() {
+    __private::check::<LowercaseCode>(Some([1_00, 0, 0, 0]), false);
+}
+
+#[test]
+#[should_panic(expected = "currency codes must consist of ASCII uppercase letters and digits")]
+fn test_check_rejects_empty_code() {
+    __private::check::<EmptyCode>(Some([1_00, 0, 0, 0]), false);
+}
+
+#[test]
+#[should_panic(expected = "IS_ISO is set but CODE is not an ISO-4217 code")]
+fn test_check_rejects_unknown_iso_code() {
+    __private::check::<NotIso>(Some([1_00, 0, 0, 0]), false);
+}
+
+#[test]
+fn test_iso_currencies_match_reference() {
+    for info in crate::registry::builtin().filter(|info| info.is_iso) {
+        let digits = crate::iso4217::decimal_digits(info.code).unwrap();
+        assert_eq!(info.numeric_code, crate::iso4217::numeric_code(info.code), "{}", info.code);
+        match info.code {
+            // the ariary and the ouguiya deliberately keep their fifths (see `Currency::BASE`),
+            // which take one decimal digit where ISO-4217 lists two
+            "MGA" | "MRU" => {
+                assert_eq!(info.base, U256::from(5u8), "{}", info.code);
+                assert_eq!((info.decimal_digits(), digits), (1, 2), "{}", info.code);
+            }
+            _ => assert_eq!(info.decimal_digits(), digits, "{}", info.code),
+        }
+    }
+    assert_eq!(JPY::decimal_digits(), 0);
+    assert_eq!(CNY::decimal_digits(), 2);
+    assert_eq!(JOD::decimal_digits(), 3);
+    assert_eq!(CLF::decimal_digits(), 4);
+    assert_eq!(XAU::decimal_digits(), 0);
+    assert_eq!((USD::NUMERIC_CODE, XAU::NUMERIC_CODE, BTC::NUMERIC_CODE), (Some(840), Some(959), None));
+}
@@ -0,0 +1,293 @@
+//! Home of the [`currency_set!`](crate::currency_set!) macro, which declares an amount type
+//! restricted to a closed set of currencies.
+
+/// Declares an enum holding an [`Amount`](crate::Amount) of one of a closed set of currencies,
+/// for APIs that only accept some currencies:
+///
+/// ```ignore
+/// use currencies::{currency::*, *};
+///
+/// currency_set!(
+///     /// The currencies our payments are settled in.
+///     pub Settlement = USD | EUR | GBP
+/// );
+///
+/// let amount = Settlement::from(amt!(EUR, "12.50€"));
+/// match amount {
+///     Settlement::USD(_) | Settlement::GBP(_) => unreachable!(),
+///     Settlement::EUR(euros) => assert_eq!(euros, amt!(EUR, "12.50€")),
+/// }
+/// assert_eq!(Settlement::CODES, ["USD", "EUR", "GBP"]);
+/// assert!(Amount::<USD>::try_from(amount).is_err());
+/// ```
+///
+/// Each variant is named after its currency and holds an unchecked `Amount` of it, so
+/// matching on the enum is exhaustive. The generated type:
+/// - is converted from any `Amount` of its currencies via [`From`], and back via [`TryFrom`],
+///   which fails with [`ConversionError::CurrencyMismatch`](crate::ConversionError) for the
+///   other currencies;
+/// - is converted to a [`DynAmount`](crate::DynAmount) via [`From`], and from one via
+///   [`TryFrom`];
+/// - displays and, with the `parsing` feature, parses like a [`DynAmount`](crate::DynAmount),
+///   restricted to its currencies with [`DynAmount::parse_among`](crate::DynAmount::parse_among);
+/// - with the `serde` feature, is serialized like a [`DynAmount`](crate::DynAmount) and only
+///   deserialized from amounts of its currencies.
+///
+/// The currencies must be named by identifiers in scope, which may refer to currencies defined
+/// outside of this crate.
+#[macro_export]
+macro_rules! currency_set {
+    ($(#[$meta:meta])* $vis:vis $name:ident = $($currency:ident)|+ $(,)?) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+        #[allow(clippy::upper_case_acronyms)]
+        $vis enum $name {
+            $(
+                #[doc = concat!("An amount of [`", stringify!($currency), "`].")]
+                $currency($crate::Amount<$currency>),
+            )+
+        }
+
+        #[allow(dead_code)]
+        impl $name {
+            /// The codes of the currencies in this set, in the order they were declared.
+            pub const CODES: [&'static str; [$(stringify!($currency)),+].len()] =
+                [$(<$currency as $crate::Currency>::CODE),+];
+
+            /// Returns the [`CurrencyInfo`]($crate::CurrencyInfo) of every currency in this set,
+            /// in the order they were declared.
+            pub fn currencies() -> [$crate::CurrencyInfo; [$(stringify!($currency)),+].len()] {
+                [$($crate::CurrencyInfo::of::<$currency>()),+]
+            }
+
+            /// Returns the [`CurrencyInfo`]($crate::CurrencyInfo) of the currency this amount is
+            /// denominated in.
+            pub fn currency(&self) -> $crate::CurrencyInfo {
+                match self {
+                    $($name::$currency(_) => $crate::CurrencyInfo::of::<$currency>(),)+
+                }
+            }
+
+            /// Returns the code of the currency this amount is denominated in.
+            pub fn code(&self) -> &'static str {
+                match self {
+                    $($name::$currency(_) => <$currency as $crate::Currency>::CODE,)+
+                }
+            }
+        }
+
+        $(
+            impl<Safety: $crate::safety::Safety> ::core::convert::From<$crate::Amount<$currency, Safety>> for $name {
+                fn from(amount: $crate::Amount<$currency, Safety>) -> Self {
+                    $name::$currency($crate::Amount::from_raw(amount.raw_backing()))
+                }
+            }
+
+            impl<Safety: $crate::safety::Safety> ::core::convert::TryFrom<$name> for $crate::Amount<$currency, Safety> {
+                type Error = $crate::ConversionError;
+
+                fn try_from(amount: $name) -> ::core::result::Result<Self, $crate::ConversionError> {
+                    #[allow(unreachable_patterns)]
+                    match amount {
+                        $name::$currency(amount) => ::core::result::Result::Ok($crate::Amount::from_raw(amount.raw_backing())),
+                        _ => ::core::result::Result::Err($crate::ConversionError::CurrencyMismatch),
+                    }
+                }
+            }
+        )+
+
+        impl ::core::convert::From<$name> for $crate::DynAmount {
+            fn from(amount: $name) -> Self {
+                match amount {
+                    $($name::$currency(amount) => $crate::DynAmount::from(amount),)+
+                }
+            }
+        }
+
+        impl ::core::convert::TryFrom<$crate::DynAmount> for $name {
+            type Error = $crate::ConversionError;
+
+            fn try_from(amount: $crate::DynAmount) -> ::core::result::Result<Self, $crate::ConversionError> {
+                $(
+                    if *amount.currency() == $crate::CurrencyInfo::of::<$currency>() {
+                        return match amount.to_amount::<$currency, $crate::safety::Unchecked>() {
+                            ::core::option::Option::Some(amount) => ::core::result::Result::Ok($name::$currency(amount)),
+                            ::core::option::Option::None if amount.is_negative() => {
+                                ::core::result::Result::Err($crate::ConversionError::Negative)
+                            }
+                            ::core::option::Option::None => ::core::result::Result::Err($crate::ConversionError::Overflow),
+                        };
+                    }
+                )+
+                ::core::result::Result::Err($crate::ConversionError::CurrencyMismatch)
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    $($name::$currency(amount) => ::core::fmt::Display::fmt(amount, f),)+
+                }
+            }
+        }
+
+        $crate::__impl_currency_set_parsing!($name);
+        $crate::__impl_currency_set_serde!($name);
+    };
+}
+
+#[cfg(feature = "parsing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_currency_set_parsing {
+    ($name:ident) => {
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::dynamic::DynParseError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                $crate::dynamic::parse_currency_set(s, &Self::currencies())
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "parsing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_currency_set_parsing {
+    ($name:ident) => {};
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_currency_set_serde {
+    ($name:ident) => {
+        impl $crate::serde_integration::__private::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::serde_integration::__private::Serializer,
+            {
+                $crate::serde_integration::__private::Serialize::serialize(&$crate::DynAmount::from(*self), serializer)
+            }
+        }
+
+        impl<'de> $crate::serde_integration::__private::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::serde_integration::__private::Deserializer<'de>,
+            {
+                $crate::serde_integration::deserialize_currency_set(deserializer, &Self::currencies())
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_currency_set_serde {
+    ($name:ident) => {};
+}
+
+#[cfg(test)]
+use crate::{
+    currency::{BTC, EUR, GBP, USD},
+    safety::Checked,
+    Amount, ConversionError, DynAmount,
+};
+
+#[cfg(test)]
+use alloc::format;
+
+#[cfg(test)]
+crate::define_currency!(SETGEM, i64, 1_000, "gem", "Set Gem", SuffixSpaced, false, false);
+
+#[cfg(test)]
+currency_set!(Settlement = USD | EUR | GBP);
+
+#[cfg(test)]
+currency_set!(
+    /// A set with a currency that is not in the registry.
+    pub(crate) Rewards = SETGEM | USD,
+);
+
+#[test]
+fn test_currency_set_conversions() {
+    let dollars = Amount::<USD>::from_raw(12_50);
+    let amount = Settlement::from(dollars);
+    assert_eq!(amount, Settlement::USD(dollars));
+    assert_eq!(
+        Settlement::from(Amount::<EUR, Checked>::from_raw(1_00)),
+        Settlement::EUR(Amount::from_raw(1_00))
+    );
+    assert_eq!(Amount::<USD>::try_from(amount), Ok(dollars));
+    assert_eq!(Amount::<USD, Checked>::try_from(amount), Ok(Amount::from_raw(12_50)));
+    assert_eq!(Amount::<GBP>::try_from(amount), Err(ConversionError::CurrencyMismatch));
+
+    assert_eq!(DynAmount::from(amount), DynAmount::from(dollars));
+    assert_eq!(Settlement::try_from(DynAmount::from(dollars)), Ok(amount));
+    let bitcoin = DynAmount::from(Amount::<BTC>::from_raw(1));
+    assert_eq!(Settlement::try_from(bitcoin), Err(ConversionError::CurrencyMismatch));
+    let negative = -DynAmount::from(dollars);
+    assert_eq!(Settlement::try_from(negative), Err(ConversionError::Negative));
+    let gems = -DynAmount::from(Amount::<SETGEM>::from_raw(1_500));
+    assert_eq!(Rewards::try_from(gems), Ok(Rewards::SETGEM(Amount::from_raw(-1_500))));
+}
+
+#[test]
+fn test_currency_set_info() {
+    assert_eq!(Settlement::CODES, ["USD", "EUR", "GBP"]);
+    assert_eq!(Rewards::CODES, ["SETGEM", "USD"]);
+    assert_eq!(Settlement::currencies()[1], crate::CurrencyInfo::of::<EUR>());
+    let amount = Settlement::from(Amount::<GBP>::from_raw(3_00));
+    assert_eq!(amount.code(), "GBP");
+    assert_eq!(amount.currency(), crate::CurrencyInfo::of::<GBP>());
+    assert_eq!(format!("{}", amount), "£3.00");
+    assert_eq!(format!("{}", Rewards::from(Amount::<SETGEM>::from_raw(-500))), "-0.500 gem");
+}
+
+#[cfg(feature = "parsing")]
+#[test]
+fn test_currency_set_parsing() {
+    use crate::dynamic::DynParseError;
+
+    assert_eq!("$12.50".parse::<Settlement>(), Ok(Settlement::USD(Amount::from_raw(12_50))));
+    assert_eq!("12.50 eur".parse::<Settlement>(), Ok(Settlement::EUR(Amount::from_raw(12_50))));
+    assert_eq!(
+        "-£1".parse::<Settlement>(),
+        Err(DynParseError::InvalidAmount("negative amounts require a signed backing"))
+    );
+    assert_eq!("¥500".parse::<Settlement>(), Err(DynParseError::UnknownCurrency("¥".into())));
+    assert_eq!("0.1 BTC".parse::<Settlement>(), Err(DynParseError::UnknownCurrency("BTC".into())));
+    assert_eq!("-1.5 gem".parse::<Rewards>(), Ok(Rewards::SETGEM(Amount::from_raw(-1_500))));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_currency_set_serde() {
+    let amount = Settlement::from(Amount::<EUR>::from_raw(12_50));
+    let json = serde_json::to_string(&amount).unwrap();
+    assert_eq!(json, r#"{"currency":"EUR","value":"12.50"}"#);
+    assert_eq!(serde_json::from_str::<Settlement>(&json).unwrap(), amount);
+
+    let err = serde_json::from_str::<Settlement>(r#"{"currency":"BTC","value":"1"}"#).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("unsupported currency code `BTC`, expected one of USD, EUR, GBP"),
+        "{}",
+        err
+    );
+    let err = serde_json::from_str::<Settlement>(r#"{"currency":"USD","value":"-1"}"#).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("invalid USD amount: negative amounts require a signed backing"),
+        "{}",
+        err
+    );
+
+    let gems = Rewards::from(Amount::<SETGEM>::from_raw(-1_500));
+    let json = serde_json::to_string(&gems).unwrap();
+    assert_eq!(json, r#"{"currency":"SETGEM","value":"-1.500"}"#);
+    assert_eq!(serde_json::from_str::<Rewards>(&json).unwrap(), gems);
+}
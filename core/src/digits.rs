@@ -0,0 +1,157 @@
+//! Home of [`DigitSystem`], which describes the non-ASCII decimal digits that amounts can be
+//! parsed from and formatted with, along with the separators recognised alongside them.
+
+use core::fmt;
+
+/// A system of decimal digits. Parsing accepts digits from any of these systems, and
+/// [`Amount::with_digits`](crate::Amount::with_digits) formats an amount using a specific one.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum DigitSystem {
+    /// ASCII digits, `0123456789`.
+    #[default]
+    Ascii,
+    /// Arabic-Indic digits, `٠١٢٣٤٥٦٧٨٩`.
+    ArabicIndic,
+    /// Extended Arabic-Indic digits as used in Persian and Urdu, `۰۱۲۳۴۵۶۷۸۹`.
+    Persian,
+    /// Devanagari digits, `०१२३४५६७८९`.
+    Devanagari,
+    /// Full-width digits as used in CJK text, `０１２３４５６７８９`.
+    FullWidth,
+}
+
+impl DigitSystem {
+    /// Every supported [`DigitSystem`].
+    pub const ALL: [DigitSystem; 5] = [
+        DigitSystem::Ascii,
+        DigitSystem::ArabicIndic,
+        DigitSystem::Persian,
+        DigitSystem::Devanagari,
+        DigitSystem::FullWidth,
+    ];
+
+    /// Returns the character representing zero in this [`DigitSystem`]. The remaining digits
+    /// follow it consecutively.
+    pub const fn zero(self) -> char {
+        match self {
+            DigitSystem::Ascii => '0',
+            DigitSystem::ArabicIndic => '\u{0660}',
+            DigitSystem::Persian => '\u{06F0}',
+            DigitSystem::Devanagari => '\u{0966}',
+            DigitSystem::FullWidth => '\u{FF10}',
+        }
+    }
+
+    /// Returns the character representing `digit` (which must be less than 10) in this
+    /// [`DigitSystem`].
+    pub fn digit(self, digit: u8) -> char {
+        assert!(digit < 10, "{} is not a decimal digit", digit);
+        char::from_u32(self.zero() as u32 + digit as u32).unwrap()
+    }
+
+    /// Returns the [`DigitSystem`] that `c` belongs to along with its value, or [`None`] if
+    /// `c` is not a decimal digit in any supported system.
+    pub fn of(c: char) -> Option<(DigitSystem, u8)> {
+        DigitSystem::ALL.into_iter().find_map(|system| {
+            let value = (c as u32).wrapping_sub(system.zero() as u32);
+            (value < 10).then_some((system, value as u8))
+        })
+    }
+}
+
+/// Returns the value of `c` if it is a decimal digit in any [`DigitSystem`].
+#[inline]
+pub(crate) fn decimal_digit(c: char) -> Option<u8> {
+    match c {
+        '0'..='9' => Some(c as u8 - b'0'),
+        _ if c.is_ascii() => None,
+        _ => DigitSystem::of(c).map(|(_, value)| value),
+    }
+}
+
+/// The characters accepted as a decimal point: the ASCII full stop, the full-width full stop
+/// and the Arabic decimal separator.
+pub(crate) const DECIMAL_POINTS: [char; 3] = ['.', '\u{FF0E}', '\u{066B}'];
+
+/// The characters accepted as commas, which separate thousands but may also serve as a
+/// decimal comma: the ASCII comma and the full-width comma.
+pub(crate) const COMMAS: [char; 2] = [',', '\u{FF0C}'];
+
+/// The characters accepted as spaces between a symbol and a number, which also separate
+/// thousands: the ASCII space, the no-break space, the narrow no-break space and the
+/// full-width (ideographic) space.
+pub(crate) const SPACES: [char; 4] = [' ', '\u{00A0}', '\u{202F}', '\u{3000}'];
+
+/// Returns `true` if `c` may separate groups of thousands.
+#[inline]
+pub(crate) fn is_group_separator(c: char) -> bool {
+    COMMAS.contains(&c) || SPACES.contains(&c) || c == '\u{066C}'
+}
+
+/// A [`fmt::Write`] adaptor that translates the ASCII digits written to it into the digits of
+/// another [`DigitSystem`]. Only the number of an amount is written through it, so digits in a
+/// currency symbol or code are left untouched.
+pub(crate) struct DigitWriter<'a, W: fmt::Write> {
+    pub(crate) inner: &'a mut W,
+    pub(crate) system: DigitSystem,
+}
+
+impl<W: fmt::Write> fmt::Write for DigitWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.system == DigitSystem::Ascii {
+            return self.inner.write_str(s);
+        }
+        for c in s.chars() {
+            match c {
+                '0'..='9' => self.inner.write_char(self.system.digit(c as u8 - b'0'))?,
+                _ => self.inner.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Displays the wrapped amount with the digits of its number written in a specific
+/// [`DigitSystem`]. Returned by [`Amount::with_digits`](crate::Amount::with_digits) and
+/// [`DynAmount::with_digits`](crate::DynAmount::with_digits).
+#[derive(Copy, Clone, Debug)]
+pub struct WithDigits<T> {
+    pub(crate) value: T,
+    pub(crate) system: DigitSystem,
+}
+
+impl<T> WithDigits<T> {
+    pub(crate) fn new(value: T, system: DigitSystem) -> Self {
+        WithDigits { value, system }
+    }
+}
+
+#[cfg(test)]
+extern crate alloc;
+
+#[test]
+fn test_digit_systems() {
+    for system in DigitSystem::ALL {
+        for digit in 0..10 {
+            assert_eq!(DigitSystem::of(system.digit(digit)), Some((system, digit)));
+            assert_eq!(decimal_digit(system.digit(digit)), Some(digit));
+        }
+    }
+    assert_eq!(DigitSystem::of('٣'), Some((DigitSystem::ArabicIndic, 3)));
+    assert_eq!(DigitSystem::of('۴'), Some((DigitSystem::Persian, 4)));
+    assert_eq!(DigitSystem::of('७'), Some((DigitSystem::Devanagari, 7)));
+    assert_eq!(DigitSystem::of('９'), Some((DigitSystem::FullWidth, 9)));
+    assert_eq!(DigitSystem::of('a'), None);
+    assert_eq!(decimal_digit('٫'), None);
+}
+
+#[test]
+fn test_digit_writer() {
+    let mut text = alloc::string::String::new();
+    let mut writer = DigitWriter {
+        inner: &mut text,
+        system: DigitSystem::ArabicIndic,
+    };
+    fmt::Write::write_str(&mut writer, "1,024.50").unwrap();
+    assert_eq!(text, "١,٠٢٤.٥٠");
+}
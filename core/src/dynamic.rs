@@ -0,0 +1,550 @@
+//! Home of [`DynAmount`], an amount whose [`Currency`] is only known at runtime, along with the
+//! dynamic parser that can read an amount of any known currency from a string.
+
+#[cfg(any(feature = "parsing", feature = "serde"))]
+use num_integer::Integer;
+use num_traits::Zero;
+#[cfg(any(feature = "parsing", feature = "serde"))]
+use num_traits::{CheckedAdd, CheckedMul};
+#[cfg(feature = "parsing")]
+use std::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+#[cfg(any(feature = "parsing", feature = "serde"))]
+use crate::amount::minor_scale;
+use crate::amount::{fmt_raw, Amount};
+use crate::currency::*;
+#[cfg(feature = "parsing")]
+use crate::digits::SPACES;
+#[cfg(any(feature = "parsing", feature = "serde"))]
+use crate::digits::{decimal_digit, is_group_separator, COMMAS, DECIMAL_POINTS};
+use crate::digits::{DigitSystem, WithDigits};
+#[cfg(any(feature = "parsing", feature = "serde"))]
+use crate::parsing::is_decimal_comma;
+#[cfg(feature = "parsing")]
+use crate::parsing::MINUS_SIGNS;
+use crate::safety;
+use crate::u256::{backing_to_u256, u256_to_backing, U256};
+
+/// Represents an amount of a [`Currency`] that is only known at runtime, described by a
+/// [`CurrencyInfo`].
+///
+/// Any [`Amount`] can be converted into a [`DynAmount`] via [`From`], and a [`DynAmount`] can
+/// be converted back via [`DynAmount::to_amount`]. The raw value is always stored as a
+/// [`U256`] magnitude plus a sign so that amounts of currencies with any
+/// [`Backing`](crate::Backing), signed or unsigned, fit.
+///
+/// With the `parsing` feature enabled, a [`DynAmount`] can be parsed from strings such as
+/// `"€12"`, `"12 DOT"` or `"USD 5"` by looking at the symbol or code of every currency known to
+/// the [`registry`](crate::registry).
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DynAmount {
+    currency: CurrencyInfo,
+    raw: U256,
+    negative: bool,
+}
+
+impl DynAmount {
+    /// Constructs a non-negative [`DynAmount`] of the specified currency from a raw value, with
+    /// the same meaning as the raw [`Backing`](crate::Backing) value of an [`Amount`].
+    ///
+    /// Negative amounts can be obtained by negating the result.
+    pub const fn from_raw(currency: CurrencyInfo, raw: U256) -> Self {
+        DynAmount {
+            currency,
+            raw,
+            negative: false,
+        }
+    }
+
+    /// Returns the [`CurrencyInfo`] of the currency this amount is denominated in.
+    pub fn currency(&self) -> &CurrencyInfo {
+        &self.currency
+    }
+
+    /// Allows direct access to the magnitude of the raw value used to internally represent this
+    /// [`DynAmount`]. The sign is available via [`DynAmount::is_negative`].
+    pub fn raw_backing(&self) -> U256 {
+        self.raw
+    }
+
+    /// Returns `true` if this amount is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns a wrapper that displays this [`DynAmount`] with its digits written in the
+    /// specified [`DigitSystem`].
+    pub fn with_digits(&self, system: DigitSystem) -> WithDigits<&Self> {
+        WithDigits::new(self, system)
+    }
+
+    /// Converts this [`DynAmount`] into an [`Amount`] of the [`Currency`] `C`.
+    ///
+    /// Returns [`None`] if this amount is not denominated in `C` or if the raw value does not
+    /// fit within `C::Backing`, which includes negative amounts when it is unsigned.
+    pub fn to_amount<C: Currency, Safety: safety::Safety>(&self) -> Option<Amount<C, Safety>> {
+        if self.currency != CurrencyInfo::of::<C>() {
+            return None;
+        }
+        Some(Amount::from_raw(u256_to_backing(self.raw, self.negative)?))
+    }
+}
+
+impl core::ops::Neg for DynAmount {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        DynAmount {
+            negative: !self.negative && !self.raw.is_zero(),
+            ..self
+        }
+    }
+}
+
+impl<C: Currency, Safety: safety::Safety> From<Amount<C, Safety>> for DynAmount {
+    fn from(amount: Amount<C, Safety>) -> Self {
+        let raw = amount.raw_backing();
+        DynAmount {
+            currency: CurrencyInfo::of::<C>(),
+            raw: backing_to_u256(raw),
+            negative: raw < C::Backing::zero(),
+        }
+    }
+}
+
+impl core::fmt::Display for DynAmount {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.with_digits(DigitSystem::Ascii).fmt(f)
+    }
+}
+
+impl core::fmt::Display for WithDigits<&DynAmount> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let amount = self.value;
+        if amount.negative {
+            core::fmt::Write::write_char(f, '-')?;
+        }
+        fmt_raw(
+            f,
+            amount.raw,
+            amount.currency.base,
+            amount.currency.symbol,
+            amount.currency.style,
+            self.system,
+        )
+    }
+}
+
+impl core::fmt::Debug for DynAmount {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+/// The error returned when a [`DynAmount`] cannot be parsed from a string.
+#[cfg(feature = "parsing")]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum DynParseError {
+    /// The input did not contain a currency symbol or code before or after the number.
+    MissingCurrency,
+    /// The symbol or code in the input does not belong to any known currency.
+    UnknownCurrency(String),
+    /// The symbol in the input is shared by several currencies (e.g. `$`) and no preferred
+    /// currency was supplied to disambiguate it.
+    Ambiguous {
+        /// The symbol that was found in the input.
+        symbol: String,
+        /// Every known currency using `symbol`.
+        candidates: Vec<CurrencyInfo>,
+    },
+    /// The numeric portion of the input is malformed or does not fit the currency.
+    InvalidAmount(&'static str),
+}
+
+#[cfg(feature = "parsing")]
+impl core::fmt::Display for DynParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DynParseError::MissingCurrency => write!(f, "expected a currency symbol or code"),
+            DynParseError::UnknownCurrency(symbol) => write!(f, "unknown currency `{}`", symbol),
+            DynParseError::Ambiguous { symbol, candidates } => {
+                write!(f, "ambiguous currency symbol `{}`, could be any of ", symbol)?;
+                for (i, candidate) in candidates.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", candidate.code)?;
+                }
+                Ok(())
+            }
+            DynParseError::InvalidAmount(reason) => write!(f, "invalid amount: {}", reason),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl std::error::Error for DynParseError {}
+
+#[cfg(feature = "parsing")]
+impl DynAmount {
+    /// Parses a [`DynAmount`] of any known currency from a string such as `"€12"`,
+    /// `"12.50 DOT"` or `"USD 5"`.
+    ///
+    /// The currency is identified by the symbol or code found before or after the number.
+    /// Codes are unique and always take precedence. Symbols shared by several currencies (such
+    /// as `$`) are resolved using `preferred`, which should be the [`Currency::CODE`] of one of
+    /// them; if it is [`None`] or matches none of them, a [`DynParseError::Ambiguous`] listing
+    /// every candidate is returned.
+    ///
+    /// Unlike the [`FromStr`](core::str::FromStr) impl of [`Amount`], the symbol may appear on
+    /// either side of the number regardless of [`Currency::STYLE`], surrounding whitespace is
+    /// ignored and the decimal point is optional. A decimal comma is also accepted, as in
+    /// `5,00 €`, for currencies whose [`Currency::STYLE`] places the symbol after the number,
+    /// while a number such as `$1,50` that could mean either is rejected. Negative amounts are
+    /// recognised in the same notations, i.e. a leading or trailing minus sign (`-` or `−`) on
+    /// either side of the symbol, or parentheses around the whole amount.
+    pub fn parse_with_preference(s: &str, preferred: Option<&str>) -> Result<Self, DynParseError> {
+        Self::parse_with(s, |symbol| resolve_currency(symbol, preferred))
+    }
+
+    /// Parses a [`DynAmount`] like [`DynAmount::parse_with_preference`], but only accepts the
+    /// specified currencies, which need not be in the [`registry`](crate::registry).
+    ///
+    /// Codes take precedence over symbols as usual, and a symbol is only ambiguous if several
+    /// of `currencies` use it, so `"$5"` is read as dollars if `currencies` contains a single
+    /// one. The codes and symbols of other currencies are reported as
+    /// [`DynParseError::UnknownCurrency`].
+    pub fn parse_among(s: &str, currencies: &[CurrencyInfo]) -> Result<Self, DynParseError> {
+        Self::parse_with(s, |symbol| {
+            if let Some(currency) = currencies.iter().find(|c| c.code.eq_ignore_ascii_case(symbol)) {
+                return Ok(*currency);
+            }
+            let symbol_lowercase = symbol.to_lowercase();
+            let candidates: Vec<CurrencyInfo> = currencies
+                .iter()
+                .filter(|c| c.symbol.to_lowercase() == symbol_lowercase)
+                .copied()
+                .collect();
+            match candidates.len() {
+                0 => Err(DynParseError::UnknownCurrency(symbol.to_string())),
+                1 => Ok(candidates[0]),
+                _ => Err(DynParseError::Ambiguous {
+                    symbol: symbol.to_string(),
+                    candidates,
+                }),
+            }
+        })
+    }
+
+    /// Parses a [`DynAmount`] from `s`, identifying its currency from the symbol or code next
+    /// to the number with `resolve`.
+    fn parse_with(s: &str, resolve: impl FnOnce(&str) -> Result<CurrencyInfo, DynParseError>) -> Result<Self, DynParseError> {
+        let mut s = s.trim();
+        let mut signs = 0;
+        if let Some(inner) = s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+            s = inner.trim();
+            signs += 1;
+        }
+        let number_start = s
+            .find(|c: char| decimal_digit(c).is_some())
+            .ok_or(DynParseError::InvalidAmount("expected a number"))?;
+        // the number ends after its last digit or decimal point, so that spaces before a
+        // suffix are not mistaken for thousands separators
+        let number_end = s[number_start..]
+            .char_indices()
+            .take_while(|&(_, c)| decimal_digit(c).is_some() || is_group_separator(c) || DECIMAL_POINTS.contains(&c))
+            .filter(|&(_, c)| !SPACES.contains(&c))
+            .last()
+            .map_or(s.len(), |(i, c)| number_start + i + c.len_utf8());
+        let mut prefix = s[..number_start].trim();
+        let mut suffix = s[number_end..].trim();
+        for text in [&mut prefix, &mut suffix] {
+            if let Some(rest) = text.strip_prefix(MINUS_SIGNS) {
+                *text = rest.trim();
+                signs += 1;
+            }
+            if let Some(rest) = text.strip_suffix(MINUS_SIGNS) {
+                *text = rest.trim();
+                signs += 1;
+            }
+        }
+        if signs > 1 {
+            return Err(DynParseError::InvalidAmount("more than one sign"));
+        }
+        let symbol = match (prefix.is_empty(), suffix.is_empty()) {
+            (true, true) => return Err(DynParseError::MissingCurrency),
+            (false, true) => prefix,
+            (true, false) => suffix,
+            (false, false) => return Err(DynParseError::InvalidAmount("unexpected text on both sides of the number")),
+        };
+        let currency = resolve(symbol)?;
+        let raw = parse_number(&s[number_start..number_end], &currency).map_err(DynParseError::InvalidAmount)?;
+        let amount = DynAmount::from_raw(currency, raw);
+        Ok(if signs == 1 { -amount } else { amount })
+    }
+}
+
+/// Parses a value of a type generated by [`currency_set!`](crate::currency_set!), which only
+/// accepts the specified currencies.
+#[cfg(feature = "parsing")]
+#[doc(hidden)]
+pub fn parse_currency_set<T>(s: &str, currencies: &[CurrencyInfo]) -> Result<T, DynParseError>
+where
+    T: TryFrom<DynAmount, Error = crate::ConversionError>,
+{
+    T::try_from(DynAmount::parse_among(s, currencies)?).map_err(|err| match err {
+        crate::ConversionError::Negative => DynParseError::InvalidAmount("negative amounts require a signed backing"),
+        _ => DynParseError::InvalidAmount("amount is too large"),
+    })
+}
+
+#[cfg(feature = "parsing")]
+impl core::str::FromStr for DynAmount {
+    type Err = DynParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DynAmount::parse_with_preference(s, None)
+    }
+}
+
+/// Finds the currency identified by `symbol`, which may be either a code or a symbol.
+#[cfg(feature = "parsing")]
+fn resolve_currency(symbol: &str, preferred: Option<&str>) -> Result<CurrencyInfo, DynParseError> {
+    if let Some(currency) = crate::registry::by_code(symbol) {
+        return Ok(currency);
+    }
+    let mut candidates = crate::registry::by_symbol(symbol);
+    match candidates.len() {
+        0 => Err(DynParseError::UnknownCurrency(symbol.to_string())),
+        1 => Ok(candidates.remove(0)),
+        _ => match preferred.and_then(|code| candidates.iter().find(|c| c.code.eq_ignore_ascii_case(code))) {
+            Some(currency) => Ok(*currency),
+            None => Err(DynParseError::Ambiguous {
+                symbol: symbol.to_string(),
+                candidates,
+            }),
+        },
+    }
+}
+
+/// Parses a number such as `1,000.25` into a raw value of the specified currency.
+///
+/// Digits may come from any [`DigitSystem`] and the separators accepted by the static parser
+/// are recognised here too.
+#[cfg(any(feature = "parsing", feature = "serde"))]
+pub(crate) fn parse_number(number: &str, currency: &CurrencyInfo) -> Result<U256, &'static str> {
+    const OVERFLOW: &str = "amount is too large";
+    let ten = U256::from(10u32);
+    let (decimals, factor) = minor_scale(currency.base);
+    let (whole, fraction) = match (number.split_once(DECIMAL_POINTS), number.rsplit_once(COMMAS)) {
+        (Some((whole, fraction)), _) => (whole, fraction),
+        (None, Some((whole, fraction))) if !whole.contains(COMMAS) && is_decimal_comma(fraction.chars().count(), decimals, currency.style) => {
+            (whole, fraction)
+        }
+        // a last group of other than three digits may have been meant as decimals
+        (None, Some((_, group))) if group.chars().count() != 3 => return Err("expected a decimal point"),
+        (None, _) => (number, ""),
+    };
+    let mut major = U256::zero();
+    let mut previous = None;
+    for c in whole.chars() {
+        match decimal_digit(c) {
+            Some(digit) => {
+                let digit = U256::from(digit as u32);
+                major = major.checked_mul(&ten).and_then(|m| m.checked_add(&digit)).ok_or(OVERFLOW)?;
+            }
+            None if is_group_separator(c) && previous.and_then(decimal_digit).is_some() => (),
+            None => return Err("unexpected character"),
+        }
+        previous = Some(c);
+    }
+    if previous.is_some_and(is_group_separator) {
+        return Err("unexpected character");
+    }
+    let mut minor = U256::zero();
+    let mut fraction_digits = 0;
+    for c in fraction.chars() {
+        let digit = decimal_digit(c).ok_or("unexpected character")?;
+        if fraction_digits < decimals {
+            minor = minor * ten + U256::from(digit as u32);
+        }
+        fraction_digits += 1;
+    }
+    if fraction_digits > decimals {
+        return Err("too many decimal digits");
+    }
+    for _ in fraction_digits..decimals {
+        minor *= ten;
+    }
+    // currencies whose base is not a power of ten have fewer minor units than the digits spell
+    let (minor, remainder) = minor.div_rem(&factor);
+    if !remainder.is_zero() {
+        return Err("not a whole number of minor units");
+    }
+    major.checked_mul(&currency.base).and_then(|m| m.checked_add(&minor)).ok_or(OVERFLOW)
+}
+
+#[cfg(test)]
+extern crate alloc;
+
+#[cfg(test)]
+use alloc::format;
+
+#[test]
+fn test_dyn_amount_conversions() {
+    let amount = Amount::<DOT>::from_raw(12_0000000004);
+    let dynamic = DynAmount::from(amount);
+    assert_eq!(dynamic.currency().code, "DOT");
+    assert_eq!(dynamic.raw_backing(), U256::from(12_0000000004u64));
+    assert_eq!(format!("{}", dynamic), "12.0000000004 DOT");
+    assert_eq!(dynamic.to_amount::<DOT, safety::Unchecked>(), Some(amount));
+    assert_eq!(dynamic.to_amount::<KSM, safety::Unchecked>(), None);
+
+    let too_big = DynAmount::from_raw(CurrencyInfo::of::<USD>(), U256::from(u128::MAX));
+    assert_eq!(too_big.to_amount::<USD, safety::Checked>(), None);
+}
+
+#[cfg(feature = "parsing")]
+#[test]
+fn test_parse_dyn_amount() {
+    let amount: DynAmount = "€12".parse().unwrap();
+    assert_eq!(amount, DynAmount::from(Amount::<EUR>::from_raw(12_00)));
+
+    let amount: DynAmount = "12 DOT".parse().unwrap();
+    assert_eq!(amount, DynAmount::from(Amount::<DOT>::from_raw(12_0000000000)));
+
+    let amount: DynAmount = "USD 5".parse().unwrap();
+    assert_eq!(amount, DynAmount::from(Amount::<USD>::from_raw(5_00)));
+
+    let amount: DynAmount = " 1,000.000000000000000001 eth ".parse().unwrap();
+    assert_eq!(format!("{}", amount), "1000.000000000000000001 ETH");
+
+    let amount: DynAmount = "1.5 kd".parse().unwrap();
+    assert_eq!(amount, DynAmount::from(Amount::<KWD>::from_raw(1_500)));
+}
+
+#[cfg(feature = "parsing")]
+#[test]
+fn test_parse_dyn_amount_ambiguous() {
+    let Err(DynParseError::Ambiguous { symbol, candidates }) = "$5.00".parse::<DynAmount>() else {
+        panic!("expected an ambiguity error");
+    };
+    assert_eq!(symbol, "$");
+    assert!(candidates.contains(&CurrencyInfo::of::<USD>()));
+    assert!(candidates.contains(&CurrencyInfo::of::<CAD>()));
+    assert!(candidates.contains(&CurrencyInfo::of::<AUD>()));
+
+    let amount = DynAmount::parse_with_preference("$5.00", Some("CAD")).unwrap();
+    assert_eq!(amount, DynAmount::from(Amount::<CAD>::from_raw(5_00)));
+
+    let err = DynAmount::parse_with_preference("$5.00", Some("EUR")).unwrap_err();
+    assert!(err.to_string().starts_with("ambiguous currency symbol `$`, could be any of USD, "));
+}
+
+#[cfg(feature = "parsing")]
+#[test]
+fn test_parse_dyn_amount_among() {
+    let usd_eur = [CurrencyInfo::of::<USD>(), CurrencyInfo::of::<EUR>()];
+    let amount = DynAmount::parse_among("$5", &usd_eur).unwrap();
+    assert_eq!(amount, DynAmount::from(Amount::<USD>::from_raw(5_00)));
+
+    let usd_cad = [CurrencyInfo::of::<USD>(), CurrencyInfo::of::<CAD>()];
+    let Err(DynParseError::Ambiguous { candidates, .. }) = DynAmount::parse_among("$5", &usd_cad) else {
+        panic!("expected an ambiguity error");
+    };
+    assert_eq!(candidates.len(), 2);
+    let amount = DynAmount::parse_among("5 CAD", &usd_cad).unwrap();
+    assert_eq!(amount, DynAmount::from(Amount::<CAD>::from_raw(5_00)));
+
+    assert!(matches!(
+        DynAmount::parse_among("0.1 BTC", &usd_eur),
+        Err(DynParseError::UnknownCurrency(_))
+    ));
+}
+
+#[cfg(feature = "parsing")]
+#[test]
+fn test_parse_dyn_amount_errors() {
+    assert_eq!("12.00".parse::<DynAmount>(), Err(DynParseError::MissingCurrency));
+    assert_eq!("12.00 FOO".parse::<DynAmount>(), Err(DynParseError::UnknownCurrency("FOO".into())));
+    assert_eq!("USD".parse::<DynAmount>(), Err(DynParseError::InvalidAmount("expected a number")));
+    assert_eq!(
+        "USD 1.001".parse::<DynAmount>(),
+        Err(DynParseError::InvalidAmount("too many decimal digits"))
+    );
+    assert_eq!(
+        "$5 USD".parse::<DynAmount>().unwrap_err().to_string(),
+        "invalid amount: unexpected text on both sides of the number"
+    );
+    assert_eq!(
+        "USD 1.0.0".parse::<DynAmount>(),
+        Err(DynParseError::InvalidAmount("unexpected character"))
+    );
+    assert_eq!(
+        "1.7 MGA".parse::<DynAmount>(),
+        Err(DynParseError::InvalidAmount("not a whole number of minor units"))
+    );
+}
+
+#[cfg(all(test, feature = "parsing"))]
+crate::define_currency!(TESTDYN, u128, 1_000, "TDY", "Dynamic Parsing Test Token", SuffixSpaced, false, false);
+
+#[cfg(feature = "parsing")]
+#[test]
+fn test_parse_dyn_amount_registered() {
+    assert_eq!("3.5 TESTDYN".parse::<DynAmount>(), Err(DynParseError::UnknownCurrency("TESTDYN".into())));
+    crate::registry::register::<TESTDYN>();
+    let amount: DynAmount = "3.5 TESTDYN".parse().unwrap();
+    assert_eq!(amount.to_amount::<TESTDYN, safety::Unchecked>(), Some(Amount::from_raw(3_500)));
+    let amount: DynAmount = "TDY 3".parse().unwrap();
+    assert_eq!(format!("{}", amount), "3.000 TDY");
+}
+
+#[cfg(feature = "parsing")]
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_parse_dyn_amount_negative() {
+    let expected = -DynAmount::from(Amount::<USD>::from_raw(5_00));
+    for input in ["-$5.00", "$-5.00", "($5.00)", "$5.00-", "−$5", "(USD 5)"] {
+        assert_eq!(DynAmount::parse_with_preference(input, Some("USD")), Ok(expected), "{input}");
+    }
+    assert_eq!(format!("{}", expected), "-$5.00");
+
+    let amount: DynAmount = "−5,00 €".parse().unwrap();
+    assert!(amount.is_negative());
+    assert_eq!(format!("{}", amount), "-5.00€");
+    assert_eq!(amount.to_amount::<EUR, safety::Unchecked>(), None);
+
+    let amount: DynAmount = "5.25- DOT".parse().unwrap();
+    assert_eq!(format!("{}", amount), "-5.2500000000 DOT");
+    assert_eq!("1,000 DOT".parse::<DynAmount>().unwrap().raw_backing(), U256::from(1000_0000000000u64));
+    let err = DynAmount::parse_with_preference("$1,50", Some("USD"));
+    assert_eq!(err, Err(DynParseError::InvalidAmount("expected a decimal point")));
+    let amount = DynAmount::parse_with_preference("$1,500", Some("USD")).unwrap();
+    assert_eq!(amount, DynAmount::from(Amount::<USD>::from_raw(1500_00)));
+    assert_eq!("1,5 DOT".parse::<DynAmount>().unwrap().raw_backing(), U256::from(1_5000000000u64));
+
+    assert_eq!("(-5 DOT)".parse::<DynAmount>(), Err(DynParseError::InvalidAmount("more than one sign")));
+    assert_eq!("-0 DOT".parse::<DynAmount>().map(|a| a.is_negative()), Ok(false));
+}
+
+#[cfg(feature = "parsing")]
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_parse_dyn_amount_unicode_digits() {
+    let amount: DynAmount = "١٢٫٥ AED".parse().unwrap();
+    assert_eq!(amount, DynAmount::from(Amount::<AED>::from_raw(12_50)));
+    let amount: DynAmount = "1\u{202F}000,50\u{A0}€".parse().unwrap();
+    assert_eq!(amount, DynAmount::from(Amount::<EUR>::from_raw(1000_50)));
+    let amount: DynAmount = "₹१,२३४".parse().unwrap();
+    assert_eq!(amount, DynAmount::from(Amount::<INR>::from_raw(1234_00)));
+    let amount: DynAmount = "１２３\u{3000}DOT".parse().unwrap();
+    assert_eq!(
+        format!("{}", amount.with_digits(DigitSystem::FullWidth)),
+        "１２３.００００００００００ DOT"
+    );
+    assert_eq!("1٬ DOT".parse::<DynAmount>(), Err(DynParseError::InvalidAmount("unexpected character")));
+}
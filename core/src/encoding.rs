@@ -0,0 +1,101 @@
+//! Home of [`LeBytes`], a fixed-width little-endian binary encoding for [`Backing`] types and
+//! the [`Amount`]s backed by them.
+//!
+//! Unlike the human-readable string produced by the [`core::fmt::Display`] impl of [`Amount`],
+//! this encoding always has the same length for a given [`Backing`], which makes it suitable for
+//! storage keys and other places where a compact, predictable layout matters. Integrations
+//! with `parity-scale-codec` and `borsh` are available behind the `scale` and `borsh` features.
+
+use crate::amount::{Amount, Backing};
+use crate::currency::Currency;
+use crate::safety;
+use crate::u256::U256;
+
+/// Implemented on [`Backing`] types that have a fixed-width little-endian binary encoding.
+///
+/// Implemented for all primitive integer [`Backing`]s and for [`U256`], whose encoding is 32
+/// bytes long.
+pub trait LeBytes: Backing {
+    /// The encoded form of this type, e.g. `[u8; 8]` for [`u64`].
+    type Bytes: AsRef<[u8]> + AsMut<[u8]> + Default + Copy + core::fmt::Debug;
+
+    /// Returns the little-endian encoding of `self`.
+    fn to_le_bytes(&self) -> Self::Bytes;
+
+    /// Decodes a value from its little-endian encoding.
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+}
+
+macro_rules! impl_le_bytes {
+    ($($ty:ty),*) => {
+        $(
+            impl LeBytes for $ty {
+                type Bytes = [u8; core::mem::size_of::<$ty>()];
+
+                fn to_le_bytes(&self) -> Self::Bytes {
+                    <$ty>::to_le_bytes(*self)
+                }
+
+                fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                    <$ty>::from_le_bytes(bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_le_bytes!(u32, u64, u128, i64, i128);
+
+impl LeBytes for U256 {
+    type Bytes = [u8; 32];
+
+    fn to_le_bytes(&self) -> Self::Bytes {
+        let mut bytes = [0; 32];
+        self.0.to_little_endian(&mut bytes);
+        bytes
+    }
+
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        U256(primitive_types::U256::from_little_endian(&bytes))
+    }
+}
+
+impl<C: Currency, Safety: safety::Safety> Amount<C, Safety>
+where
+    C::Backing: LeBytes,
+{
+    /// Returns the fixed-width little-endian encoding of the raw [`Backing`] value of this
+    /// [`Amount`], e.g. 16 bytes for an [`Amount`] of [`DOT`](crate::currency::DOT).
+    pub fn to_le_bytes(&self) -> <C::Backing as LeBytes>::Bytes {
+        self.raw_backing().to_le_bytes()
+    }
+
+    /// Decodes an [`Amount`] from the fixed-width little-endian encoding of its raw [`Backing`]
+    /// value, as produced by [`Amount::to_le_bytes`].
+    pub fn from_le_bytes(bytes: <C::Backing as LeBytes>::Bytes) -> Self {
+        Amount::from_raw(C::Backing::from_le_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+use crate::currency::{DOT, ETH, USD};
+
+#[test]
+fn test_le_bytes() {
+    let amount = Amount::<USD>::from_raw(1_00);
+    assert_eq!(amount.to_le_bytes(), [100, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(Amount::<USD>::from_le_bytes(amount.to_le_bytes()), amount);
+
+    let amount = Amount::<DOT>::from_raw(12_0000000004);
+    assert_eq!(amount.to_le_bytes().len(), 16);
+    assert_eq!(Amount::<DOT>::from_le_bytes(amount.to_le_bytes()), amount);
+
+    let amount = Amount::<ETH>::from_raw(U256::from(0x0102u64));
+    let mut expected = [0u8; 32];
+    expected[..2].copy_from_slice(&[0x02, 0x01]);
+    assert_eq!(amount.to_le_bytes(), expected);
+    assert_eq!(Amount::<ETH>::from_le_bytes(expected), amount);
+    assert_eq!(U256::from_le_bytes([0xff; 32]), U256::MAX_VALUE);
+
+    assert_eq!(LeBytes::to_le_bytes(&-2i64), [0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]);
+}
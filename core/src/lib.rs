@@ -6,28 +6,96 @@
 
 pub mod amount;
 pub use amount::{Amount, Backing};
+pub mod checked;
+pub use checked::ArithmeticError;
 pub mod currency;
-pub use currency::Currency;
+pub use currency::{Currency, CurrencyInfo};
+pub mod currency_set;
+pub mod digits;
+pub use digits::DigitSystem;
+pub mod encoding;
+pub use encoding::LeBytes;
+pub mod conversion;
+pub use conversion::ConversionError;
+pub mod dynamic;
+pub use dynamic::DynAmount;
+pub mod registry;
 pub mod u256;
 pub use u256::U256;
+pub mod iso4217;
+pub mod literal;
 pub mod safety;
 
-#[cfg(feature = "parsing")]
 mod parsing;
-#[cfg(feature = "parsing")]
 pub use parsing::*;
 
-/// Contains impls for [`serde`] integration
+/// Contains impls for [`serde`](::serde) integration
 #[cfg(feature = "serde")]
 pub mod serde_integration;
+#[cfg(feature = "serde")]
+pub use serde_integration as serde;
 
-#[cfg(test)]
+/// Contains impls for [`parity_scale_codec`] integration
+#[cfg(feature = "scale")]
+pub mod scale_integration;
+
+/// Contains impls for [`borsh`] integration
+#[cfg(feature = "borsh")]
+pub mod borsh_integration;
+
+/// Contains impls for [`rust_decimal`] integration
+#[cfg(feature = "rust_decimal")]
+pub mod rust_decimal_integration;
+
+/// Contains impls for [`bigdecimal`] integration
+#[cfg(feature = "bigdecimal")]
+pub mod bigdecimal_integration;
+
+/// Contains impls for [`postgres_types`] integration
+#[cfg(feature = "postgres")]
+pub mod postgres_integration;
+
+/// Contains impls for [`rusqlite`] integration
+#[cfg(feature = "rusqlite")]
+pub mod rusqlite_integration;
+
+/// Contains the `google.type.Money` message and its [`prost`] integration
+#[cfg(feature = "prost")]
+pub mod prost_integration;
+
+/// Contains conversions between [`Amount`] slices and [`arrow_array`] decimal arrays
+#[cfg(feature = "arrow")]
+pub mod arrow_integration;
+
+/// Contains stand-in types describing the alternative [`serde`](::serde) representations of
+/// [`Amount`] in generated schemas
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+pub mod schema;
+
+/// Contains impls for [`schemars`] integration
+#[cfg(feature = "schemars")]
+pub mod schemars_integration;
+
+/// Contains impls for [`utoipa`] integration
+#[cfg(feature = "utoipa")]
+pub mod utoipa_integration;
+
+/// Contains impls for [`arbitrary`] integration
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary_integration;
+
+/// Contains impls and strategies for [`proptest`] integration
+#[cfg(feature = "proptest")]
+pub mod proptest_integration;
+
+#[cfg(any(test, feature = "schemars", feature = "utoipa"))]
 extern crate alloc;
 
 #[cfg(test)]
 use alloc::format;
 
 #[test]
+#[allow(clippy::useless_conversion)]
 fn show_off_currency_math() {
     use currency::*;
 
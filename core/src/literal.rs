@@ -0,0 +1,619 @@
+//! Compile-time parsing of amount literals, which is how the `amt!` and `amt_checked!` macros
+//! support currencies defined outside of this crate.
+//!
+//! The macros cannot see the [`Currency`] impl of a user-defined type, so instead of parsing
+//! the literal themselves they emit a [`Literal`] holding its text and read the parsed value
+//! from [`FromLiteral::PARSED`]. That constant is evaluated by the compiler against the
+//! [`Currency::BASE`], [`Currency::SYMBOL`] and [`Currency::STYLE`] of the currency, so an
+//! invalid literal is still rejected at compile time.
+//!
+//! A literal may be written in the format accepted by the [`FromStr`](core::str::FromStr) impl
+//! of [`Amount`], except that only ASCII letters of the currency symbol are matched
+//! case-insensitively, or as a decimal number followed by a space and the currency code as in
+//! [`Amount::from_code_str`]. A [`Literal::NUMERIC`] literal is a bare decimal number as in
+//! [`Amount::from_decimal_str`]. [`parse_literal`] applies the same rules at runtime.
+
+use crate::amount::Amount;
+use crate::currency::{Currency, FormatStyle};
+use crate::digits::DigitSystem;
+use crate::parsing::{is_decimal_comma, ParseError, ParseErrorKind};
+use crate::u256::U256;
+
+/// The text of an amount literal, supplied as a type so that it can be parsed in a constant.
+pub trait Literal {
+    /// The literal to parse, such as `"$3.24"` or `"3.24 USD"`.
+    const TEXT: &'static str;
+
+    /// Whether [`Literal::TEXT`] is a bare decimal number such as `3.24`, written as a numeric
+    /// literal rather than a string.
+    const NUMERIC: bool = false;
+}
+
+/// Implemented by the [`Backing`](crate::Backing) types whose amounts can be parsed from a
+/// [`Literal`] at compile time: [`u32`], [`u64`], [`u128`], [`i64`], [`i128`] and [`U256`].
+#[diagnostic::on_unimplemented(
+    message = "amounts of `{C}` cannot be parsed at compile time",
+    note = "only currencies backed by `u32`, `u64`, `u128`, `i64`, `i128` or `U256` are supported"
+)]
+pub trait FromLiteral<C: Currency, L: Literal>: Sized {
+    /// The raw value of the amount of `C` written by `L`, or the reason it is invalid.
+    const PARSED: Result<Self, ParseErrorKind>;
+}
+
+macro_rules! impl_from_literal {
+    ($($ty:ty),*) => {
+        $(
+            impl<C: Currency<Backing = $ty>, L: Literal> FromLiteral<C, L> for $ty {
+                const PARSED: Result<$ty, ParseErrorKind> =
+                    match parse_text::<C, L>(from_u128(C::BASE as u128), <$ty>::MIN != 0) {
+                        Ok((negative, magnitude)) => {
+                            let limit = <$ty>::MAX as u128 + negative as u128;
+                            match to_u128(magnitude) {
+                                Some(magnitude) if magnitude <= limit => match negative {
+                                    true => Ok((magnitude as $ty).wrapping_neg()),
+                                    false => Ok(magnitude as $ty),
+                                },
+                                _ => Err(ParseErrorKind::Overflow),
+                            }
+                        }
+                        Err(kind) => Err(kind),
+                    };
+            }
+        )*
+    };
+}
+
+impl_from_literal!(u32, u64, u128, i64, i128);
+
+impl<C: Currency<Backing = U256>, L: Literal> FromLiteral<C, L> for U256 {
+    const PARSED: Result<U256, ParseErrorKind> = match parse_text::<C, L>(C::BASE.0 .0, false) {
+        Ok((_, magnitude)) => Ok(U256(primitive_types::U256(magnitude))),
+        Err(kind) => Err(kind),
+    };
+}
+
+/// Parses `text` at runtime following the same rules as [`FromLiteral::PARSED`], treating it
+/// as a bare decimal number if `numeric` is set. The `amt!` macros use this to validate literals
+/// of the built-in currencies while they are expanded, which gives more specific errors than a
+/// failed constant.
+pub fn parse_literal<C: Currency>(text: &str, numeric: bool) -> Result<C::Backing, ParseError> {
+    let amount = match numeric {
+        true => Amount::<C>::from_decimal_str(text),
+        false => text.parse::<Amount<C>>().or_else(|err| match ends_with_code(text.as_bytes(), C::CODE) {
+            true => Amount::from_code_str(text),
+            false => Err(err),
+        }),
+    };
+    amount.map(|amount| amount.raw_backing())
+}
+
+/// Describes `kind` for the compile error reported when an amount literal is invalid.
+pub const fn message(kind: ParseErrorKind) -> &'static str {
+    match kind {
+        ParseErrorKind::Expected(expected) if str_eq(expected, ".") => "invalid amount: expected `.`",
+        ParseErrorKind::Expected(expected) if str_eq(expected, " ") => "invalid amount: expected ` `",
+        ParseErrorKind::Expected(expected) if str_eq(expected, ")") => "invalid amount: expected `)`",
+        ParseErrorKind::Expected(_) => "invalid amount: expected the currency symbol",
+        ParseErrorKind::ExpectedDigit => "invalid amount: expected digit (0-9)",
+        ParseErrorKind::ExpectedHexDigit => "invalid amount: expected hexadecimal digit (0-9, a-f)",
+        ParseErrorKind::TooManyDecimalDigits => "invalid amount: too many decimal digits",
+        ParseErrorKind::Overflow => "invalid amount: amount is too large",
+        ParseErrorKind::TrailingCharacters => "invalid amount: unexpected trailing characters",
+        ParseErrorKind::Negative => "invalid amount: negative amounts require a signed backing",
+        ParseErrorKind::Inexact => "invalid amount: amount is not a whole number of minor units",
+    }
+}
+
+/// An unsigned 256-bit integer as little-endian 64-bit limbs, the same layout as [`U256`],
+/// which is wide enough to hold the magnitude of an amount of any supported backing.
+pub(crate) type Wide = [u64; 4];
+
+/// Unwraps a [`Result`], returning early with its error. `?` is not allowed in `const fn`s.
+macro_rules! tri {
+    ($result:expr) => {
+        match $result {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        }
+    };
+}
+
+pub(crate) const fn from_u128(n: u128) -> Wide {
+    [n as u64, (n >> 64) as u64, 0, 0]
+}
+
+const fn to_u128(n: Wide) -> Option<u128> {
+    match n[2] == 0 && n[3] == 0 {
+        true => Some(n[0] as u128 | (n[1] as u128) << 64),
+        false => None,
+    }
+}
+
+/// Computes `n * factor + addend`, returning [`None`] on overflow.
+const fn mul_add(n: Wide, factor: Wide, addend: Wide) -> Option<Wide> {
+    let mut out = [0u64; 8];
+    let mut i = 0;
+    while i < 4 {
+        let mut carry = 0u128;
+        let mut j = 0;
+        while j < 4 {
+            let sum = out[i + j] as u128 + n[i] as u128 * factor[j] as u128 + carry;
+            out[i + j] = sum as u64;
+            carry = sum >> 64;
+            j += 1;
+        }
+        out[i + 4] = carry as u64;
+        i += 1;
+    }
+    let mut carry = 0u128;
+    let mut k = 0;
+    while k < 8 {
+        let sum = out[k] as u128 + if k < 4 { addend[k] as u128 } else { 0 } + carry;
+        out[k] = sum as u64;
+        carry = sum >> 64;
+        k += 1;
+    }
+    match out[4] | out[5] | out[6] | out[7] {
+        0 => Some([out[0], out[1], out[2], out[3]]),
+        _ => None,
+    }
+}
+
+/// Computes `n * 10 + digit`, or [`None`] if `n` is [`None`] or the result overflows.
+const fn push_digit(n: Option<Wide>, digit: u8) -> Option<Wide> {
+    match n {
+        Some(n) => mul_add(n, from_u128(10), from_u128(digit as u128)),
+        None => None,
+    }
+}
+
+/// Divides `n` by the non-zero `divisor`, returning the quotient and the remainder.
+pub(crate) const fn div_rem_small(mut n: Wide, divisor: u64) -> (Wide, u64) {
+    let mut remainder = 0u128;
+    let mut i = 4;
+    while i > 0 {
+        i -= 1;
+        let limb = (remainder << 64) | n[i] as u128;
+        n[i] = (limb / divisor as u128) as u64;
+        remainder = limb % divisor as u128;
+    }
+    (n, remainder as u64)
+}
+
+/// Returns the number of digits after the decimal point of a currency with the specified
+/// base, as computed by [`Currency::decimal_digits`], along with the number of units in the
+/// last decimal place that each minor unit is worth.
+const fn minor_scale(mut base: Wide) -> (usize, u64) {
+    let mut digits = 0;
+    let mut factor = 1;
+    while base[1] != 0 || base[2] != 0 || base[3] != 0 || base[0] > 1 {
+        // once no factor of ten is left, only twos or only fives remain
+        base = match (div_rem_small(base, 10), div_rem_small(base, 5)) {
+            ((quotient, 0), _) => quotient,
+            (_, (quotient, 0)) => {
+                factor *= 2;
+                quotient
+            }
+            _ => {
+                factor *= 5;
+                div_rem_small(base, 2).0
+            }
+        };
+        digits += 1;
+    }
+    (digits, factor)
+}
+
+pub(crate) const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Decodes the character at byte offset `pos` of `s`, returning it along with its length in
+/// bytes, or [`None`] at the end of the input.
+const fn peek(s: &[u8], pos: usize) -> Option<(char, usize)> {
+    if pos >= s.len() {
+        return None;
+    }
+    let first = s[pos] as u32;
+    let (len, mut code) = match first {
+        0x00..=0x7f => (1, first),
+        0xc0..=0xdf => (2, first & 0x1f),
+        0xe0..=0xef => (3, first & 0x0f),
+        _ => (4, first & 0x07),
+    };
+    let mut i = 1;
+    while i < len {
+        code = (code << 6) | (s[pos + i] as u32 & 0x3f);
+        i += 1;
+    }
+    match char::from_u32(code) {
+        Some(c) => Some((c, len)),
+        None => None,
+    }
+}
+
+const fn contains(chars: &[char], c: char) -> bool {
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] == c {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// The `const` counterpart of [`decimal_digit`](crate::digits::decimal_digit).
+const fn decimal_digit(c: char) -> Option<u8> {
+    let mut i = 0;
+    while i < DigitSystem::ALL.len() {
+        let value = (c as u32).wrapping_sub(DigitSystem::ALL[i].zero() as u32);
+        if value < 10 {
+            return Some(value as u8);
+        }
+        i += 1;
+    }
+    None
+}
+
+// These mirror the lists in `digits` and `parsing`, whose `contains` is not a `const fn`.
+const DECIMAL_POINTS: [char; 3] = ['.', '\u{FF0E}', '\u{066B}'];
+const COMMAS: [char; 2] = [',', '\u{FF0C}'];
+const SPACES: [char; 4] = [' ', '\u{00A0}', '\u{202F}', '\u{3000}'];
+const MINUS_SIGNS: [char; 2] = ['-', '\u{2212}'];
+
+const fn is_group_separator(c: char) -> bool {
+    contains(&COMMAS, c) || contains(&SPACES, c) || c == '\u{066C}'
+}
+
+/// Returns the offset just past a character of `chars` at byte offset `pos`, if there is one.
+const fn take(s: &[u8], pos: usize, chars: &[char]) -> Option<usize> {
+    match peek(s, pos) {
+        Some((c, len)) if contains(chars, c) => Some(pos + len),
+        _ => None,
+    }
+}
+
+/// Consumes `expected` from `s` at byte offset `pos`, ignoring the case of ASCII letters.
+const fn expect_str(s: &[u8], pos: usize, expected: &'static str) -> Result<usize, ParseErrorKind> {
+    let bytes = expected.as_bytes();
+    if pos + bytes.len() > s.len() {
+        return Err(ParseErrorKind::Expected(expected));
+    }
+    let mut i = 0;
+    while i < bytes.len() {
+        if !s[pos + i].eq_ignore_ascii_case(&bytes[i]) {
+            return Err(ParseErrorKind::Expected(expected));
+        }
+        i += 1;
+    }
+    Ok(pos + bytes.len())
+}
+
+/// The `const` counterpart of `parse_number` in the runtime parser: parses the unsigned number
+/// at byte offset `pos` of `s` into a number of minor units, returning it along with the offset
+/// just past the number. The decimal point may only be omitted if `point_optional` is set or
+/// the currency has no decimal digits, and a decimal comma is only accepted in the notation of
+/// currencies with the specified `style`.
+const fn parse_number(s: &[u8], mut pos: usize, base: Wide, style: FormatStyle, point_optional: bool) -> Result<(Wide, usize), ParseErrorKind> {
+    let whole_start = pos;
+    let mut whole = Some([0; 4]);
+    // the offset just past the last comma, the whole part before it and the digits seen since
+    let mut last_comma: Option<(usize, Option<Wide>, usize)> = None;
+    let mut commas = 0;
+    let mut whole_digits = 0;
+    while let Some((c, len)) = peek(s, pos) {
+        if let Some(digit) = decimal_digit(c) {
+            whole = push_digit(whole, digit);
+            whole_digits += 1;
+            if let Some((comma, before, digits)) = last_comma {
+                last_comma = Some((comma, before, digits + 1));
+            }
+            pos += len;
+        } else if pos > whole_start && contains(&COMMAS, c) {
+            last_comma = Some((pos + len, whole, 0));
+            commas += 1;
+            pos += len;
+            match peek(s, pos) {
+                Some((c, _)) if decimal_digit(c).is_some() => (),
+                _ => return Err(ParseErrorKind::ExpectedDigit),
+            }
+        } else if pos > whole_start && is_group_separator(c) {
+            match peek(s, pos + len) {
+                Some((next, _)) if decimal_digit(next).is_some() => pos += len,
+                _ => break,
+            }
+        } else {
+            break;
+        }
+    }
+    let (decimals, factor) = minor_scale(base);
+    let whole = match (take(s, pos, &DECIMAL_POINTS), last_comma) {
+        (Some(end), _) => {
+            pos = end;
+            whole
+        }
+        (None, Some((after_comma, before, digits))) if commas == 1 && is_decimal_comma(digits, decimals, style) => {
+            pos = after_comma;
+            before
+        }
+        (None, Some((_, _, digits))) if digits != 3 => return Err(ParseErrorKind::Expected(".")),
+        _ if point_optional || decimals == 0 => whole,
+        _ => return Err(ParseErrorKind::Expected(".")),
+    };
+    let Some(whole) = whole else {
+        return Err(ParseErrorKind::Overflow);
+    };
+    let mut fraction = Some([0; 4]);
+    let mut fraction_digits = 0;
+    while let Some((c, len)) = peek(s, pos) {
+        let Some(digit) = decimal_digit(c) else {
+            break;
+        };
+        pos += len;
+        if fraction_digits < decimals {
+            fraction = push_digit(fraction, digit);
+        }
+        fraction_digits += 1;
+    }
+    if fraction_digits > decimals {
+        return Err(ParseErrorKind::TooManyDecimalDigits);
+    }
+    if whole_digits == 0 && fraction_digits == 0 {
+        return Err(ParseErrorKind::ExpectedDigit);
+    }
+    while fraction_digits < decimals {
+        fraction = push_digit(fraction, 0);
+        fraction_digits += 1;
+    }
+    let Some(fraction) = fraction else {
+        return Err(ParseErrorKind::Overflow);
+    };
+    // the fraction of a currency whose base is not a power of ten must be a whole number of
+    // minor units, e.g. `1.4 Ar` is 2 of the 5 iraimbilanja to the ariary but `1.7 Ar` is none
+    let (fraction, 0) = div_rem_small(fraction, factor) else {
+        return Err(ParseErrorKind::Inexact);
+    };
+    match mul_add(whole, base, fraction) {
+        Some(raw) => Ok((raw, pos)),
+        None => Err(ParseErrorKind::Overflow),
+    }
+}
+
+/// Parses `text` as an amount of a currency with the specified symbol, style and base,
+/// returning whether it is negative along with its magnitude in minor units. Negative amounts
+/// are rejected unless `signed` is set.
+const fn parse(text: &str, symbol: &'static str, style: FormatStyle, base: Wide, signed: bool) -> Result<(bool, Wide), ParseErrorKind> {
+    let s = text.as_bytes();
+    let prefixed = matches!(style, FormatStyle::PrefixAttached | FormatStyle::PrefixSpaced);
+    let suffixed = matches!(style, FormatStyle::SuffixAttached | FormatStyle::SuffixSpaced);
+    let parenthesized = !s.is_empty() && s[0] == b'(';
+    let mut pos = parenthesized as usize;
+    let mut negative = parenthesized;
+    // a minus sign may appear at any of the places the runtime parser accepts one, but only once
+    macro_rules! minus {
+        () => {
+            if let (false, Some(end)) = (negative, take(s, pos, &MINUS_SIGNS)) {
+                negative = true;
+                pos = end;
+            }
+        };
+    }
+    macro_rules! space {
+        ($required:expr) => {
+            match (take(s, pos, &SPACES), $required) {
+                (Some(end), _) => pos = end,
+                (None, true) => return Err(ParseErrorKind::Expected(" ")),
+                (None, false) => (),
+            }
+        };
+    }
+    minus!();
+    if prefixed {
+        pos = tri!(expect_str(s, pos, symbol));
+        space!(matches!(style, FormatStyle::PrefixSpaced));
+        minus!();
+    }
+    let (magnitude, end) = tri!(parse_number(s, pos, base, style, false));
+    pos = end;
+    minus!();
+    if suffixed {
+        space!(matches!(style, FormatStyle::SuffixSpaced));
+        pos = tri!(expect_str(s, pos, symbol));
+        minus!();
+    }
+    if parenthesized {
+        pos = tri!(expect_str(s, pos, ")"));
+    }
+    if negative && !signed {
+        return Err(ParseErrorKind::Negative);
+    }
+    if pos < s.len() {
+        return Err(ParseErrorKind::TrailingCharacters);
+    }
+    Ok((negative, magnitude))
+}
+
+/// Parses the text of `L` as an amount of `C` with the specified base, following the rules
+/// described in the [module documentation](self).
+const fn parse_text<C: Currency, L: Literal>(base: Wide, signed: bool) -> Result<(bool, Wide), ParseErrorKind> {
+    let s = L::TEXT.as_bytes();
+    if L::NUMERIC {
+        return parse_plain(s, None, base, C::STYLE, signed);
+    }
+    match parse(L::TEXT, C::SYMBOL, C::STYLE, base, signed) {
+        Err(_) if ends_with_code(s, C::CODE) => parse_plain(s, Some(C::CODE), base, C::STYLE, signed),
+        result => result,
+    }
+}
+
+/// The `const` counterpart of [`Amount::from_decimal_str`], or of [`Amount::from_code_str`] if
+/// a `code` is given.
+const fn parse_plain(s: &[u8], code: Option<&'static str>, base: Wide, style: FormatStyle, signed: bool) -> Result<(bool, Wide), ParseErrorKind> {
+    let (negative, mut pos) = match take(s, 0, &MINUS_SIGNS) {
+        Some(end) => (true, end),
+        None => (false, 0),
+    };
+    if negative && !signed {
+        return Err(ParseErrorKind::Negative);
+    }
+    let (magnitude, end) = tri!(parse_number(s, pos, base, style, true));
+    pos = end;
+    if let Some(code) = code {
+        pos = match take(s, pos, &SPACES) {
+            Some(end) => end,
+            None => return Err(ParseErrorKind::Expected(" ")),
+        };
+        pos = tri!(expect_str(s, pos, code));
+    }
+    if pos < s.len() {
+        return Err(ParseErrorKind::TrailingCharacters);
+    }
+    Ok((negative, magnitude))
+}
+
+/// Returns `true` if `s` ends with `code`, ignoring the case of ASCII letters, in which case a
+/// literal that is not in the format of the [`FromStr`](core::str::FromStr) impl is read as in
+/// [`Amount::from_code_str`].
+const fn ends_with_code(s: &[u8], code: &str) -> bool {
+    let code = code.as_bytes();
+    if code.len() > s.len() {
+        return false;
+    }
+    let start = s.len() - code.len();
+    let mut i = 0;
+    while i < code.len() {
+        if !s[start + i].eq_ignore_ascii_case(&code[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+use crate::currency::*;
+
+#[cfg(test)]
+crate::define_currency!(LITERALGEM, i128, 1_000, "gem", "Literal Gem", SuffixSpaced, false, false);
+
+#[cfg(test)]
+crate::define_currency!(LITERALCR, u32, 5, "₡", "Literal Colón", PrefixSpaced, false, false, radix);
+
+#[cfg(test)]
+fn parse_const<C: Currency, L: Literal>() -> Result<C::Backing, ParseErrorKind>
+where
+    C::Backing: FromLiteral<C, L>,
+{
+    <C::Backing as FromLiteral<C, L>>::PARSED
+}
+
+#[cfg(test)]
+macro_rules! check_literals {
+    ($currency:ty: $($text:literal),* $(,)?) => {
+        check_literals!(@check $currency, false: $($text),*);
+    };
+    ($currency:ty, numeric: $($text:literal),* $(,)?) => {
+        check_literals!(@check $currency, true: $($text),*);
+    };
+    (@check $currency:ty, $numeric:literal: $($text:literal),*) => {
+        $({
+            struct Text;
+            impl Literal for Text {
+                const TEXT: &'static str = $text;
+                const NUMERIC: bool = $numeric;
+            }
+            let expected = parse_literal::<$currency>($text, $numeric).map_err(|err| err.kind());
+            assert_eq!(parse_const::<$currency, Text>(), expected, "{}", $text);
+        })*
+    };
+}
+
+#[test]
+fn test_literals_match_runtime_parser() {
+    check_literals!(USD: "$3.24", "$1,234.56", "-$5.00", "$-5.00", "($5.00)", "$5.00-", "$.5", "$3", "$3.245", "3.24", "$3.24 ", "$ 3.24");
+    check_literals!(USD: "$18446744073709551.61", "$18446744073709551.62", "$1,000", "$5,00", "$5,000,00", "$١٢٣.٤٥");
+    check_literals!(EUR: "3.24€", "5，00€", "-3,24€", "3 000.50€", "3.24 €", "3.24");
+    check_literals!(LITERALGEM: "12.345 gem", "-12.345 GEM", "12.345gem", "(0.001 gem)", "12.345 gem-", "−1 gem", "1.2345 gem");
+    check_literals!(LITERALGEM: "170141183460469231731687303715884105.727 gem", "-170141183460469231731687303715884105.728 gem");
+    check_literals!(LITERALGEM: "170141183460469231731687303715884105.728 gem", "-170141183460469231731687303715884105.729 gem");
+    check_literals!(LITERALCR: "₡ 3.2", "₡3.2", "₡ 858993459.0", "₡ 858993459.1", "₡ -1.0", "₡ 3.5", "₡ 3.9");
+    check_literals!(MGA: "1.4 Ar", "1.7 Ar");
+    check_literals!(JPY: "500¥", "500.¥", "¥500", "500", "500.5¥", "1,500¥");
+    check_literals!(KRW: "500 ₩", "500₩", "500. ₩");
+    check_literals!(BTC: "0.00000001 BTC", "21000000.00000000 btc", "1 BTC", "1. BTC");
+    check_literals!(DOT: "115244897682.0000000008 DOT");
+    check_literals!(ETH: "1.5 ETH", "0.000000000000000001 ETH", "-1.0 ETH", "1.0000000000000000001 ETH");
+    check_literals!(ETH: "115792089237316195423570985008687907853269984665640564039457.584007913129639935 ETH");
+    check_literals!(ETH: "115792089237316195423570985008687907853269984665640564039457.584007913129639936 ETH");
+}
+
+#[test]
+fn test_code_suffixed_literals_match_runtime_parser() {
+    check_literals!(USD: "3.24 USD", "3 usd", "-3.24 USD", "1,000.50 USD", "3.245 USD", "3.24USD", "3.24  USD", "USD", " USD");
+    check_literals!(USD: "18446744073709551.61 USD", "18446744073709551.62 USD", "$3.24 USD", "3.24 USDx");
+    check_literals!(EUR: "3,24 EUR", "3.24 EUR", "3.24\u{00A0}EUR");
+    check_literals!(LITERALGEM: "12.345 LITERALGEM", "-170141183460469231731687303715884105.728 LITERALGEM", "−1 literalgem");
+    check_literals!(LITERALCR: "3.2 LITERALCR", "3.24 LITERALCR", "-1 LITERALCR", "3.7 LITERALCR");
+    check_literals!(ETH: "1.5 ETH", "-1.5 ETH");
+}
+
+#[test]
+fn test_numeric_literals_match_runtime_parser() {
+    check_literals!(USD, numeric: "3.24", "3", "3.", ".5", "-3.24", "3.245", "18446744073709551.61", "18446744073709551.62", "1e3", "");
+    check_literals!(LITERALGEM, numeric: "-12.345", "-170141183460469231731687303715884105.728", "0.0001");
+    check_literals!(LITERALCR, numeric: "858993459.0", "0.4", "-0", "0.5");
+    check_literals!(ETH, numeric: "1.5", "115792089237316195423570985008687907853269984665640564039457.584007913129639935");
+}
+
+#[test]
+fn test_parse_literal() {
+    assert_eq!(parse_literal::<USD>("$3.24", false), Ok(3_24));
+    assert_eq!(parse_literal::<USD>("3.24 USD", false), Ok(3_24));
+    assert_eq!(parse_literal::<USD>("3.24", true), Ok(3_24));
+    assert_eq!(
+        parse_literal::<USD>("3.24", false).map_err(|err| err.kind()),
+        Err(ParseErrorKind::Expected("$"))
+    );
+    assert_eq!(
+        parse_literal::<USD>("$3.24", true).map_err(|err| err.kind()),
+        Err(ParseErrorKind::ExpectedDigit)
+    );
+    assert_eq!(parse_literal::<ETH>("1 ETH", false), Ok(ETH::BASE));
+}
+
+#[test]
+fn test_literal_messages() {
+    assert_eq!(message(ParseErrorKind::Expected(".")), "invalid amount: expected `.`");
+    assert_eq!(message(ParseErrorKind::Expected("gem")), "invalid amount: expected the currency symbol");
+    assert_eq!(
+        message(ParseErrorKind::Negative),
+        "invalid amount: negative amounts require a signed backing"
+    );
+}
+
+#[test]
+fn test_wide_arithmetic() {
+    assert_eq!(
+        mul_add(from_u128(u128::MAX), from_u128(u128::MAX), from_u128(1)),
+        Some([2, 0, u64::MAX - 1, u64::MAX])
+    );
+    assert_eq!(mul_add([0, 0, 0, 1], from_u128(1 << 64), [0; 4]), None);
+    assert_eq!(mul_add([0, 0, 1, 0], from_u128(1 << 64), [0; 4]), Some([0, 0, 0, 1]));
+    assert_eq!(mul_add([u64::MAX; 4], from_u128(1), from_u128(1)), None);
+    assert_eq!(minor_scale(ETH::BASE.0 .0), (18, 1));
+    assert_eq!(minor_scale(from_u128(5)), (1, 2));
+    assert_eq!(minor_scale(from_u128(4)), (2, 25));
+    assert_eq!(minor_scale(from_u128(1)), (0, 1));
+}
@@ -1,17 +1,474 @@
-use core::{fmt::Debug, str::FromStr};
+use core::{fmt, ops::Range, str::FromStr};
 
-use crate::safety::Unchecked;
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, Zero};
 
-use super::*;
+use crate::amount::{minor_scale, Amount, Backing};
 use crate::currency::*;
+use crate::digits::{decimal_digit, is_group_separator, COMMAS, DECIMAL_POINTS, SPACES};
+use crate::safety;
+
+#[cfg(feature = "quoth")]
+use crate::safety::Unchecked;
+#[cfg(feature = "quoth")]
+use core::fmt::Debug;
+#[cfg(feature = "quoth")]
+use quoth::{Parsable, ParsableExt, Span, Spanned};
+
+/// Describes what went wrong while parsing an [`Amount`]. See [`ParseError::kind`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ParseErrorKind {
+    /// The specified text, such as the currency symbol or the decimal point, was expected.
+    Expected(&'static str),
+    /// A digit was expected, e.g. after a thousands separator.
+    ExpectedDigit,
+    /// A hexadecimal digit was expected.
+    ExpectedHexDigit,
+    /// More digits follow the decimal point than the currency supports.
+    TooManyDecimalDigits,
+    /// The amount is too large to be represented by the [`Backing`] of the currency.
+    Overflow,
+    /// A complete amount was parsed but more input follows it.
+    TrailingCharacters,
+    /// The amount is negative but the [`Backing`] of the currency is unsigned.
+    Negative,
+    /// The amount is not a whole number of minor units, e.g. `1.5e0` wei, or `1.7 Ar` with
+    /// five iraimbilanja (of 0.2 Ar each) to the ariary.
+    Inexact,
+}
+
+/// The error returned when an [`Amount`] cannot be parsed from a string.
+///
+/// Includes the byte range of the offending portion of the input, see [`ParseError::span`].
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    span: Range<usize>,
+}
+
+impl ParseError {
+    fn new(kind: ParseErrorKind, span: Range<usize>) -> Self {
+        ParseError { kind, span }
+    }
+
+    /// Returns the [`ParseErrorKind`] of this [`ParseError`].
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte range of the input that this [`ParseError`] refers to.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Expected(expected) => write!(f, "expected `{}`", expected),
+            ParseErrorKind::ExpectedDigit => write!(f, "expected digit (0-9)"),
+            ParseErrorKind::ExpectedHexDigit => write!(f, "expected hexadecimal digit (0-9, a-f)"),
+            ParseErrorKind::TooManyDecimalDigits => write!(f, "too many decimal digits"),
+            ParseErrorKind::Overflow => write!(f, "amount is too large"),
+            ParseErrorKind::TrailingCharacters => write!(f, "unexpected trailing characters"),
+            ParseErrorKind::Negative => write!(f, "negative amounts require a signed backing"),
+            ParseErrorKind::Inexact => write!(f, "amount is not a whole number of minor units"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Consumes `expected` (ignoring case) from `input` at byte offset `pos`, returning the offset
+/// just past it.
+fn expect_str(input: &str, pos: usize, expected: &'static str) -> Result<usize, ParseError> {
+    let mut end = pos;
+    let mut chars = input[pos..].chars();
+    for e in expected.chars() {
+        match chars.next() {
+            Some(c) if c == e || c.to_lowercase().eq(e.to_lowercase()) => end += c.len_utf8(),
+            c => {
+                let found = c.map_or(0, char::len_utf8);
+                return Err(ParseError::new(ParseErrorKind::Expected(expected), end..end + found));
+            }
+        }
+    }
+    Ok(end)
+}
+
+/// Returns the byte range of the character at byte offset `pos` of `input`, which is empty at
+/// the end of the input.
+fn char_span(input: &str, pos: usize) -> Range<usize> {
+    pos..pos + input[pos..].chars().next().map_or(0, char::len_utf8)
+}
+
+/// The characters recognised as a minus sign: the ASCII hyphen-minus and the Unicode minus
+/// sign (U+2212).
+pub(crate) const MINUS_SIGNS: [char; 2] = ['-', '\u{2212}'];
+
+/// Consumes a minus sign from `input` at byte offset `pos`, returning the offset just past it.
+fn take_minus(input: &str, pos: usize) -> Option<usize> {
+    let c = input[pos..].chars().next().filter(|c| MINUS_SIGNS.contains(c))?;
+    Some(pos + c.len_utf8())
+}
+
+/// Consumes an optional space (see [`expect_space`]) from `input` at byte offset `pos`.
+fn skip_space(input: &str, pos: usize) -> usize {
+    expect_space(input, pos).unwrap_or(pos)
+}
+
+/// Consumes a space from `input` at byte offset `pos`, which may also be a no-break or
+/// full-width space.
+fn expect_space(input: &str, pos: usize) -> Result<usize, ParseError> {
+    match input[pos..].chars().next() {
+        Some(c) if SPACES.contains(&c) => Ok(pos + c.len_utf8()),
+        _ => Err(ParseError::new(ParseErrorKind::Expected(" "), char_span(input, pos))),
+    }
+}
+
+/// Returns `true` if `B` is able to represent negative values.
+pub(crate) fn is_signed<B: Backing>() -> bool {
+    B::zero().checked_sub(&B::one()).is_some()
+}
+
+/// Returns `true` if a lone comma followed by `digits` digits (and no decimal point) should be
+/// read as a decimal comma rather than a thousands separator. Only currencies whose symbol
+/// follows the number, as is usual where the decimal comma is used, accept one, and a group of
+/// exactly three digits is always read as thousands, so `1,000` never means one.
+pub(crate) const fn is_decimal_comma(digits: usize, decimals: usize, style: FormatStyle) -> bool {
+    matches!(style, FormatStyle::SuffixAttached | FormatStyle::SuffixSpaced) && digits <= decimals && digits != 3
+}
+
+/// Computes `acc * 10 + digit`, or `acc * 10 - digit` if `down` is set, returning [`None`] on
+/// overflow.
+#[inline(always)]
+fn push_digit<B: Backing>(acc: B, digit: u8, down: bool) -> Option<B> {
+    push_radix_digit(acc, 10, digit as u32, down)
+}
+
+/// Computes `acc * radix + digit`, or `acc * radix - digit` if `down` is set, returning
+/// [`None`] on overflow.
+#[inline(always)]
+fn push_radix_digit<B: Backing>(acc: B, radix: u32, digit: u32, down: bool) -> Option<B> {
+    let acc = acc.checked_mul(&B::from(radix))?;
+    match down {
+        true => acc.checked_sub(&B::from(digit)),
+        false => acc.checked_add(&B::from(digit)),
+    }
+}
+
+/// Parses the unsigned number at byte offset `pos` of `input` into a raw value of `C`,
+/// returning it along with the offset just past the number.
+///
+/// Digits may come from any [`DigitSystem`](crate::DigitSystem). Commas, no-break spaces and
+/// their full-width and Arabic forms are accepted as thousands separators. A lone comma
+/// followed by no more digits than `C` supports after the decimal point is instead treated as a
+/// decimal comma, as in `5,00 €`, if `C` is written with a suffix symbol and other than three
+/// digits follow it (see [`is_decimal_comma`]). Without a decimal point, a last group of other
+/// than three digits after a comma is otherwise rejected, since it may have been meant as
+/// decimals.
+///
+/// If `down` is set the number is accumulated as a negative value, which lets signed backings
+/// reach their minimum value. The decimal point may only be omitted if `point_optional` is set
+/// or `C` has no decimal digits.
+fn parse_number<C: Currency>(input: &str, mut pos: usize, down: bool, point_optional: bool) -> Result<(C::Backing, usize), ParseError> {
+    let peek = |pos: usize| input[pos..].chars().next();
+    let peek_digit = |pos: usize| peek(pos).and_then(decimal_digit);
+    let whole_start = pos;
+    // `None` once the digits seen so far overflow, which is only an error if they end up
+    // being the whole part of the number
+    let mut whole = Some(C::Backing::zero());
+    // the offset of the last comma, the whole part before it and the digits seen since
+    let mut last_comma = None;
+    let mut commas = 0;
+    let mut whole_digits = 0;
+    loop {
+        match peek(pos) {
+            Some(c) if decimal_digit(c).is_some() => {
+                whole = whole.and_then(|whole| push_digit(whole, decimal_digit(c).unwrap(), down));
+                whole_digits += 1;
+                if let Some((_, _, digits)) = &mut last_comma {
+                    *digits += 1;
+                }
+                pos += c.len_utf8();
+            }
+            Some(c) if pos > whole_start && COMMAS.contains(&c) => {
+                last_comma = Some((pos, whole, 0));
+                commas += 1;
+                pos += c.len_utf8();
+                if peek_digit(pos).is_none() {
+                    return Err(ParseError::new(ParseErrorKind::ExpectedDigit, char_span(input, pos)));
+                }
+            }
+            Some(c) if pos > whole_start && is_group_separator(c) && peek_digit(pos + c.len_utf8()).is_some() => {
+                pos += c.len_utf8();
+            }
+            _ => break,
+        }
+    }
+    let (decimals, factor) = minor_scale(C::BASE);
+    let whole = match (peek(pos), last_comma) {
+        (Some(c), _) if DECIMAL_POINTS.contains(&c) => {
+            pos += c.len_utf8();
+            whole
+        }
+        (_, Some((comma, before_comma, digits))) if commas == 1 && is_decimal_comma(digits, decimals, C::STYLE) => {
+            pos = comma + peek(comma).map_or(0, char::len_utf8);
+            before_comma
+        }
+        (_, Some((comma, _, digits))) if digits != 3 => {
+            return Err(ParseError::new(ParseErrorKind::Expected("."), char_span(input, comma)));
+        }
+        _ if point_optional || decimals == 0 => whole,
+        _ => return Err(ParseError::new(ParseErrorKind::Expected("."), char_span(input, pos))),
+    };
+    let whole = whole.ok_or(ParseError::new(ParseErrorKind::Overflow, whole_start..pos))?;
+    let fraction_start = pos;
+    let mut fraction = C::Backing::zero();
+    let mut fraction_digits = 0;
+    while let Some(c) = peek(pos).filter(|&c| decimal_digit(c).is_some()) {
+        pos += c.len_utf8();
+        if fraction_digits < decimals {
+            fraction = push_digit(fraction, decimal_digit(c).unwrap(), down).ok_or(ParseError::new(ParseErrorKind::Overflow, whole_start..pos))?;
+        }
+        fraction_digits += 1;
+    }
+    if fraction_digits > decimals {
+        return Err(ParseError::new(ParseErrorKind::TooManyDecimalDigits, fraction_start..pos));
+    }
+    if whole_digits == 0 && fraction_digits == 0 {
+        return Err(ParseError::new(ParseErrorKind::ExpectedDigit, char_span(input, whole_start)));
+    }
+    for _ in fraction_digits..decimals {
+        fraction = push_digit(fraction, 0, down).ok_or(ParseError::new(ParseErrorKind::Overflow, whole_start..pos))?;
+    }
+    // the fraction of a currency whose base is not a power of ten must be a whole number of
+    // minor units, e.g. `1.4 Ar` is 2 of the 5 iraimbilanja to the ariary but `1.7 Ar` is none
+    if !(fraction % factor).is_zero() {
+        return Err(ParseError::new(ParseErrorKind::Inexact, fraction_start..pos));
+    }
+    let raw = whole
+        .checked_mul(&C::BASE)
+        .and_then(|whole| whole.checked_add(&(fraction / factor)))
+        .ok_or(ParseError::new(ParseErrorKind::Overflow, whole_start..pos))?;
+    Ok((raw, pos))
+}
+
+/// Parses an amount of `C` in the format produced by its [`core::fmt::Display`] impl from the
+/// start of `input`, returning the raw [`Backing`] value and the number of bytes consumed.
+///
+/// Negative amounts are recognised when written with a leading minus sign (`-$5.00`), a minus
+/// sign between a prefix symbol and the number (`$-5.00`), a trailing minus sign after the
+/// number or the suffix symbol (`$5.00-`, `5.00 DOT-`) or accounting-style parentheses
+/// (`($5.00)`), using either `-` or the Unicode minus sign `−`. They are rejected with
+/// [`ParseErrorKind::Negative`] if `C::Backing` is unsigned.
+///
+/// Digits are accumulated directly into `C::Backing` using checked arithmetic, so this never
+/// allocates and works without `std`.
+pub(crate) fn parse_prefix<C: Currency>(input: &str) -> Result<(C::Backing, usize), ParseError> {
+    let prefixed = C::STYLE == FormatStyle::PrefixAttached || C::STYLE == FormatStyle::PrefixSpaced;
+    let suffixed = C::STYLE == FormatStyle::SuffixAttached || C::STYLE == FormatStyle::SuffixSpaced;
+    let parenthesized = input.starts_with('(');
+    let mut pos = parenthesized as usize;
+    let mut negative = parenthesized;
+    let mut minus = |pos: &mut usize| {
+        if let Some(end) = take_minus(input, *pos).filter(|_| !negative) {
+            negative = true;
+            *pos = end;
+        }
+    };
+    minus(&mut pos);
+    if prefixed {
+        pos = expect_str(input, pos, C::SYMBOL)?;
+        pos = match C::STYLE {
+            FormatStyle::PrefixSpaced => expect_space(input, pos)?,
+            _ => skip_space(input, pos),
+        };
+        minus(&mut pos);
+    }
+    // signed backings are accumulated downwards so that their minimum value can be parsed
+    let signed = is_signed::<C::Backing>();
+    let (value, end) = parse_number::<C>(input, pos, signed, false)?;
+    pos = end;
+    minus(&mut pos);
+    if suffixed {
+        pos = match C::STYLE {
+            FormatStyle::SuffixSpaced => expect_space(input, pos)?,
+            _ => skip_space(input, pos),
+        };
+        pos = expect_str(input, pos, C::SYMBOL)?;
+        minus(&mut pos);
+    }
+    if parenthesized {
+        pos = expect_str(input, pos, ")")?;
+    }
+    match (negative, signed) {
+        (true, true) => Ok((value, pos)),
+        (true, false) => Err(ParseError::new(ParseErrorKind::Negative, 0..pos)),
+        (false, true) => C::Backing::zero()
+            .checked_sub(&value)
+            .map(|raw| (raw, pos))
+            .ok_or(ParseError::new(ParseErrorKind::Overflow, 0..pos)),
+        (false, false) => Ok((value, pos)),
+    }
+}
+
+/// Parses an [`Amount`] written the way its [`Display`](core::fmt::Display) impl writes it,
+/// such as `"$1,234.50"`. Needs no features and works in `no_std`: the `parsing` feature only
+/// adds parsing of [`DynAmount`](crate::dynamic::DynAmount)s and currency sets.
+impl<C: Currency, Safety: safety::Safety> FromStr for Amount<C, Safety> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (raw, consumed) = parse_prefix::<C>(s)?;
+        if consumed < s.len() {
+            return Err(ParseError::new(ParseErrorKind::TrailingCharacters, consumed..s.len()));
+        }
+        Ok(Amount::from_raw(raw))
+    }
+}
+
+/// Consumes an optional leading minus sign from `input`, failing if `B` is unsigned. Returns
+/// whether the sign was present along with the offset just past it.
+fn leading_minus<B: Backing>(input: &str) -> Result<(bool, usize), ParseError> {
+    match take_minus(input, 0) {
+        Some(end) if !is_signed::<B>() => Err(ParseError::new(ParseErrorKind::Negative, 0..end)),
+        Some(end) => Ok((true, end)),
+        None => Ok((false, 0)),
+    }
+}
+
+/// Parses the rest of `input` from byte offset `pos` as a non-empty run of digits in the
+/// specified radix, accumulating downwards if `down` is set.
+fn parse_digits<B: Backing>(input: &str, pos: usize, radix: u32, down: bool) -> Result<B, ParseError> {
+    let expected = match radix {
+        16 => ParseErrorKind::ExpectedHexDigit,
+        _ => ParseErrorKind::ExpectedDigit,
+    };
+    if pos == input.len() {
+        return Err(ParseError::new(expected, pos..pos));
+    }
+    let mut acc = B::zero();
+    for (i, c) in input[pos..].char_indices() {
+        let digit = c.to_digit(radix).ok_or(ParseError::new(expected, char_span(input, pos + i)))?;
+        acc = push_radix_digit(acc, radix, digit, down).ok_or(ParseError::new(ParseErrorKind::Overflow, pos..input.len()))?;
+    }
+    Ok(acc)
+}
+
+/// Parses the exponent of a number in scientific notation, starting at byte offset `pos` of
+/// `input` and running to its end.
+fn parse_exponent(input: &str, mut pos: usize) -> Result<i64, ParseError> {
+    let negative = input.as_bytes().get(pos) == Some(&b'-');
+    if negative || input.as_bytes().get(pos) == Some(&b'+') {
+        pos += 1;
+    }
+    parse_digits::<i64>(input, pos, 10, negative)
+}
+
+impl<C: Currency, Safety: safety::Safety> Amount<C, Safety> {
+    /// Parses an [`Amount`] from a plain number of minor units, such as the
+    /// `"1500000000000000000"` wei balances returned by blockchain RPCs.
+    ///
+    /// The input may only contain decimal digits and an optional leading minus sign, which is
+    /// rejected with [`ParseErrorKind::Negative`] if `C::Backing` is unsigned.
+    pub fn from_minor_str(s: &str) -> Result<Self, ParseError> {
+        let (negative, pos) = leading_minus::<C::Backing>(s)?;
+        parse_digits(s, pos, 10, negative).map(Amount::from_raw)
+    }
 
-use quoth::{Parsable, ParsableExt, ParseStream, Span, Spanned};
+    /// Parses an [`Amount`] from a plain decimal number of major units without a currency
+    /// symbol, such as `"123.45"`, `"-0.5"` or `"100"`.
+    ///
+    /// This is the inverse of the `decimal_string` serde representation. The number is read
+    /// like the one in the [`FromStr`] impl, except that the decimal point is optional.
+    pub fn from_decimal_str(s: &str) -> Result<Self, ParseError> {
+        let (negative, pos) = leading_minus::<C::Backing>(s)?;
+        let (raw, consumed) = parse_number::<C>(s, pos, negative, true)?;
+        if consumed < s.len() {
+            return Err(ParseError::new(ParseErrorKind::TrailingCharacters, consumed..s.len()));
+        }
+        Ok(Amount::from_raw(raw))
+    }
+
+    /// Parses an [`Amount`] from a plain decimal number followed by a space and the
+    /// [`Currency::CODE`], such as `"3.24 USD"` or `"-0.5 eur"`.
+    ///
+    /// The number is read like the one in [`Amount::from_decimal_str`], and ASCII letters of
+    /// the code are matched case-insensitively.
+    pub fn from_code_str(s: &str) -> Result<Self, ParseError> {
+        let (negative, pos) = leading_minus::<C::Backing>(s)?;
+        let (raw, pos) = parse_number::<C>(s, pos, negative, true)?;
+        let pos = expect_str(s, expect_space(s, pos)?, C::CODE)?;
+        if pos < s.len() {
+            return Err(ParseError::new(ParseErrorKind::TrailingCharacters, pos..s.len()));
+        }
+        Ok(Amount::from_raw(raw))
+    }
+
+    /// Parses an [`Amount`] from a hexadecimal number of minor units, such as
+    /// `"0x0de0b6b3a7640000"`. The `0x` prefix is optional and digits are case-insensitive.
+    ///
+    /// An optional leading minus sign is accepted as in [`Amount::from_minor_str`].
+    pub fn from_hex_minor(s: &str) -> Result<Self, ParseError> {
+        let (negative, mut pos) = leading_minus::<C::Backing>(s)?;
+        if s[pos..].starts_with("0x") || s[pos..].starts_with("0X") {
+            pos += 2;
+        }
+        parse_digits(s, pos, 16, negative).map(Amount::from_raw)
+    }
+
+    /// Parses an [`Amount`] from a number of minor units in scientific notation, such as
+    /// `"1.5e18"` or `"25E-1"`. Plain numbers such as `"42"` or `"4.20e1"` are accepted too.
+    ///
+    /// The conversion is exact: a value that is not a whole number of minor units, such as
+    /// `"1.5e0"`, is rejected with [`ParseErrorKind::Inexact`] rather than rounded, and one
+    /// that does not fit in `C::Backing` is rejected with [`ParseErrorKind::Overflow`].
+    pub fn from_scientific(s: &str) -> Result<Self, ParseError> {
+        let (negative, start) = leading_minus::<C::Backing>(s)?;
+        let (mantissa_end, exponent) = match s[start..].find(['e', 'E']) {
+            Some(i) => (start + i, parse_exponent(s, start + i + 1)?),
+            None => (s.len(), 0),
+        };
+        let mantissa = &s[start..mantissa_end];
+        let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if let Some(i) = mantissa.find(|c: char| !c.is_ascii_digit() && c != '.') {
+            return Err(ParseError::new(ParseErrorKind::ExpectedDigit, char_span(s, start + i)));
+        }
+        if fraction.contains('.') {
+            let i = start + whole.len() + 1 + fraction.find('.').unwrap_or(0);
+            return Err(ParseError::new(ParseErrorKind::ExpectedDigit, i..i + 1));
+        }
+        let digits = whole.len() + fraction.len();
+        if digits == 0 {
+            return Err(ParseError::new(ParseErrorKind::ExpectedDigit, char_span(s, start)));
+        }
+        // the number of mantissa digits that end up before the decimal point
+        let kept = (whole.len() as i64).saturating_add(exponent);
+        let overflow = ParseError::new(ParseErrorKind::Overflow, 0..s.len());
+        let mut raw = C::Backing::zero();
+        for (i, digit) in whole.bytes().chain(fraction.bytes()).enumerate() {
+            if (i as i64) < kept {
+                raw = push_digit(raw, digit - b'0', negative).ok_or(overflow.clone())?;
+            } else if digit != b'0' {
+                return Err(ParseError::new(ParseErrorKind::Inexact, 0..s.len()));
+            }
+        }
+        if !raw.is_zero() {
+            for _ in digits as i64..kept {
+                raw = push_digit(raw, 0, negative).ok_or(overflow.clone())?;
+            }
+        }
+        Ok(Amount::from_raw(raw))
+    }
+}
 
 /// Represents an [`Amount`] that has been parsed from a string representation. Includes
 /// [`Span`] information.
 ///
 /// The parsed [`Amount`] is stored in the `amount` field, and the [`Span`] of the parsed
-/// string is stored in the `span` field.
+/// string is stored in the `span` field. Requires the `quoth` feature.
+#[cfg(feature = "quoth")]
 #[derive(Clone, PartialEq, Eq, Hash, Spanned, ParsableExt)]
 pub struct ParsedAmount<C: Currency = USD, Safety: safety::Safety = Unchecked> {
     /// The parsed [`Amount`].
@@ -20,89 +477,51 @@ pub struct ParsedAmount<C: Currency = USD, Safety: safety::Safety = Unchecked> {
     pub span: Span,
 }
 
+#[cfg(feature = "quoth")]
 impl<C: Currency, Safety: safety::Safety> Debug for ParsedAmount<C, Safety> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:?}", self.amount)
     }
 }
 
+#[cfg(feature = "quoth")]
 impl<C: Currency, Safety: safety::Safety> Parsable for ParsedAmount<C, Safety> {
     fn parse(stream: &mut quoth::ParseStream) -> quoth::Result<Self> {
+        use quoth::IndexedStr;
+
         let start_position = stream.position;
-        if C::STYLE == FormatStyle::PrefixAttached || C::STYLE == FormatStyle::PrefixSpaced {
-            let _symbol = stream.parse_istr(C::SYMBOL)?;
-        }
-        if C::STYLE == FormatStyle::PrefixSpaced {
-            let _space = stream.parse_str(" ")?;
-        }
-        let whole_start_position = stream.position;
-        let mut whole_digits = Vec::new();
-        loop {
-            if stream.next_digit().is_ok() {
-                whole_digits.push(stream.parse_digit()?);
-            } else if whole_digits.len() > 0 && stream.next_char() == Ok(',') {
-                stream.consume(1)?;
-                whole_digits.push(stream.parse_digit()?);
-            } else {
-                break;
+        let remaining = stream.remaining();
+        let remaining = remaining.as_str();
+        // quoth positions are in chars whereas `parse_prefix` deals in bytes
+        let to_position = |byte: usize| start_position + remaining[..byte.min(remaining.len())].chars().count();
+        match parse_prefix::<C>(remaining) {
+            Ok((raw, consumed)) => {
+                let end_position = to_position(consumed);
+                stream.position = end_position;
+                Ok(ParsedAmount {
+                    amount: Amount::from_raw(raw),
+                    span: Span::new(stream.source().clone(), start_position..end_position),
+                })
+            }
+            Err(err) => {
+                let span = to_position(err.span.start)..to_position(err.span.end);
+                Err(quoth::Error::new(Span::new(stream.source().clone(), span), err))
             }
         }
-        let _dot = stream.parse_str(".")?;
-        let mut decimal_digits = Vec::new();
-        let decimal_start_position = stream.position;
-        while stream.next_digit().is_ok() {
-            decimal_digits.push(stream.parse_digit()?);
-        }
-        if decimal_digits.len() > C::decimal_digits() {
-            return Err(quoth::Error::new(
-                Span::new(stream.source().clone(), decimal_start_position..stream.position),
-                "too many decimal digits",
-            ));
-        }
-        while decimal_digits.len() < C::decimal_digits() {
-            decimal_digits.push(0);
-        }
-        let dec_end_position = stream.position;
-        let raw = format!(
-            "{}{}",
-            whole_digits.into_iter().map(|d| d.to_string()).collect::<String>(),
-            decimal_digits.into_iter().map(|d| d.to_string()).collect::<String>()
-        )
-        .replace(",", "");
-        let backing = C::Backing::from_str(&raw).map_err(|_| {
-            quoth::Error::new(
-                Span::new(stream.source().clone(), whole_start_position..dec_end_position),
-                "invalid amount",
-            )
-        })?;
-        let amount = Amount::from_raw(backing);
-        if C::STYLE == FormatStyle::SuffixSpaced {
-            let _space = stream.parse_str(" ")?;
-        }
-        if C::STYLE == FormatStyle::SuffixAttached || C::STYLE == FormatStyle::SuffixSpaced {
-            let _symbol = stream.parse_istr(C::SYMBOL)?;
-        }
-        let end_position = stream.position;
-        Ok(ParsedAmount {
-            amount,
-            span: Span::new(stream.source().clone(), start_position..end_position),
-        })
     }
 }
 
-impl<C: Currency, Safety: safety::Safety> FromStr for Amount<C, Safety> {
-    type Err = serde::de::value::Error;
+#[cfg(test)]
+extern crate alloc;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut stream = ParseStream::from(s);
-        let parsed = stream
-            .parse::<ParsedAmount<C, Safety>>()
-            .map_err(|err| serde::de::Error::custom(err.to_string()))?;
-        Ok(parsed.amount)
-    }
-}
+#[cfg(test)]
+use alloc::string::ToString;
+
+#[cfg(test)]
+use crate::u256::U256;
 
 #[test]
+#[allow(clippy::inconsistent_digit_grouping, clippy::zero_prefixed_literal)]
 fn test_parsing_usd() {
     assert_eq!(USD::decimal_digits(), 2);
 
@@ -155,3 +574,296 @@ fn test_parsing_ada() {
     assert_eq!(amount.to_string(), "0.100000 ADA");
     assert_eq!(amount, Amount::from_raw(100_000));
 }
+
+#[test]
+fn test_parse_errors() {
+    let err = "$1.00xyz".parse::<Amount<USD>>().unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::TrailingCharacters);
+    assert_eq!(err.span(), 5..8);
+
+    let err = "€1.00".parse::<Amount<USD>>().unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::Expected("$"));
+    assert_eq!(err.to_string(), "expected `$`");
+
+    let err = "$1,,0.00".parse::<Amount<USD>>().unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::ExpectedDigit);
+    assert_eq!(err.span(), 3..4);
+
+    let err = "$184467440737095516.16".parse::<Amount<USD>>().unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::Overflow);
+    assert_eq!("$184467440737095516.15".parse::<Amount<USD>>().unwrap(), Amount::from_raw(u64::MAX));
+
+    let err = "1.00DOT".parse::<Amount<DOT>>().unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::Expected(" "));
+    assert_eq!("1.00 dot".parse::<Amount<DOT>>().unwrap(), Amount::from_raw(1_0000000000));
+    assert_eq!("2.50€".parse::<Amount<EUR>>().unwrap(), Amount::from_raw(2_50));
+}
+
+#[test]
+fn test_parsing_u256() {
+    let amount: Amount<ETH> = "123456789012.345678901234567890 ETH".parse().unwrap();
+    assert_eq!(amount, Amount::from_raw(123456789012345678901234567890u128.into()));
+    let err = "115792089237316195423570985008687907853269984665640564039458.0 ETH"
+        .parse::<Amount<ETH>>()
+        .unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::Overflow);
+}
+
+#[cfg(feature = "quoth")]
+#[test]
+fn test_parsed_amount_span() {
+    use quoth::ParseStream;
+
+    let mut stream = ParseStream::from("€ 1,000.00 DOT rest");
+    assert!(stream.parse::<ParsedAmount<DOT>>().is_err());
+    stream.position = 2;
+    let parsed = stream.parse::<ParsedAmount<DOT>>().unwrap();
+    assert_eq!(parsed.amount, Amount::from_raw(1000_0000000000));
+    assert_eq!(parsed.span.source_text(), "1,000.00 DOT");
+    assert_eq!(stream.position, 14);
+
+    let err = "$1.001".parse::<ParsedAmount<USD>>().unwrap_err();
+    assert_eq!(err.span().source_text(), "001");
+}
+
+#[cfg(test)]
+crate::define_currency!(SEUR, i64, 1_00, "€", "Signed Euro", SuffixAttached, false, false);
+
+#[cfg(test)]
+crate::define_currency!(XTWO, u64, 1_00, "X2", "Test Two", SuffixSpaced, false, false);
+
+#[cfg(test)]
+crate::define_currency!(SMGA, i64, 5, "Ar", "Signed Ariary", SuffixSpaced, false, false, radix);
+
+#[test]
+fn test_parsing_negative() {
+    let expected = Amount::<SIGNEDUSD>::from_raw(-5_00);
+    for input in ["-$5.00", "$-5.00", "($5.00)", "$5.00-", "−$5.00", "$ -5.00"] {
+        assert_eq!(input.parse::<Amount<SIGNEDUSD>>().unwrap(), expected, "{input}");
+    }
+    assert_eq!(expected.to_string(), "-$5.00");
+    assert_eq!("-$0.05".parse::<Amount<SIGNEDUSD>>().unwrap(), Amount::from_raw(-5));
+    assert_eq!(Amount::<SIGNEDUSD>::from_raw(-5).to_string(), "-$0.05");
+    assert_eq!("-$92233720368547758.08".parse::<Amount<SIGNEDUSD>>().unwrap(), Amount::from_raw(i64::MIN));
+    assert_eq!(Amount::<SIGNEDUSD>::from_raw(i64::MIN).to_string(), "-$92233720368547758.08");
+
+    let expected = Amount::<SEUR>::from_raw(-5_00);
+    for input in ["−5,00 €", "-5.00€", "5.00-€", "5,00 €-", "(5.00€)"] {
+        assert_eq!(input.parse::<Amount<SEUR>>().unwrap(), expected, "{input}");
+    }
+    assert_eq!(expected.to_string(), "-5.00€");
+
+    let err = "--$5.00".parse::<Amount<SIGNEDUSD>>().unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::Expected("$"));
+    let err = "(-$5.00)".parse::<Amount<SIGNEDUSD>>().unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::Expected("$"));
+    let err = "($5.00".parse::<Amount<SIGNEDUSD>>().unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::Expected(")"));
+}
+
+#[test]
+fn test_parsing_negative_unsigned() {
+    for input in ["-$5.00", "$-5.00", "($5.00)", "$5.00-", "-$0.00"] {
+        let err = input.parse::<Amount<USD>>().unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::Negative, "{input}");
+    }
+    let err = "−5,00 €".parse::<Amount<EUR>>().unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::Negative);
+    assert_eq!(err.to_string(), "negative amounts require a signed backing");
+    assert_eq!("5,00 €".parse::<Amount<EUR>>().unwrap(), Amount::from_raw(5_00));
+    assert_eq!(
+        "1.234,5 €".parse::<Amount<EUR>>().unwrap_err().kind(),
+        ParseErrorKind::TooManyDecimalDigits
+    );
+    assert_eq!("1,234,50€".parse::<Amount<EUR>>().unwrap_err().kind(), ParseErrorKind::Expected("."));
+    assert_eq!(
+        "1,5 ETH".parse::<Amount<ETH>>().unwrap(),
+        Amount::from_raw(1_500000000000000000u64.into())
+    );
+    assert_eq!("1,000 ETH".parse::<Amount<ETH>>().unwrap_err().kind(), ParseErrorKind::Expected("."));
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_parsing_decimal_comma_requires_suffix_symbol() {
+    let err = "$1,50".parse::<Amount<USD>>().unwrap_err();
+    assert_eq!((err.kind(), err.span()), (ParseErrorKind::Expected("."), 2..3));
+    assert_eq!("$1,500.00".parse::<Amount<USD>>().unwrap(), Amount::from_raw(1500_00));
+    assert_eq!(Amount::<USD>::from_decimal_str("1,500").unwrap(), Amount::from_raw(1500_00));
+    assert_eq!(Amount::<USD>::from_decimal_str("1,5").unwrap_err().kind(), ParseErrorKind::Expected("."));
+    assert_eq!(
+        Amount::<USD>::from_code_str("1,50 USD").unwrap_err().kind(),
+        ParseErrorKind::Expected(".")
+    );
+    assert_eq!(Amount::<EUR>::from_decimal_str("1,5").unwrap(), Amount::from_raw(1_50));
+    assert_eq!(Amount::<EUR>::from_code_str("1,50 EUR").unwrap(), Amount::from_raw(1_50));
+}
+
+#[test]
+fn test_from_minor_str() {
+    let amount = Amount::<ETH>::from_minor_str("1500000000000000000").unwrap();
+    assert_eq!(amount.to_string(), "1.500000000000000000 ETH");
+    let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+    assert_eq!(Amount::<ETH>::from_minor_str(max).unwrap(), Amount::from_raw(U256::MAX_VALUE));
+    let err = Amount::<ETH>::from_minor_str("115792089237316195423570985008687907853269984665640564039457584007913129639936").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::Overflow);
+
+    assert_eq!(Amount::<SIGNEDUSD>::from_minor_str("-500").unwrap(), Amount::from_raw(-5_00));
+    assert_eq!(
+        Amount::<SIGNEDUSD>::from_minor_str("-9223372036854775808").unwrap(),
+        Amount::from_raw(i64::MIN)
+    );
+    assert_eq!(Amount::<USD>::from_minor_str("-500").unwrap_err().kind(), ParseErrorKind::Negative);
+    let err = Amount::<USD>::from_minor_str("1,000").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::ExpectedDigit);
+    assert_eq!(err.span(), 1..2);
+    assert_eq!(Amount::<USD>::from_minor_str("").unwrap_err().kind(), ParseErrorKind::ExpectedDigit);
+}
+
+#[test]
+fn test_from_hex_minor() {
+    let amount = Amount::<ETH>::from_hex_minor("0x0de0b6b3a7640000").unwrap();
+    assert_eq!(amount.to_string(), "1.000000000000000000 ETH");
+    assert_eq!(Amount::<ETH>::from_hex_minor("DE0B6B3A7640000").unwrap(), amount);
+    let max = "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+    assert_eq!(Amount::<ETH>::from_hex_minor(max).unwrap(), Amount::from_raw(U256::MAX_VALUE));
+    assert_eq!(
+        Amount::<USD>::from_hex_minor("0x1ffffffffffffffff").unwrap_err().kind(),
+        ParseErrorKind::Overflow
+    );
+    assert_eq!(Amount::<SIGNEDUSD>::from_hex_minor("-0x1f4").unwrap(), Amount::from_raw(-5_00));
+
+    let err = Amount::<USD>::from_hex_minor("0xfg").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::ExpectedHexDigit);
+    assert_eq!(err.span(), 3..4);
+    assert_eq!(Amount::<USD>::from_hex_minor("0x").unwrap_err().kind(), ParseErrorKind::ExpectedHexDigit);
+}
+
+#[test]
+fn test_from_scientific() {
+    let amount = Amount::<ETH>::from_scientific("1.5e18").unwrap();
+    assert_eq!(amount, Amount::<ETH>::from_minor_str("1500000000000000000").unwrap());
+    assert_eq!(Amount::<ETH>::from_scientific("15E+17").unwrap(), amount);
+    assert_eq!(Amount::<ETH>::from_scientific("0.0000015e24").unwrap(), amount);
+    assert_eq!(Amount::<USD>::from_scientific("42").unwrap(), Amount::from_raw(42));
+    assert_eq!(Amount::<USD>::from_scientific("150e-1").unwrap(), Amount::from_raw(15));
+    assert_eq!(Amount::<USD>::from_scientific("4.20e1").unwrap(), Amount::from_raw(42));
+    assert_eq!(Amount::<USD>::from_scientific("0e999999").unwrap(), Amount::from_raw(0));
+    assert_eq!(Amount::<SIGNEDUSD>::from_scientific("-2.5e2").unwrap(), Amount::from_raw(-2_50));
+
+    assert_eq!(Amount::<USD>::from_scientific("1.5e0").unwrap_err().kind(), ParseErrorKind::Inexact);
+    assert_eq!(Amount::<USD>::from_scientific("15e-1").unwrap_err().kind(), ParseErrorKind::Inexact);
+    assert_eq!(Amount::<ETH>::from_scientific("1e78").unwrap_err().kind(), ParseErrorKind::Overflow);
+    assert_eq!(
+        Amount::<ETH>::from_scientific("1e77").unwrap().raw_backing(),
+        U256(primitive_types::U256::exp10(77))
+    );
+    assert_eq!(
+        Amount::<USD>::from_scientific("1e99999999999999999999").unwrap_err().kind(),
+        ParseErrorKind::Overflow
+    );
+    assert_eq!(Amount::<USD>::from_scientific("-1e2").unwrap_err().kind(), ParseErrorKind::Negative);
+    assert_eq!(Amount::<USD>::from_scientific("1.2.3").unwrap_err().kind(), ParseErrorKind::ExpectedDigit);
+    assert_eq!(Amount::<USD>::from_scientific(".e5").unwrap_err().kind(), ParseErrorKind::ExpectedDigit);
+    assert_eq!(Amount::<USD>::from_scientific("1e").unwrap_err().kind(), ParseErrorKind::ExpectedDigit);
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_parsing_unicode_digits() {
+    assert_eq!("$١٬٠٢٤٫٥٠".parse::<Amount<USD>>().unwrap(), Amount::from_raw(1024_50));
+    assert_eq!("١٢٫٥٠ Dh".parse::<Amount<AED>>().unwrap(), Amount::from_raw(12_50));
+    assert_eq!("۱۲۳.۴۵€".parse::<Amount<EUR>>().unwrap(), Amount::from_raw(123_45));
+    assert_eq!("₹१,२३४.५०".parse::<Amount<INR>>().unwrap(), Amount::from_raw(1234_50));
+    assert_eq!(
+        "１，０００．５\u{3000}DOT".parse::<Amount<DOT>>().unwrap(),
+        Amount::from_raw(1000_5000000000)
+    );
+    assert_eq!("1\u{202F}000,50\u{A0}€".parse::<Amount<EUR>>().unwrap(), Amount::from_raw(1000_50));
+    assert_eq!("1\u{A0}000.00 DOT".parse::<Amount<DOT>>().unwrap(), Amount::from_raw(1000_0000000000));
+    assert_eq!("5 €".parse::<Amount<EUR>>().unwrap_err().kind(), ParseErrorKind::Expected("."));
+
+    let err = "$١٫٠٠١".parse::<Amount<USD>>().unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::TooManyDecimalDigits);
+    assert_eq!(err.span(), 5..11);
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_format_native_digits() {
+    use crate::{DigitSystem, DynAmount};
+
+    let amount = Amount::<USD>::from_raw(1024_50);
+    assert_eq!(amount.with_digits(DigitSystem::ArabicIndic).to_string(), "$١٠٢٤.٥٠");
+    assert_eq!(amount.with_digits(DigitSystem::Devanagari).to_string(), "$१०२४.५०");
+    assert_eq!(amount.with_digits(DigitSystem::Ascii).to_string(), amount.to_string());
+    for system in DigitSystem::ALL {
+        let text = amount.with_digits(system).to_string();
+        assert_eq!(text.parse::<Amount<USD>>().unwrap(), amount, "{text}");
+    }
+    let amount = Amount::<SEUR>::from_raw(-12_05);
+    assert_eq!(amount.with_digits(DigitSystem::Persian).to_string(), "-۱۲.۰۵€");
+    assert_eq!(amount.with_digits(DigitSystem::FullWidth).to_string(), "-１２.０５€");
+    let amount = Amount::<XTWO>::from_raw(10_50);
+    assert_eq!(amount.with_digits(DigitSystem::ArabicIndic).to_string(), "١٠.٥٠ X2");
+    assert_eq!(DynAmount::from(amount).with_digits(DigitSystem::Devanagari).to_string(), "१०.५० X2");
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_from_decimal_str() {
+    assert_eq!(Amount::<USD>::from_decimal_str("123.45").unwrap(), Amount::from_raw(123_45));
+    assert_eq!(Amount::<USD>::from_decimal_str("100").unwrap(), Amount::from_raw(100_00));
+    assert_eq!(Amount::<USD>::from_decimal_str("1,000.5").unwrap(), Amount::from_raw(1000_50));
+    assert_eq!(Amount::<SIGNEDUSD>::from_decimal_str("-0.5").unwrap(), Amount::from_raw(-50));
+    assert_eq!(Amount::<USD>::from_decimal_str("-0.5").unwrap_err().kind(), ParseErrorKind::Negative);
+    assert_eq!(
+        Amount::<USD>::from_decimal_str("$1.00").unwrap_err().kind(),
+        ParseErrorKind::ExpectedDigit
+    );
+    assert_eq!(
+        Amount::<USD>::from_decimal_str("1.00 USD").unwrap_err().kind(),
+        ParseErrorKind::TrailingCharacters
+    );
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_from_code_str() {
+    assert_eq!(Amount::<USD>::from_code_str("3.24 USD").unwrap(), Amount::from_raw(3_24));
+    assert_eq!(Amount::<USD>::from_code_str("1,000 usd").unwrap(), Amount::from_raw(1000_00));
+    assert_eq!(Amount::<SIGNEDUSD>::from_code_str("-0.5 SIGNEDUSD").unwrap(), Amount::from_raw(-50));
+    assert_eq!(Amount::<USD>::from_code_str("-0.5 USD").unwrap_err().kind(), ParseErrorKind::Negative);
+    assert_eq!(Amount::<USD>::from_code_str("3.24USD").unwrap_err().kind(), ParseErrorKind::Expected(" "));
+    assert_eq!(
+        Amount::<USD>::from_code_str("3.24 EUR").unwrap_err().kind(),
+        ParseErrorKind::Expected("USD")
+    );
+    assert_eq!(Amount::<USD>::from_code_str("3.24 USD!").unwrap_err().span(), 8..9);
+}
+
+#[test]
+fn test_parsing_radix_fractions() {
+    assert_eq!("1.4 Ar".parse::<Amount<MGA>>(), Ok(Amount::from_raw(7)));
+    assert_eq!("0.8 Ar".parse::<Amount<MGA>>(), Ok(Amount::from_raw(4)));
+    let err = "1.7 Ar".parse::<Amount<MGA>>().unwrap_err();
+    assert_eq!((err.kind(), err.span()), (ParseErrorKind::Inexact, 2..3));
+    assert_eq!("1.5 Ar".parse::<Amount<MGA>>().unwrap_err().kind(), ParseErrorKind::Inexact);
+    assert_eq!("-1.4 Ar".parse::<Amount<SMGA>>(), Ok(Amount::from_raw(-7)));
+    assert_eq!("-1.5 Ar".parse::<Amount<SMGA>>().unwrap_err().kind(), ParseErrorKind::Inexact);
+    assert_eq!("(0.9 Ar)".parse::<Amount<SMGA>>().unwrap_err().kind(), ParseErrorKind::Inexact);
+    assert_eq!(Amount::<SMGA>::from_raw(-7).to_string(), "-1.4 Ar");
+}
+
+#[test]
+fn test_parsing_zero_decimals() {
+    let yen = Amount::<JPY>::from_raw(500);
+    assert_eq!(yen.to_string(), "500¥");
+    assert_eq!("500¥".parse::<Amount<JPY>>(), Ok(yen));
+    assert_eq!("500.¥".parse::<Amount<JPY>>(), Ok(yen));
+    assert_eq!(Amount::<JPY>::from_decimal_str("500"), Ok(yen));
+    assert_eq!(Amount::<KRW>::from_raw(1500).to_string(), "1500 ₩");
+    assert_eq!("1,500 ₩".parse::<Amount<KRW>>(), Ok(Amount::from_raw(1500)));
+    let err = "500.5¥".parse::<Amount<JPY>>().unwrap_err();
+    assert_eq!((err.kind(), err.span()), (ParseErrorKind::TooManyDecimalDigits, 4..5));
+}
@@ -0,0 +1,216 @@
+use std::error::Error;
+
+use bytes::{BufMut, BytesMut};
+use num_integer::Integer;
+use num_traits::{CheckedAdd, CheckedMul, Zero};
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::amount::Amount;
+use crate::conversion::{ConversionError, DecimalParts};
+use crate::currency::Currency;
+use crate::safety;
+use crate::u256::U256;
+
+const NUMERIC_POS: u16 = 0x0000;
+const NUMERIC_NEG: u16 = 0x4000;
+
+/// The base of the digits in the binary `NUMERIC` format.
+const NBASE: u16 = 10_000;
+
+/// Returns `10^exp`, or [`None`] if that doesn't fit in a [`U256`].
+fn pow10(exp: u32) -> Option<U256> {
+    (0..exp).try_fold(U256::from(1u8), |acc, _| acc.checked_mul(&U256::from(10u8)))
+}
+
+/// Splits `value` into base-10000 digits, most significant first, producing at least
+/// `min_len` of them.
+fn nbase_digits(mut value: U256, min_len: usize) -> Vec<i16> {
+    let mut digits = Vec::new();
+    while !value.is_zero() || digits.len() < min_len {
+        let (rest, digit) = value.div_rem(&U256::from(NBASE));
+        digits.push(digit.0.low_u32() as i16);
+        value = rest;
+    }
+    digits.reverse();
+    digits
+}
+
+/// Writes an [`Amount`] in the binary format of a `NUMERIC` column, with a display scale equal
+/// to the number of digits after the decimal point of its [`Currency`].
+impl<C: Currency, Safety: safety::Safety> ToSql for Amount<C, Safety> {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        let parts = self.decimal_parts()?;
+        let (integer, fraction) = parts.mantissa.div_rem(&pow10(parts.scale).ok_or(ConversionError::Overflow)?);
+
+        // the fraction is padded with zeros on the right to fill its last base-10000 digit
+        let fraction_len = parts.scale.div_ceil(4);
+        let padding = pow10(fraction_len * 4 - parts.scale).ok_or(ConversionError::Overflow)?;
+        let fraction = fraction.checked_mul(&padding).ok_or(ConversionError::Overflow)?;
+
+        let integer = nbase_digits(integer, 0);
+        let mut weight = integer.len() as i16 - 1;
+        let mut digits = integer;
+        digits.extend(nbase_digits(fraction, fraction_len as usize));
+        let leading_zeros = digits.iter().take_while(|digit| **digit == 0).count();
+        digits.drain(..leading_zeros);
+        weight -= leading_zeros as i16;
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        if digits.is_empty() {
+            weight = 0;
+        }
+
+        out.put_i16(i16::try_from(digits.len())?);
+        out.put_i16(weight);
+        out.put_u16(if parts.negative { NUMERIC_NEG } else { NUMERIC_POS });
+        out.put_u16(u16::try_from(parts.scale)?);
+        for digit in digits {
+            out.put_i16(digit);
+        }
+        Ok(IsNull::No)
+    }
+
+    accepts!(NUMERIC);
+
+    to_sql_checked!();
+}
+
+/// Reads an [`Amount`] from the binary format of a `NUMERIC` column, failing if the value is
+/// not a whole number of minor units (regardless of the column's scale) or does not fit in the
+/// [`Backing`](crate::Backing) of the [`Currency`].
+impl<'a, C: Currency, Safety: safety::Safety> FromSql<'a> for Amount<C, Safety> {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let header =
+            |i: usize| -> Result<[u8; 2], Box<dyn Error + Sync + Send>> { Ok(raw.get(i * 2..i * 2 + 2).ok_or("invalid NUMERIC value")?.try_into()?) };
+        let ndigits = i16::from_be_bytes(header(0)?);
+        let weight = i16::from_be_bytes(header(1)?);
+        let negative = match u16::from_be_bytes(header(2)?) {
+            NUMERIC_POS => false,
+            NUMERIC_NEG => true,
+            _ => return Err("NaN and infinite NUMERIC values are not supported".into()),
+        };
+        if ndigits < 0 || raw.len() != 8 + ndigits as usize * 2 {
+            return Err("invalid NUMERIC value".into());
+        }
+
+        // the value is `mantissa * 10^exponent`
+        let mut mantissa = U256::zero();
+        let mut exponent = (weight as i32 - ndigits as i32 + 1) * 4;
+        for i in 0..ndigits as usize {
+            let mut digit = i16::from_be_bytes(header(4 + i)?);
+            if !(0..NBASE as i16).contains(&digit) {
+                return Err("invalid NUMERIC digit".into());
+            }
+            // the last digit is usually padded with zeros, which could otherwise overflow
+            let mut width = 4;
+            while i + 1 == ndigits as usize && digit != 0 && digit % 10 == 0 {
+                digit /= 10;
+                width -= 1;
+                exponent += 1;
+            }
+            mantissa = mantissa
+                .checked_mul(&U256::from(10u32.pow(width)))
+                .and_then(|m| m.checked_add(&U256::from(digit as u16)))
+                .ok_or(ConversionError::Overflow)?;
+        }
+
+        let scale = match exponent {
+            0.. if mantissa.is_zero() => 0,
+            0.. => {
+                let factor = pow10(exponent as u32).ok_or(ConversionError::Overflow)?;
+                mantissa = mantissa.checked_mul(&factor).ok_or(ConversionError::Overflow)?;
+                0
+            }
+            _ => -exponent as u32,
+        };
+        Ok(Amount::from_decimal_parts(DecimalParts { negative, mantissa, scale })?)
+    }
+
+    accepts!(NUMERIC);
+}
+
+#[cfg(test)]
+use crate::currency::{DOT, ETH, MGA, SIGNEDUSD, USD};
+
+#[cfg(test)]
+fn encode<T: ToSql>(value: T) -> Vec<u8> {
+    let mut out = BytesMut::new();
+    value.to_sql_checked(&Type::NUMERIC, &mut out).unwrap();
+    out.to_vec()
+}
+
+#[cfg(test)]
+fn numeric(ndigits: i16, weight: i16, sign: u16, dscale: u16, digits: &[i16]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for word in [ndigits as u16, weight as u16, sign, dscale]
+        .into_iter()
+        .chain(digits.iter().map(|d| *d as u16))
+    {
+        out.extend(word.to_be_bytes());
+    }
+    out
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping, clippy::zero_prefixed_literal)]
+fn test_postgres_numeric_encoding() {
+    assert_eq!(encode(Amount::<USD>::from_raw(123_45)), numeric(2, 0, 0, 2, &[123, 4500]));
+    assert_eq!(encode(Amount::<USD>::from_raw(0)), numeric(0, 0, 0, 2, &[]));
+    assert_eq!(encode(Amount::<USD>::from_raw(0_05)), numeric(1, -1, 0, 2, &[500]));
+    assert_eq!(encode(Amount::<USD>::from_raw(1000000_00)), numeric(1, 1, 0, 2, &[100]));
+    assert_eq!(
+        encode(Amount::<SIGNEDUSD>::from_raw(-12345678_90)),
+        numeric(3, 1, 0x4000, 2, &[1234, 5678, 9000])
+    );
+    assert_eq!(encode(Amount::<MGA>::from_raw(17)), numeric(2, 0, 0, 1, &[3, 4000]));
+    assert_eq!(encode(Amount::<DOT>::from_raw(12_0000000004)), numeric(4, 0, 0, 10, &[12, 0, 0, 400]));
+    assert_eq!(
+        encode(Amount::<ETH>::from_raw(U256::from(1_500000000000000000u64))),
+        numeric(2, 0, 0, 18, &[1, 5000])
+    );
+    assert!(!<Amount<USD> as ToSql>::accepts(&Type::INT8));
+    assert!(<Amount<USD> as FromSql>::accepts(&Type::NUMERIC));
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_postgres_numeric_round_trip() {
+    fn round_trip<C: Currency>(amount: Amount<C>) {
+        let bytes = encode(amount);
+        assert_eq!(Amount::<C>::from_sql(&Type::NUMERIC, &bytes).unwrap(), amount);
+    }
+    round_trip(Amount::<USD>::from_raw(123_45));
+    round_trip(Amount::<USD>::from_raw(0));
+    round_trip(Amount::<USD>::from_raw(u64::MAX));
+    round_trip(Amount::<SIGNEDUSD>::from_raw(i64::MIN));
+    round_trip(Amount::<MGA>::from_raw(u64::MAX));
+    round_trip(Amount::<DOT>::from_raw(u128::MAX));
+    round_trip(Amount::<ETH>::from_raw(U256::MAX_VALUE));
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_postgres_numeric_decoding() {
+    let decode = |bytes: Vec<u8>| Amount::<USD>::from_sql(&Type::NUMERIC, &bytes).map_err(|err| err.to_string());
+    // NUMERIC(20, 4) stores 123.4500 with a display scale of 4
+    assert_eq!(decode(numeric(2, 0, 0, 4, &[123, 4500])), Ok(Amount::from_raw(123_45)));
+    assert_eq!(decode(numeric(1, 2, 0, 0, &[7])), Ok(Amount::from_raw(7_0000_0000_00)));
+    assert_eq!(decode(numeric(0, 0, 0, 0, &[])), Ok(Amount::from_raw(0)));
+    assert_eq!(decode(numeric(0, 0, 0x4000, 0, &[])), Ok(Amount::from_raw(0)));
+
+    let err = "value cannot be represented without losing precision";
+    assert_eq!(decode(numeric(2, 0, 0, 3, &[123, 4560])), Err(err.into()));
+    assert_eq!(
+        decode(numeric(1, 0, 0x4000, 0, &[1])),
+        Err("negative amounts require a signed backing".into())
+    );
+    assert_eq!(decode(numeric(1, 20, 0, 0, &[1])), Err("value is too large for the target type".into()));
+    assert_eq!(
+        decode(numeric(0, 0, 0xC000, 0, &[])),
+        Err("NaN and infinite NUMERIC values are not supported".into())
+    );
+    assert_eq!(decode(numeric(1, 0, 0, 0, &[10000])), Err("invalid NUMERIC digit".into()));
+    assert_eq!(decode(numeric(2, 0, 0, 0, &[1])), Err("invalid NUMERIC value".into()));
+    assert_eq!(decode(vec![0, 1]), Err("invalid NUMERIC value".into()));
+}
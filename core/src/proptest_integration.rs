@@ -0,0 +1,138 @@
+use num_traits::{Bounded, CheckedAdd, CheckedSub, One, Zero};
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::prelude::{prop_oneof, BoxedStrategy, Strategy};
+use proptest::sample::select;
+
+use crate::amount::Amount;
+use crate::currency::Currency;
+use crate::encoding::LeBytes;
+use crate::parsing::is_signed;
+use crate::safety;
+use crate::u256::U256;
+
+/// Generates [`U256`] values of every magnitude, favoring those that also fit in a [`u64`] or
+/// a [`u128`] over uniformly random ones, which are almost always enormous.
+impl Arbitrary for U256 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            any::<u64>().prop_map(U256::from),
+            any::<u128>().prop_map(U256::from),
+            any::<[u8; 32]>().prop_map(U256::from_le_bytes),
+        ]
+        .boxed()
+    }
+}
+
+/// Generates [`Amount`]s from any raw [`Backing`](crate::Backing) value, mixed with the
+/// values produced by [`edge_amounts`] and [`near_base_multiples`].
+impl<C: Currency + 'static, Safety: safety::Safety + 'static> Arbitrary for Amount<C, Safety>
+where
+    C::Backing: Arbitrary + Bounded + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            1 => edge_amounts(),
+            1 => near_base_multiples(),
+            2 => any::<C::Backing>().prop_map(Amount::from_raw),
+        ]
+        .boxed()
+    }
+}
+
+/// Generates the amounts of `C` at the edges of its range: zero, one minor unit and one whole
+/// unit (i.e. [`Currency::BASE`]) on either side of zero, and the minimum and maximum values
+/// of its [`Backing`](crate::Backing).
+pub fn edge_amounts<C: Currency, Safety: safety::Safety>() -> impl Strategy<Value = Amount<C, Safety>>
+where
+    C::Backing: Bounded + 'static,
+{
+    let zero = C::Backing::zero();
+    let one = C::Backing::one();
+    let mut values = vec![zero, one, C::BASE, C::Backing::min_value(), C::Backing::max_value()];
+    if is_signed::<C::Backing>() {
+        values.extend([zero - one, zero - C::BASE]);
+    }
+    values.sort();
+    values.dedup();
+    select(values).prop_map(Amount::from_raw)
+}
+
+/// Generates amounts of `C` within one minor unit of a whole number of major units, i.e. of a
+/// multiple of [`Currency::BASE`], where rounding and formatting bugs tend to hide.
+pub fn near_base_multiples<C: Currency, Safety: safety::Safety>() -> impl Strategy<Value = Amount<C, Safety>>
+where
+    C::Backing: Arbitrary,
+{
+    (any::<C::Backing>(), -1i8..=1).prop_map(|(raw, offset)| {
+        let multiple = raw - raw % C::BASE;
+        let raw = match offset {
+            -1 => multiple.checked_sub(&C::Backing::one()),
+            1 => multiple.checked_add(&C::Backing::one()),
+            _ => None,
+        };
+        Amount::from_raw(raw.unwrap_or(multiple))
+    })
+}
+
+#[cfg(test)]
+use crate::currency::*;
+
+#[cfg(test)]
+use proptest::test_runner::{Config, TestRunner};
+
+#[cfg(test)]
+fn check_round_trips<C: Currency + 'static>()
+where
+    C::Backing: Arbitrary + Bounded + 'static,
+{
+    use proptest::prop_assert_eq;
+
+    let mut runner = TestRunner::new(Config::with_cases(64));
+    runner
+        .run(&any::<Amount<C>>(), |amount| {
+            let text = amount.to_string();
+            prop_assert_eq!(text.parse::<Amount<C>>(), Ok(amount), "{} does not round-trip", text);
+            #[cfg(feature = "serde")]
+            {
+                let json = serde_json::to_string(&amount).unwrap();
+                prop_assert_eq!(serde_json::from_str::<Amount<C>>(&json).unwrap(), amount);
+            }
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_edge_amounts() {
+    use proptest::strategy::ValueTree;
+
+    let mut runner = TestRunner::deterministic();
+    let mut seen = std::collections::BTreeSet::new();
+    for _ in 0..200 {
+        seen.insert(edge_amounts::<USD, safety::Unchecked>().new_tree(&mut runner).unwrap().current());
+    }
+    let expected = [0, 1, 1_00, u64::MAX].map(Amount::<USD>::from_raw);
+    assert_eq!(seen.into_iter().collect::<Vec<_>>(), expected);
+
+    for _ in 0..100 {
+        let amount = near_base_multiples::<USD, safety::Unchecked>().new_tree(&mut runner).unwrap().current();
+        let remainder = amount.raw_backing() % 1_00;
+        assert!([0, 1, 99].contains(&remainder), "{}", amount);
+    }
+}
+
+#[test]
+fn test_builtin_currency_round_trips() {
+    macro_rules! check_all {
+        ($(($currency:ident, $($rest:tt)*)),* $(,)?) => {
+            $(check_round_trips::<$currency>();)*
+        };
+    }
+    crate::currencies_table!(check_all);
+}
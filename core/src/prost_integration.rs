@@ -0,0 +1,229 @@
+use prost::alloc::string::{String, ToString};
+
+use num_integer::Integer;
+use num_traits::{CheckedMul, ToPrimitive, Zero};
+
+use crate::amount::Amount;
+use crate::conversion::{ConversionError, DecimalParts};
+use crate::currency::Currency;
+use crate::dynamic::DynAmount;
+use crate::safety;
+use crate::u256::U256;
+
+const NANOS_PER_UNIT: u32 = 1_000_000_000;
+
+/// The `google.type.Money` protobuf message, which represents an amount of money with its
+/// currency type.
+///
+/// This has the same wire format as the type generated from `google/type/money.proto`, so it
+/// can be used in its place or to decode its encoding. Conversions to and from [`Amount`] and
+/// [`DynAmount`] are exact, failing with [`ConversionError::Inexact`] rather than dropping
+/// digits beyond the 9 supported by `nanos`; see [`Money::truncated`] for a lossy alternative.
+#[derive(Clone, PartialEq, Eq, Hash, prost::Message)]
+pub struct Money {
+    /// The three-letter currency code defined in ISO 4217, or the [`Currency::CODE`] of a
+    /// currency outside of ISO 4217.
+    #[prost(string, tag = "1")]
+    pub currency_code: String,
+    /// The whole units of the amount.
+    #[prost(int64, tag = "2")]
+    pub units: i64,
+    /// Number of nano (10^-9) units of the amount, between -999,999,999 and +999,999,999
+    /// inclusive. If `units` is non-zero, `nanos` must be zero or have the same sign.
+    #[prost(int32, tag = "3")]
+    pub nanos: i32,
+}
+
+impl Money {
+    /// Converts an [`Amount`] or [`DynAmount`] into a [`Money`], rounding towards zero to a
+    /// whole number of nanos, e.g. for [`ETH`](crate::currency::ETH) amounts which have 18
+    /// digits after the decimal point.
+    ///
+    /// Fails with [`ConversionError::Overflow`] if the whole units do not fit in an [`i64`].
+    pub fn truncated(amount: impl Into<DynAmount>) -> Result<Money, ConversionError> {
+        let amount = amount.into();
+        to_money(amount.currency().code, dyn_decimal_parts(&amount)?, true)
+    }
+
+    /// Returns the exact decimal value of this [`Money`], validating `nanos`.
+    fn decimal_parts(&self) -> Result<DecimalParts, ConversionError> {
+        let nanos_range = -(NANOS_PER_UNIT as i32 - 1)..NANOS_PER_UNIT as i32;
+        if !nanos_range.contains(&self.nanos) || (self.units > 0 && self.nanos < 0) || (self.units < 0 && self.nanos > 0) {
+            return Err(ConversionError::Invalid);
+        }
+        // cannot overflow, as both parts are at most 64 bits wide
+        let mantissa = U256::from(self.units.unsigned_abs()) * U256::from(NANOS_PER_UNIT) + U256::from(self.nanos.unsigned_abs());
+        Ok(DecimalParts {
+            negative: self.units < 0 || self.nanos < 0,
+            mantissa,
+            scale: 9,
+        })
+    }
+}
+
+fn dyn_decimal_parts(amount: &DynAmount) -> Result<DecimalParts, ConversionError> {
+    DecimalParts::from_minor(amount.is_negative(), amount.raw_backing(), amount.currency().base)
+}
+
+/// Splits `parts` into whole units and nanos, rounding towards zero if `truncate` is set.
+fn to_money(code: &str, parts: DecimalParts, truncate: bool) -> Result<Money, ConversionError> {
+    let ten = U256::from(10u8);
+    let mut nanos = parts.mantissa;
+    for _ in parts.scale..9 {
+        nanos = nanos.checked_mul(&ten).ok_or(ConversionError::Overflow)?;
+    }
+    for _ in 9..parts.scale {
+        let (quotient, remainder) = nanos.div_rem(&ten);
+        if !remainder.is_zero() && !truncate {
+            return Err(ConversionError::Inexact);
+        }
+        nanos = quotient;
+    }
+    let (units, nanos) = nanos.div_rem(&U256::from(NANOS_PER_UNIT));
+    let units = units.to_u64().ok_or(ConversionError::Overflow)?;
+    let nanos = nanos.to_u64().unwrap() as i32;
+    let (units, nanos) = match parts.negative {
+        true => (0i64.checked_sub_unsigned(units).ok_or(ConversionError::Overflow)?, -nanos),
+        false => (i64::try_from(units).map_err(|_| ConversionError::Overflow)?, nanos),
+    };
+    Ok(Money {
+        currency_code: code.to_string(),
+        units,
+        nanos,
+    })
+}
+
+/// Converts an [`Amount`] into a [`Money`] exactly, failing with [`ConversionError::Inexact`]
+/// if it has non-zero digits beyond the 9th after the decimal point.
+impl<C: Currency, Safety: safety::Safety> TryFrom<Amount<C, Safety>> for Money {
+    type Error = ConversionError;
+
+    fn try_from(amount: Amount<C, Safety>) -> Result<Self, ConversionError> {
+        to_money(C::CODE, amount.decimal_parts()?, false)
+    }
+}
+
+/// Converts a [`Money`] into an [`Amount`] exactly, failing with
+/// [`ConversionError::CurrencyMismatch`] unless its `currency_code` is the [`Currency::CODE`]
+/// of `C`.
+impl<C: Currency, Safety: safety::Safety> TryFrom<Money> for Amount<C, Safety> {
+    type Error = ConversionError;
+
+    fn try_from(money: Money) -> Result<Self, ConversionError> {
+        if money.currency_code != C::CODE {
+            return Err(ConversionError::CurrencyMismatch);
+        }
+        Amount::from_decimal_parts(money.decimal_parts()?)
+    }
+}
+
+/// Converts a [`DynAmount`] into a [`Money`] exactly, like the [`TryFrom`] impl for [`Amount`].
+impl TryFrom<DynAmount> for Money {
+    type Error = ConversionError;
+
+    fn try_from(amount: DynAmount) -> Result<Self, ConversionError> {
+        to_money(amount.currency().code, dyn_decimal_parts(&amount)?, false)
+    }
+}
+
+/// Converts a [`Money`] into a [`DynAmount`] exactly, looking up its `currency_code` in the
+/// [`registry`](crate::registry).
+impl TryFrom<Money> for DynAmount {
+    type Error = ConversionError;
+
+    fn try_from(money: Money) -> Result<Self, ConversionError> {
+        let currency = crate::registry::by_code(&money.currency_code).ok_or(ConversionError::UnknownCurrency)?;
+        let parts = money.decimal_parts()?;
+        let amount = DynAmount::from_raw(currency, parts.to_minor(currency.base)?);
+        Ok(if parts.negative { -amount } else { amount })
+    }
+}
+
+#[cfg(test)]
+use crate::currency::{DOT, ETH, KWD, SIGNEDUSD, USD};
+
+#[cfg(test)]
+fn money(code: &str, units: i64, nanos: i32) -> Money {
+    Money {
+        currency_code: code.into(),
+        units,
+        nanos,
+    }
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_amount_to_money() {
+    assert_eq!(Money::try_from(Amount::<USD>::from_raw(123_45)), Ok(money("USD", 123, 450_000_000)));
+    assert_eq!(Money::try_from(Amount::<KWD>::from_raw(2_005)), Ok(money("KWD", 2, 5_000_000)));
+    assert_eq!(
+        Money::try_from(Amount::<SIGNEDUSD>::from_raw(-1_05)),
+        Ok(money("SIGNEDUSD", -1, -50_000_000))
+    );
+    assert_eq!(Money::try_from(Amount::<SIGNEDUSD>::from_raw(-5)), Ok(money("SIGNEDUSD", 0, -50_000_000)));
+    assert_eq!(
+        Money::try_from(Amount::<SIGNEDUSD>::from_raw(i64::MIN)),
+        Ok(money("SIGNEDUSD", -92233720368547758, -80_000_000))
+    );
+    assert_eq!(Money::try_from(Amount::<DOT>::from_raw(12_3000000000)), Ok(money("DOT", 12, 300_000_000)));
+    let eth = Amount::<ETH>::from_raw(U256::from(1_500000000000000000u64));
+    assert_eq!(Money::try_from(eth), Ok(money("ETH", 1, 500_000_000)));
+
+    let eth = Amount::<ETH>::from_raw(U256::from(1_500000000000000001u64));
+    assert_eq!(Money::try_from(eth), Err(ConversionError::Inexact));
+    assert_eq!(Money::truncated(eth), Ok(money("ETH", 1, 500_000_000)));
+    assert_eq!(Money::truncated(Amount::<DOT>::from_raw(1)), Ok(money("DOT", 0, 0)));
+    let eth = Amount::<ETH>::from_raw(U256::from(u64::MAX) * U256::from(1_000000000000000000u64));
+    assert_eq!(Money::try_from(eth), Err(ConversionError::Overflow));
+    assert_eq!(Money::truncated(Amount::<ETH>::from_raw(U256::MAX_VALUE)), Err(ConversionError::Overflow));
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_money_to_amount() {
+    assert_eq!(Amount::<USD>::try_from(money("USD", 123, 450_000_000)), Ok(Amount::from_raw(123_45)));
+    assert_eq!(
+        Amount::<SIGNEDUSD>::try_from(money("SIGNEDUSD", 0, -50_000_000)),
+        Ok(Amount::from_raw(-5))
+    );
+    assert_eq!(
+        Amount::<ETH>::try_from(money("ETH", i64::MAX, 999_999_999)),
+        Ok(Amount::from_raw(
+            U256::from(i64::MAX as u64) * U256::from(1_000000000000000000u64) + U256::from(999_999_999_000000000u64)
+        ))
+    );
+
+    assert_eq!(Amount::<USD>::try_from(money("EUR", 1, 0)), Err(ConversionError::CurrencyMismatch));
+    assert_eq!(Amount::<USD>::try_from(money("USD", 1, 5)), Err(ConversionError::Inexact));
+    assert_eq!(Amount::<USD>::try_from(money("USD", -1, 0)), Err(ConversionError::Negative));
+    assert_eq!(Amount::<USD>::try_from(money("USD", i64::MAX, 0)), Err(ConversionError::Overflow));
+    assert_eq!(Amount::<USD>::try_from(money("USD", 1, -500_000_000)), Err(ConversionError::Invalid));
+    assert_eq!(Amount::<USD>::try_from(money("USD", 0, 1_000_000_000)), Err(ConversionError::Invalid));
+    assert_eq!(Amount::<USD>::try_from(money("USD", 0, -1_000_000_000)), Err(ConversionError::Invalid));
+}
+
+#[test]
+fn test_money_dyn_amount() {
+    let amount = DynAmount::from(Amount::<DOT>::from_raw(12_3000000000));
+    assert_eq!(Money::try_from(amount), Ok(money("DOT", 12, 300_000_000)));
+    assert_eq!(DynAmount::try_from(money("DOT", 12, 300_000_000)), Ok(amount));
+    let amount = -DynAmount::from(Amount::<USD>::from_raw(1_05));
+    assert_eq!(Money::try_from(amount), Ok(money("USD", -1, -50_000_000)));
+    assert_eq!(DynAmount::try_from(money("USD", -1, -50_000_000)), Ok(amount));
+    assert_eq!(DynAmount::try_from(money("FOO", 1, 0)), Err(ConversionError::UnknownCurrency));
+    assert_eq!(DynAmount::try_from(money("USD", 0, 1)), Err(ConversionError::Inexact));
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_money_wire_format() {
+    use prost::Message;
+
+    let encoded = money("USD", 123, 450_000_000).encode_to_vec();
+    assert_eq!(encoded[..7], [0x0a, 3, b'U', b'S', b'D', 0x10, 123]);
+    assert_eq!(Money::decode(&encoded[..]).unwrap(), money("USD", 123, 450_000_000));
+    assert_eq!(
+        Amount::<USD>::try_from(Money::decode(&encoded[..]).unwrap()),
+        Ok(Amount::from_raw(123_45))
+    );
+}
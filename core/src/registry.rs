@@ -0,0 +1,150 @@
+//! Runtime lookup of currencies by code or symbol.
+//!
+//! Every built-in currency is always available. When the `std` feature is enabled, additional
+//! currencies (e.g. ones created with [`define_currency!`](crate::define_currency)) can be
+//! added at runtime via [`register`].
+
+#[cfg(feature = "std")]
+use std::{sync::RwLock, vec::Vec};
+
+use crate::currency::*;
+
+/// Expands the rows of [`currencies_table!`](crate::currencies_table) into the
+/// [`CurrencyInfo`] of each currency.
+macro_rules! currency_infos {
+    ($(($currency:ident, $($rest:tt)*)),* $(,)?) => {
+        &[$(CurrencyInfo::of::<$currency>),*]
+    };
+}
+
+/// Lazily evaluated [`CurrencyInfo`] for every currency built into this crate.
+static BUILTIN: &[fn() -> CurrencyInfo] = crate::currencies_table!(currency_infos);
+
+/// Currencies added at runtime via [`register`].
+#[cfg(feature = "std")]
+static REGISTERED: RwLock<Vec<CurrencyInfo>> = RwLock::new(Vec::new());
+
+/// Iterates over the [`CurrencyInfo`] of every currency built into this crate.
+pub fn builtin() -> impl Iterator<Item = CurrencyInfo> {
+    BUILTIN.iter().map(|info| info())
+}
+
+/// Makes the [`Currency`] `C` known to runtime lookups such as [`by_code`] and the dynamic
+/// parser used by [`DynAmount`](crate::DynAmount).
+///
+/// Returns `false` (and leaves the registry untouched) if a currency with the same
+/// [`Currency::CODE`] is already known.
+#[cfg(feature = "std")]
+pub fn register<C: Currency>() -> bool {
+    if by_code(C::CODE).is_some() {
+        return false;
+    }
+    let mut registered = REGISTERED.write().unwrap_or_else(|err| err.into_inner());
+    if registered.iter().any(|info| info.code.eq_ignore_ascii_case(C::CODE)) {
+        return false;
+    }
+    registered.push(CurrencyInfo::of::<C>());
+    true
+}
+
+/// Returns the [`CurrencyInfo`] of every known currency, i.e. all built-in currencies followed
+/// by any that were added via [`register`].
+#[cfg(feature = "std")]
+pub fn all() -> Vec<CurrencyInfo> {
+    let registered = REGISTERED.read().unwrap_or_else(|err| err.into_inner());
+    builtin().chain(registered.iter().copied()).collect()
+}
+
+/// Looks up a known currency by its [`Currency::CODE`], ignoring ASCII case.
+pub fn by_code(code: &str) -> Option<CurrencyInfo> {
+    if let Some(info) = builtin().find(|info| info.code.eq_ignore_ascii_case(code)) {
+        return Some(info);
+    }
+    #[cfg(feature = "std")]
+    {
+        let registered = REGISTERED.read().unwrap_or_else(|err| err.into_inner());
+        if let Some(info) = registered.iter().find(|info| info.code.eq_ignore_ascii_case(code)) {
+            return Some(*info);
+        }
+    }
+    None
+}
+
+/// Looks up a known currency by its [`Currency::NUMERIC_CODE`], for systems such as card
+/// networks that only transmit ISO-4217 numeric codes.
+///
+/// Where a current currency shares its number with a
+/// [`WITHDRAWN`](crate::iso4217::WITHDRAWN) one, as `XCG` does with `ANG`, the current one is
+/// returned.
+pub fn by_numeric_code(numeric_code: u16) -> Option<CurrencyInfo> {
+    let matches = |info: &CurrencyInfo| info.numeric_code == Some(numeric_code);
+    let withdrawn = |info: &CurrencyInfo| crate::iso4217::WITHDRAWN.contains(&info.code);
+    if let Some(info) = builtin().filter(matches).min_by_key(withdrawn) {
+        return Some(info);
+    }
+    #[cfg(feature = "std")]
+    {
+        let registered = REGISTERED.read().unwrap_or_else(|err| err.into_inner());
+        if let Some(info) = registered.iter().find(|info| matches(info)) {
+            return Some(*info);
+        }
+    }
+    None
+}
+
+/// Returns every known currency whose [`Currency::SYMBOL`] matches `symbol`, ignoring case.
+///
+/// Symbols are not unique, so this can return many currencies (e.g. `$`).
+#[cfg(feature = "std")]
+pub fn by_symbol(symbol: &str) -> Vec<CurrencyInfo> {
+    let symbol = symbol.to_lowercase();
+    all().into_iter().filter(|info| info.symbol.to_lowercase() == symbol).collect()
+}
+
+#[cfg(all(test, feature = "std"))]
+crate::define_currency!(TESTREG, u64, 1_000, "⚘", "Registry Test Token", PrefixAttached, false, false);
+
+#[test]
+fn test_by_code() {
+    assert_eq!(by_code("USD"), Some(CurrencyInfo::of::<USD>()));
+    assert_eq!(by_code("dot"), Some(CurrencyInfo::of::<DOT>()));
+    assert_eq!(by_code("ETH").unwrap().decimal_digits(), 18);
+    assert_eq!(by_code("NOPE"), None);
+}
+
+#[test]
+fn test_by_numeric_code() {
+    assert_eq!(by_numeric_code(840), Some(CurrencyInfo::of::<USD>()));
+    assert_eq!(by_numeric_code(392), Some(CurrencyInfo::of::<JPY>()));
+    assert_eq!(by_numeric_code(8), Some(CurrencyInfo::of::<ALL>()));
+    assert_eq!(by_numeric_code(532), Some(CurrencyInfo::of::<XCG>()));
+    assert_eq!(by_numeric_code(0), None);
+    for info in builtin() {
+        assert_eq!(info.numeric_code.is_some(), info.is_iso, "{}", info.code);
+        if let Some(numeric_code) = info.numeric_code {
+            assert_eq!(by_numeric_code(numeric_code).map(|info| info.numeric_code), Some(Some(numeric_code)));
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_by_symbol() {
+    let dollars = by_symbol("$");
+    assert!(dollars.contains(&CurrencyInfo::of::<USD>()));
+    assert!(dollars.contains(&CurrencyInfo::of::<CAD>()));
+    assert!(dollars.contains(&CurrencyInfo::of::<AUD>()));
+    assert!(!dollars.contains(&CurrencyInfo::of::<EUR>()));
+    assert_eq!(by_symbol("€"), [CurrencyInfo::of::<EUR>()]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_register() {
+    assert!(!register::<USD>());
+    assert!(register::<TESTREG>());
+    assert!(!register::<TESTREG>());
+    assert_eq!(by_code("TESTREG"), Some(CurrencyInfo::of::<TESTREG>()));
+    assert_eq!(by_symbol("⚘"), [CurrencyInfo::of::<TESTREG>()]);
+    assert!(all().contains(&CurrencyInfo::of::<TESTREG>()));
+}
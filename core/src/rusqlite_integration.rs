@@ -0,0 +1,99 @@
+use num_traits::ToPrimitive;
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+use rusqlite::Result;
+
+use crate::amount::Amount;
+use crate::currency::Currency;
+use crate::safety;
+use crate::u256::{u256_to_backing, U256};
+
+/// Stores an [`Amount`] as its number of minor units: an `INTEGER` when it fits in an [`i64`],
+/// otherwise `TEXT` such as `"1500000000000000000000"`.
+///
+/// Columns holding such large amounts should not have `INTEGER`, `NUMERIC` or `REAL` affinity,
+/// since SQLite would then convert the text to a lossy floating point value.
+impl<C: Currency, Safety: safety::Safety> ToSql for Amount<C, Safety> {
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        let raw = self.raw_backing();
+        Ok(match raw.to_i64() {
+            Some(raw) => ToSqlOutput::from(raw),
+            None => ToSqlOutput::from(raw.to_string()),
+        })
+    }
+}
+
+/// Reads an [`Amount`] from a number of minor units stored as an `INTEGER` or as `TEXT`, as
+/// written by the [`ToSql`] impl.
+impl<C: Currency, Safety: safety::Safety> FromSql for Amount<C, Safety> {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Integer(raw) => u256_to_backing(U256::from(raw.unsigned_abs()), raw < 0)
+                .map(Amount::from_raw)
+                .ok_or(FromSqlError::OutOfRange(raw)),
+            ValueRef::Text(text) => {
+                let text = core::str::from_utf8(text).map_err(|err| FromSqlError::Other(Box::new(err)))?;
+                Amount::from_minor_str(text).map_err(|err| FromSqlError::Other(Box::new(err)))
+            }
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::currency::{DOT, ETH, SIGNEDUSD, USD};
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_rusqlite_to_sql() {
+    use rusqlite::types::Value;
+
+    let output = |output: ToSqlOutput<'_>| match output {
+        ToSqlOutput::Owned(value) => value,
+        ToSqlOutput::Borrowed(value) => value.into(),
+        _ => unreachable!(),
+    };
+    assert_eq!(output(Amount::<USD>::from_raw(123_45).to_sql().unwrap()), Value::Integer(12345));
+    assert_eq!(output(Amount::<SIGNEDUSD>::from_raw(-5).to_sql().unwrap()), Value::Integer(-5));
+    assert_eq!(
+        output(Amount::<USD>::from_raw(u64::MAX).to_sql().unwrap()),
+        Value::Text("18446744073709551615".into())
+    );
+    assert_eq!(
+        output(Amount::<ETH>::from_raw(U256::from(1_500000000000000000u64)).to_sql().unwrap()),
+        Value::Integer(1_500000000000000000)
+    );
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_rusqlite_from_sql() {
+    assert_eq!(Amount::<USD>::column_result(ValueRef::Integer(12345)), Ok(Amount::from_raw(123_45)));
+    assert_eq!(
+        Amount::<SIGNEDUSD>::column_result(ValueRef::Integer(i64::MIN)),
+        Ok(Amount::from_raw(i64::MIN))
+    );
+    assert_eq!(
+        Amount::<DOT>::column_result(ValueRef::Text(b"340282366920938463463374607431768211455")),
+        Ok(Amount::from_raw(u128::MAX))
+    );
+    assert_eq!(Amount::<USD>::column_result(ValueRef::Integer(-1)), Err(FromSqlError::OutOfRange(-1)));
+    assert_eq!(Amount::<USD>::column_result(ValueRef::Real(1.5)), Err(FromSqlError::InvalidType));
+    assert!(Amount::<USD>::column_result(ValueRef::Text(b"1.50")).is_err());
+}
+
+#[test]
+fn test_rusqlite_round_trip() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    conn.execute("CREATE TABLE balances (usd, eth)", ()).unwrap();
+    let usd = Amount::<USD>::from_raw(u64::MAX);
+    let eth = Amount::<ETH>::from_raw(U256::MAX_VALUE);
+    conn.execute("INSERT INTO balances VALUES (?1, ?2)", (usd, eth)).unwrap();
+    let (read_usd, read_eth): (Amount<USD>, Amount<ETH>) = conn
+        .query_row("SELECT usd, eth FROM balances", (), |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap();
+    assert_eq!((read_usd, read_eth), (usd, eth));
+
+    conn.execute("UPDATE balances SET usd = ?1", (Amount::<USD>::from_raw(3_24),)).unwrap();
+    let stored: i64 = conn.query_row("SELECT usd FROM balances", (), |row| row.get(0)).unwrap();
+    assert_eq!(stored, 3_24);
+}
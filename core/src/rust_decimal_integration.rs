@@ -0,0 +1,121 @@
+use rust_decimal::Decimal;
+
+use crate::amount::Amount;
+use crate::conversion::{ConversionError, DecimalParts};
+use crate::currency::Currency;
+use crate::safety;
+use crate::u256::U256;
+
+/// Converts a [`Decimal`] into an [`Amount`] exactly, failing with
+/// [`ConversionError::Inexact`] if it is not a whole number of minor units.
+impl<C: Currency, Safety: safety::Safety> TryFrom<Decimal> for Amount<C, Safety> {
+    type Error = ConversionError;
+
+    fn try_from(value: Decimal) -> Result<Self, ConversionError> {
+        Amount::from_decimal_parts(DecimalParts {
+            negative: value.is_sign_negative(),
+            mantissa: U256::from(value.mantissa().unsigned_abs()),
+            scale: value.scale(),
+        })
+    }
+}
+
+/// Converts an [`Amount`] into a [`Decimal`] exactly, keeping as many digits after the decimal
+/// point as the [`Currency`] has where possible.
+///
+/// This is fallible even for [`u64`] backings, since a [`Decimal`] has a 96-bit mantissa and
+/// at most 28 digits after the decimal point. Trailing zeros are dropped if that makes the value
+/// fit, so e.g. a whole number of ETH always converts as long as it is below `2^96`.
+impl<C: Currency, Safety: safety::Safety> TryFrom<Amount<C, Safety>> for Decimal {
+    type Error = ConversionError;
+
+    fn try_from(amount: Amount<C, Safety>) -> Result<Self, ConversionError> {
+        let parts = amount.decimal_parts()?;
+        to_decimal(parts).or_else(|_| to_decimal(parts.trim(0)))
+    }
+}
+
+fn to_decimal(parts: DecimalParts) -> Result<Decimal, ConversionError> {
+    if parts.scale > Decimal::MAX_SCALE {
+        return Err(ConversionError::Inexact);
+    }
+    let mantissa = u128::try_from(parts.mantissa.0).map_err(|_| ConversionError::Overflow)?;
+    let mantissa = i128::try_from(mantissa).map_err(|_| ConversionError::Overflow)?;
+    let mantissa = if parts.negative { -mantissa } else { mantissa };
+    Decimal::try_from_i128_with_scale(mantissa, parts.scale).map_err(|_| ConversionError::Overflow)
+}
+
+#[cfg(test)]
+use crate::currency::{DOT, ETH, MGA, SIGNEDUSD, USD};
+
+#[cfg(test)]
+use alloc::string::ToString;
+#[cfg(test)]
+use core::str::FromStr;
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_rust_decimal_to_amount() {
+    let decimal = Decimal::from_str("123.45").unwrap();
+    assert_eq!(Amount::<USD>::try_from(decimal), Ok(Amount::from_raw(123_45)));
+    assert_eq!(
+        Amount::<USD>::try_from(Decimal::from_str("7.000000").unwrap()),
+        Ok(Amount::from_raw(7_00))
+    );
+    assert_eq!(
+        Amount::<USD>::try_from(Decimal::from(18_446_744_073_709_551u64)),
+        Ok(Amount::from_raw(18_446_744_073_709_551_00))
+    );
+    assert_eq!(Amount::<MGA>::try_from(Decimal::from_str("3.4").unwrap()), Ok(Amount::from_raw(17)));
+    assert_eq!(
+        Amount::<SIGNEDUSD>::try_from(Decimal::from_str("-0.05").unwrap()),
+        Ok(Amount::from_raw(-5))
+    );
+    assert_eq!(Amount::<USD>::try_from(Decimal::from_str("-0.00").unwrap()), Ok(Amount::from_raw(0)));
+    assert_eq!(
+        Amount::<DOT>::try_from(Decimal::from_str("12.0000000004").unwrap()),
+        Ok(Amount::from_raw(12_0000000004))
+    );
+    assert_eq!(
+        Amount::<ETH>::try_from(Decimal::MAX),
+        Ok(Amount::from_raw(
+            U256::from(u128::try_from(Decimal::MAX.mantissa()).unwrap()) * U256::from(1_000000000000000000u64)
+        ))
+    );
+
+    assert_eq!(
+        Amount::<USD>::try_from(Decimal::from_str("123.456").unwrap()),
+        Err(ConversionError::Inexact)
+    );
+    assert_eq!(Amount::<MGA>::try_from(Decimal::from_str("0.1").unwrap()), Err(ConversionError::Inexact));
+    assert_eq!(Amount::<USD>::try_from(Decimal::from_str("-1").unwrap()), Err(ConversionError::Negative));
+    assert_eq!(Amount::<USD>::try_from(Decimal::from(u64::MAX)), Err(ConversionError::Overflow));
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_amount_to_rust_decimal() {
+    let decimal = Decimal::try_from(Amount::<USD>::from_raw(123_45)).unwrap();
+    assert_eq!(decimal.to_string(), "123.45");
+    assert_eq!(Decimal::try_from(Amount::<USD>::from_raw(7_00)).unwrap().to_string(), "7.00");
+    assert_eq!(
+        Decimal::try_from(Amount::<SIGNEDUSD>::from_raw(i64::MIN)).unwrap().to_string(),
+        "-92233720368547758.08"
+    );
+    assert_eq!(Decimal::try_from(Amount::<MGA>::from_raw(17)).unwrap().to_string(), "3.4");
+    assert_eq!(
+        Decimal::try_from(Amount::<DOT>::from_raw(12_0000000004)).unwrap().to_string(),
+        "12.0000000004"
+    );
+
+    let eth = Amount::<ETH>::from_raw(U256::from(1_500000000000000000u64));
+    assert_eq!(Decimal::try_from(eth).unwrap().to_string(), "1.500000000000000000");
+    let eth = Amount::<ETH>::from_raw(U256(primitive_types::U256::exp10(30)));
+    assert_eq!(Decimal::try_from(eth).unwrap().to_string(), "1000000000000");
+    assert_eq!(Amount::<ETH>::try_from(Decimal::try_from(eth).unwrap()), Ok(eth));
+    assert_eq!(
+        Decimal::try_from(Amount::<ETH>::from_raw(U256::MAX_VALUE)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(Decimal::try_from(Amount::<DOT>::from_raw(u128::MAX)), Err(ConversionError::Overflow));
+}
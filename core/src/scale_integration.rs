@@ -0,0 +1,125 @@
+use parity_scale_codec::{Compact, CompactAs, Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output};
+
+use crate::amount::{Amount, Backing};
+use crate::currency::Currency;
+use crate::encoding::LeBytes;
+use crate::safety;
+use crate::u256::U256;
+
+/// Encodes a [`U256`] as 32 little-endian bytes, the same layout `primitive-types` uses.
+impl Encode for U256 {
+    fn size_hint(&self) -> usize {
+        32
+    }
+
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(&self.to_le_bytes());
+    }
+}
+
+impl EncodeLike for U256 {}
+
+impl Decode for U256 {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let mut bytes = [0; 32];
+        input.read(&mut bytes)?;
+        Ok(U256::from_le_bytes(bytes))
+    }
+}
+
+impl MaxEncodedLen for U256 {
+    fn max_encoded_len() -> usize {
+        32
+    }
+}
+
+/// Encodes an [`Amount`] exactly like its raw [`Backing`] value.
+impl<C: Currency, Safety: safety::Safety> Encode for Amount<C, Safety>
+where
+    C::Backing: Encode,
+{
+    fn size_hint(&self) -> usize {
+        self.raw_backing().size_hint()
+    }
+
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        self.raw_backing().encode_to(dest)
+    }
+}
+
+impl<C: Currency, Safety: safety::Safety> EncodeLike for Amount<C, Safety> where C::Backing: Encode {}
+
+impl<C: Currency, Safety: safety::Safety> Decode for Amount<C, Safety>
+where
+    C::Backing: Decode,
+{
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        C::Backing::decode(input).map(Amount::from_raw)
+    }
+}
+
+impl<C: Currency, Safety: safety::Safety> MaxEncodedLen for Amount<C, Safety>
+where
+    C::Backing: MaxEncodedLen,
+{
+    fn max_encoded_len() -> usize {
+        C::Backing::max_encoded_len()
+    }
+}
+
+/// Allows [`Amount`]s to be encoded compactly via [`Compact`] or `#[codec(compact)]`, which is
+/// supported whenever the raw [`Backing`] value supports it (i.e. for unsigned primitives).
+impl<C: Currency, Safety: safety::Safety> CompactAs for Amount<C, Safety>
+where
+    C::Backing: Backing,
+{
+    type As = C::Backing;
+
+    fn encode_as(&self) -> &C::Backing {
+        &self.0
+    }
+
+    fn decode_from(raw: C::Backing) -> Result<Self, Error> {
+        Ok(Amount::from_raw(raw))
+    }
+}
+
+impl<C: Currency, Safety: safety::Safety> From<Compact<Amount<C, Safety>>> for Amount<C, Safety> {
+    fn from(compact: Compact<Amount<C, Safety>>) -> Self {
+        compact.0
+    }
+}
+
+#[cfg(test)]
+use crate::currency::{DOT, ETH, SOL, USD};
+
+#[test]
+fn test_scale_encoding() {
+    let amount = Amount::<DOT>::from_raw(12_0000000004);
+    let encoded = amount.encode();
+    assert_eq!(encoded, 12_0000000004u128.encode());
+    assert_eq!(Amount::<DOT>::decode(&mut &encoded[..]).unwrap(), amount);
+    assert_eq!(Amount::<DOT>::max_encoded_len(), 16);
+
+    let amount = Amount::<ETH>::from_raw(U256::from(1_000000000000000000u64));
+    let encoded = amount.encode();
+    assert_eq!(encoded.len(), 32);
+    assert_eq!(encoded[..8], 1_000000000000000000u64.to_le_bytes());
+    assert_eq!(encoded[8..], [0; 24]);
+    assert_eq!(Amount::<ETH>::decode(&mut &encoded[..]).unwrap(), amount);
+    assert_eq!(Amount::<ETH>::max_encoded_len(), 32);
+    assert!(Amount::<ETH>::decode(&mut &encoded[..31]).is_err());
+}
+
+#[test]
+fn test_scale_compact_encoding() {
+    let amount = Amount::<SOL>::from_raw(1_000);
+    let encoded = Compact(amount).encode();
+    assert_eq!(encoded, Compact(1_000u64).encode());
+    assert_eq!(encoded.len(), 2);
+    assert_eq!(Compact::<Amount<SOL>>::decode(&mut &encoded[..]).unwrap().0, amount);
+
+    let amount = Amount::<USD>::from_raw(u64::MAX);
+    let encoded = Compact(amount).encode();
+    assert_eq!(Amount::from(Compact::<Amount<USD>>::decode(&mut &encoded[..]).unwrap()), amount);
+}
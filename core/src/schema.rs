@@ -0,0 +1,151 @@
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::marker::PhantomData;
+use core::mem::size_of;
+
+use num_traits::{CheckedAdd, CheckedMul, ToPrimitive};
+
+use crate::amount::Amount;
+use crate::currency::{Currency, FormatStyle};
+use crate::parsing::is_signed;
+
+/// Stands in for an [`Amount`] serialized with
+/// [`minor_units`](crate::serde_integration::minor_units) when generating schemas, e.g. with
+/// `#[schemars(with = "currencies::schema::MinorUnits<USD>")]` or
+/// `#[schema(value_type = currencies::schema::MinorUnits<USD>)]`.
+pub struct MinorUnits<C: Currency>(PhantomData<C>);
+
+/// Stands in for an [`Amount`] serialized with
+/// [`decimal_string`](crate::serde_integration::decimal_string) when generating schemas.
+pub struct DecimalString<C: Currency>(PhantomData<C>);
+
+/// Stands in for an [`Amount`] serialized with [`object`](crate::serde_integration::object)
+/// when generating schemas.
+pub struct Object<C: Currency>(PhantomData<C>);
+
+/// Stands in for an [`Amount`] serialized with [`le_bytes`](crate::serde_integration::le_bytes)
+/// when generating schemas.
+pub struct LeBytes<C: Currency>(PhantomData<C>);
+
+/// The name under which the schema of a representation of amounts of `C` is registered, e.g.
+/// `AmountUSD` or `AmountMinorUnitsUSD`.
+pub(crate) fn name<C: Currency>(representation: &str) -> Cow<'static, str> {
+    Cow::Owned(format!("Amount{}{}", representation, C::CODE))
+}
+
+/// Describes amounts of `C` written in the specified way.
+pub(crate) fn description<C: Currency>(written_as: &str) -> String {
+    format!("An amount of {} ({}) written as {}", C::PROPER_NAME, C::CODE, written_as)
+}
+
+/// Returns an example amount of `C` to show in schemas, such as `$123.50`, or a single major
+/// unit if that would overflow the backing of `C`.
+pub(crate) fn example<C: Currency>() -> Amount<C> {
+    let base = C::BASE;
+    let raw = base
+        .checked_mul(&C::Backing::from(123))
+        .and_then(|raw| raw.checked_add(&(base / C::Backing::from(2))));
+    Amount::from_raw(raw.unwrap_or(base))
+}
+
+fn sign<C: Currency>() -> &'static str {
+    match is_signed::<C::Backing>() {
+        true => "-?",
+        false => "",
+    }
+}
+
+/// Appends `text` to `pattern`, escaping any characters with special meaning in a regex.
+fn push_escaped(pattern: &mut String, text: &str) {
+    for c in text.chars() {
+        if "\\.+*?()|[]{}^$/".contains(c) {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+}
+
+/// Returns a regex matching the strings produced by the [`core::fmt::Display`] impl of
+/// [`Amount`], e.g. `^-?\$[0-9]+\.[0-9]{2}$` for a signed currency with the symbol `$`.
+pub(crate) fn display_pattern<C: Currency>() -> String {
+    let mut pattern = format!("^{}", sign::<C>());
+    match C::STYLE {
+        FormatStyle::PrefixAttached => push_escaped(&mut pattern, C::SYMBOL),
+        FormatStyle::PrefixSpaced => {
+            push_escaped(&mut pattern, C::SYMBOL);
+            pattern.push(' ');
+        }
+        _ => (),
+    }
+    pattern.push_str("[0-9]+");
+    if C::decimal_digits() > 0 {
+        pattern.push_str(&format!("\\.[0-9]{{{}}}", C::decimal_digits()));
+    }
+    match C::STYLE {
+        FormatStyle::SuffixAttached => push_escaped(&mut pattern, C::SYMBOL),
+        FormatStyle::SuffixSpaced => {
+            pattern.push(' ');
+            push_escaped(&mut pattern, C::SYMBOL);
+        }
+        _ => (),
+    }
+    pattern.push('$');
+    pattern
+}
+
+/// Returns a regex matching the strings produced by
+/// [`decimal_string`](crate::serde_integration::decimal_string), e.g. `^[0-9]+\.[0-9]{2}$`.
+pub(crate) fn decimal_pattern<C: Currency>() -> String {
+    match C::decimal_digits() {
+        0 => format!("^{}[0-9]+$", sign::<C>()),
+        digits => format!("^{}[0-9]+\\.[0-9]{{{}}}$", sign::<C>(), digits),
+    }
+}
+
+/// Returns a regex matching the strings of digits that
+/// [`minor_units`](crate::serde_integration::minor_units) writes for backings too wide for
+/// primitive integers.
+pub(crate) fn digits_pattern<C: Currency>() -> String {
+    format!("^{}[0-9]+$", sign::<C>())
+}
+
+/// Returns the integer format that [`minor_units`](crate::serde_integration::minor_units)
+/// writes amounts of `C` as, such as `"uint64"`, or [`None`] if they are written as strings.
+pub(crate) fn minor_units_format<C: Currency>() -> Option<&'static str> {
+    match (size_of::<C::Backing>(), is_signed::<C::Backing>()) {
+        (0..=8, false) => Some("uint64"),
+        (0..=8, true) => Some("int64"),
+        (9..=16, false) => Some("uint128"),
+        (9..=16, true) => Some("int128"),
+        _ => None,
+    }
+}
+
+/// Returns the number of bytes that [`le_bytes`](crate::serde_integration::le_bytes) writes
+/// amounts of `C` as.
+pub(crate) fn le_bytes_len<C: Currency>() -> usize
+where
+    C::Backing: crate::encoding::LeBytes,
+{
+    <C::Backing as crate::encoding::LeBytes>::Bytes::default().as_ref().len()
+}
+
+/// Returns the example amount of `C` as a plain decimal string, e.g. `"123.50"`.
+pub(crate) fn decimal_example<C: Currency>() -> String {
+    let mut text = String::new();
+    crate::amount::fmt_decimal(&mut text, example::<C>().raw_backing(), C::BASE).unwrap();
+    text
+}
+
+/// Returns the example amount of `C` as its number of minor units, which is written as an
+/// integer if it fits in an [`i64`] and as a string of digits otherwise.
+pub(crate) fn minor_units_example<C: Currency>() -> (Option<i64>, String) {
+    let raw = example::<C>().raw_backing();
+    (raw.to_i64(), raw.to_string())
+}
+
+/// Returns the example amount of `C` as formatted by its [`core::fmt::Display`] impl.
+pub(crate) fn display_example<C: Currency>() -> String {
+    example::<C>().to_string()
+}
@@ -0,0 +1,253 @@
+use alloc::borrow::Cow;
+use alloc::format;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::amount::Amount;
+use crate::currency::Currency;
+use crate::encoding;
+use crate::parsing::is_signed;
+use crate::safety;
+use crate::schema::{self, DecimalString, LeBytes, MinorUnits, Object};
+
+/// Adds the [`Currency::CODE`] and [`Currency::decimal_digits`] of `C` to `schema` as the
+/// `x-currency` and `x-decimal-digits` keywords.
+fn with_metadata<C: Currency>(mut schema: Schema) -> Schema {
+    schema.insert("x-currency".into(), C::CODE.into());
+    schema.insert("x-decimal-digits".into(), C::decimal_digits().into());
+    schema
+}
+
+fn schema_id<C: Currency>(representation: &str) -> Cow<'static, str> {
+    Cow::Owned(format!("currencies::{}<{}>", representation, C::CODE))
+}
+
+/// Describes an [`Amount`] as serialized by its [`Serialize`](::serde::Serialize) impl: a
+/// string with the symbol of its [`Currency`], such as `"$123.45"`.
+impl<C: Currency, Safety: safety::Safety> JsonSchema for Amount<C, Safety> {
+    fn schema_name() -> Cow<'static, str> {
+        schema::name::<C>("")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        schema_id::<C>("Amount")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        with_metadata::<C>(json_schema!({
+            "type": "string",
+            "pattern": schema::display_pattern::<C>(),
+            "description": schema::description::<C>("a string with its symbol"),
+            "examples": [schema::display_example::<C>()],
+        }))
+    }
+}
+
+/// Describes an [`Amount`] as serialized by
+/// [`minor_units`](crate::serde_integration::minor_units): an integer, or a string of digits
+/// for backings wider than 128 bits.
+impl<C: Currency> JsonSchema for MinorUnits<C> {
+    fn schema_name() -> Cow<'static, str> {
+        schema::name::<C>("MinorUnits")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        schema_id::<C>("schema::MinorUnits")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let description = schema::description::<C>("a number of minor units");
+        let (integer_example, digits_example) = schema::minor_units_example::<C>();
+        let mut schema = match schema::minor_units_format::<C>() {
+            Some(format) => json_schema!({
+                "type": "integer",
+                "format": format,
+                "description": description,
+                "examples": integer_example.as_slice(),
+            }),
+            None => json_schema!({
+                "type": "string",
+                "pattern": schema::digits_pattern::<C>(),
+                "description": description,
+                "examples": [digits_example],
+            }),
+        };
+        if schema::minor_units_format::<C>().is_some() && !is_signed::<C::Backing>() {
+            schema.insert("minimum".into(), 0.into());
+        }
+        with_metadata::<C>(schema)
+    }
+}
+
+/// Describes an [`Amount`] as serialized by
+/// [`decimal_string`](crate::serde_integration::decimal_string), such as `"123.45"`.
+impl<C: Currency> JsonSchema for DecimalString<C> {
+    fn schema_name() -> Cow<'static, str> {
+        schema::name::<C>("DecimalString")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        schema_id::<C>("schema::DecimalString")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        with_metadata::<C>(json_schema!({
+            "type": "string",
+            "pattern": schema::decimal_pattern::<C>(),
+            "description": schema::description::<C>("a decimal number of major units"),
+            "examples": [schema::decimal_example::<C>()],
+        }))
+    }
+}
+
+/// Describes an [`Amount`] as serialized by [`object`](crate::serde_integration::object), such
+/// as `{"amount":"123.45","currency":"USD"}`.
+impl<C: Currency> JsonSchema for Object<C> {
+    fn schema_name() -> Cow<'static, str> {
+        schema::name::<C>("Object")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        schema_id::<C>("schema::Object")
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        with_metadata::<C>(json_schema!({
+            "type": "object",
+            "properties": {
+                "amount": DecimalString::<C>::json_schema(generator),
+                "currency": { "type": "string", "const": C::CODE },
+            },
+            "required": ["amount", "currency"],
+            "description": schema::description::<C>("an object with its currency code"),
+            "examples": [{ "amount": schema::decimal_example::<C>(), "currency": C::CODE }],
+        }))
+    }
+}
+
+/// Describes an [`Amount`] as serialized by [`le_bytes`](crate::serde_integration::le_bytes):
+/// an array of as many bytes as the backing of its [`Currency`] has.
+impl<C: Currency> JsonSchema for LeBytes<C>
+where
+    C::Backing: encoding::LeBytes,
+{
+    fn schema_name() -> Cow<'static, str> {
+        schema::name::<C>("LeBytes")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        schema_id::<C>("schema::LeBytes")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let len = schema::le_bytes_len::<C>();
+        with_metadata::<C>(json_schema!({
+            "type": "array",
+            "items": { "type": "integer", "minimum": 0, "maximum": 255 },
+            "minItems": len,
+            "maxItems": len,
+            "description": schema::description::<C>("the little-endian bytes of its number of minor units"),
+        }))
+    }
+}
+
+#[cfg(test)]
+use crate::currency::*;
+
+#[cfg(test)]
+use serde_json::json;
+
+#[cfg(test)]
+fn schema_value<T: JsonSchema>() -> serde_json::Value {
+    schemars::SchemaGenerator::default().root_schema_for::<T>().to_value()
+}
+
+#[test]
+fn test_amount_json_schema() {
+    assert_eq!(Amount::<USD>::schema_name(), "AmountUSD");
+    assert_eq!(
+        schema_value::<Amount<USD>>(),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "AmountUSD",
+            "type": "string",
+            "pattern": "^\\$[0-9]+\\.[0-9]{2}$",
+            "description": "An amount of United States Dollar (USD) written as a string with its symbol",
+            "examples": ["$123.50"],
+            "x-currency": "USD",
+            "x-decimal-digits": 2,
+        })
+    );
+    let schema = schema_value::<Amount<EUR>>();
+    assert_eq!(schema["pattern"], "^[0-9]+\\.[0-9]{2}€$");
+    assert_eq!(schema["examples"], json!(["123.50€"]));
+    let schema = schema_value::<Amount<SIGNEDUSD>>();
+    assert_eq!(schema["pattern"], "^-?\\$[0-9]+\\.[0-9]{2}$");
+    let schema = schema_value::<Amount<ETH>>();
+    assert_eq!(schema["x-decimal-digits"], 18);
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_representation_json_schemas() {
+    let schema = schema_value::<MinorUnits<USD>>();
+    assert_eq!(schema["type"], "integer");
+    assert_eq!(schema["format"], "uint64");
+    assert_eq!(schema["minimum"], 0);
+    assert_eq!(schema["examples"], json!([123_50]));
+    let schema = schema_value::<MinorUnits<SIGNEDUSD>>();
+    assert_eq!(schema["format"], "int64");
+    assert_eq!(schema.get("minimum"), None);
+    let schema = schema_value::<MinorUnits<ETH>>();
+    assert_eq!(schema["type"], "string");
+    assert_eq!(schema["pattern"], "^[0-9]+$");
+    assert_eq!(schema["examples"], json!(["123500000000000000000"]));
+
+    let schema = schema_value::<DecimalString<SIGNEDUSD>>();
+    assert_eq!(schema["pattern"], "^-?[0-9]+\\.[0-9]{2}$");
+    assert_eq!(schema["examples"], json!(["123.50"]));
+
+    let schema = schema_value::<Object<USD>>();
+    assert_eq!(schema["properties"]["amount"]["pattern"], "^[0-9]+\\.[0-9]{2}$");
+    assert_eq!(schema["properties"]["currency"], json!({ "type": "string", "const": "USD" }));
+    assert_eq!(schema["required"], json!(["amount", "currency"]));
+    assert_eq!(schema["examples"], json!([{ "amount": "123.50", "currency": "USD" }]));
+
+    let schema = schema_value::<LeBytes<ETH>>();
+    assert_eq!(schema["minItems"], 32);
+    assert_eq!(schema["maxItems"], 32);
+    assert_eq!(schema["x-currency"], "ETH");
+}
+
+#[test]
+fn test_amount_json_schema_references() {
+    let mut generator = SchemaGenerator::default();
+    let reference = generator.subschema_for::<Amount<USD>>();
+    assert_eq!(reference.to_value(), json!({ "$ref": "#/$defs/AmountUSD" }));
+    generator.subschema_for::<MinorUnits<USD>>();
+    let definitions = generator.definitions();
+    assert!(definitions.contains_key("AmountUSD"));
+    assert!(definitions.contains_key("AmountMinorUnitsUSD"));
+}
+
+#[cfg(test)]
+fn check_schemas<C: Currency>() {
+    let example = schema::example::<C>();
+    let schema = schema_value::<Amount<C>>();
+    assert_eq!(schema["examples"], json!([example.to_string()]), "{}", C::CODE);
+    assert_eq!(schema["examples"][0].as_str().unwrap().parse::<Amount<C>>(), Ok(example));
+    let schema = schema_value::<DecimalString<C>>();
+    assert_eq!(schema["examples"], json!([schema::decimal_example::<C>()]), "{}", C::CODE);
+    schema_value::<MinorUnits<C>>();
+    schema_value::<Object<C>>();
+}
+
+#[test]
+fn test_builtin_currency_json_schemas() {
+    macro_rules! check_all {
+        ($(($currency:ident, $($rest:tt)*)),* $(,)?) => {
+            $(check_schemas::<$currency>();)*
+        };
+    }
+    crate::currencies_table!(check_all);
+    assert_eq!(schema::display_example::<MATIC>(), "1.000000000000000000 MATIC");
+}
@@ -1,21 +1,24 @@
-use core::{fmt, marker::PhantomData, str::FromStr};
+use core::{fmt, marker::PhantomData};
 
 #[cfg(test)]
 use crate::{
-    currency::{ETH, USD},
+    currency::{DOT, ETH, EUR, MGA, SIGNEDUSD, USD},
     safety::{Checked, Unchecked},
 };
 
 use super::*;
-use serde::{
+use crate::currency::{CurrencyInfo, FormatStyle};
+use crate::u256::{u256_to_backing, U256};
+use ::serde::{
     de::{self, Visitor},
     Deserialize, Deserializer, Serialize,
 };
+use num_traits::{CheckedMul, Zero};
 
 impl<C: Currency, Safety: safety::Safety> Serialize for Amount<C, Safety> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        S: serde::Serializer,
+        S: ::serde::Serializer,
     {
         serializer.collect_str(self)
     }
@@ -27,14 +30,41 @@ impl<C: Currency, Safety: safety::Safety> AmountVisitor<C, Safety> {
     fn new() -> Self {
         AmountVisitor(PhantomData)
     }
+
+    /// Converts a whole number of major units with the specified magnitude and sign.
+    fn from_major<E: de::Error>(magnitude: U256, negative: bool) -> Result<Amount<C, Safety>, E> {
+        u256_to_backing::<C::Backing>(magnitude, negative)
+            .and_then(|major| major.checked_mul(&C::BASE))
+            .map(Amount::from_raw)
+            .ok_or_else(|| {
+                let sign = if negative { "-" } else { "" };
+                E::custom(format_args!("{}{} does not fit in an amount of {}", sign, magnitude, C::CODE))
+            })
+    }
 }
 
-impl<'de, C: Currency, Safety: safety::Safety> Visitor<'de> for AmountVisitor<C, Safety>
-where
-    C: Currency,
-    Safety: safety::Safety,
-    Amount<C, Safety>: FromStr<Err = de::value::Error>,
-{
+/// A fixed-size buffer that text can be written to without allocating.
+struct StackBuffer {
+    bytes: [u8; 400],
+    len: usize,
+}
+
+impl StackBuffer {
+    fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl fmt::Write for StackBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl<'de, C: Currency, Safety: safety::Safety> Visitor<'de> for AmountVisitor<C, Safety> {
     type Value = Amount<C, Safety>;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -47,19 +77,970 @@ where
     {
         v.parse::<Amount<C, Safety>>().map_err(de::Error::custom)
     }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Self::from_major(U256::from(v), false)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Self::from_major(U256::from(v.unsigned_abs()), v < 0)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        Self::from_major(U256::from(v), false)
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        Self::from_major(U256::from(v.unsigned_abs()), v < 0)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        if !v.is_finite() {
+            return Err(E::invalid_value(de::Unexpected::Float(v), &"a finite number"));
+        }
+        if v == 0.0 {
+            // also covers -0.0, which would otherwise be rejected for unsigned backings
+            return Ok(Amount::from_raw(C::Backing::zero()));
+        }
+        // the `Display` impl of `f64` writes the shortest decimal representation that round
+        // trips, without an exponent, which always fits in the buffer
+        let mut buffer = StackBuffer { bytes: [0; 400], len: 0 };
+        fmt::Write::write_fmt(&mut buffer, format_args!("{}", v)).map_err(E::custom)?;
+        let text = core::str::from_utf8(&buffer.bytes[..buffer.len]).map_err(E::custom)?;
+        Amount::from_decimal_str(text).map_err(|err| E::custom(format_args!("{}: {}", text, err)))
+    }
 }
 
-impl<'de, C: Currency, Safety: safety::Safety> Deserialize<'de> for Amount<C, Safety>
-where
-    C: Currency,
-    Safety: safety::Safety,
-    Amount<C, Safety>: FromStr<Err = de::value::Error>,
-{
+/// Deserializes an [`Amount`] from any of the following:
+///
+/// - a string in the format produced by its [`core::fmt::Display`] impl, such as `"$123.45"`,
+///   which is also how it is serialized;
+/// - an integer, interpreted as a whole number of **major** units, so `1250` is `$1250.00`;
+/// - a floating point number, also interpreted as **major** units, so `12.5` is `$12.50`.
+///
+/// Numbers are converted exactly or not at all. Integers must fit in the backing once
+/// multiplied by [`Currency::BASE`], and floats are read using their shortest decimal
+/// representation (the one [`core::fmt::Display`] produces, so `0.1` is exactly `$0.10`), which
+/// must not have more decimal digits than the currency supports. Use
+/// [`minor_units`] to read integers as minor units instead.
+///
+/// Numbers are only accepted from human-readable formats like JSON. Binary formats read the
+/// string written by the [`Serialize`] impl, since formats such as `bincode` cannot tell what
+/// kind of value comes next; use [`self_describing`] to accept
+/// numbers from self-describing binary formats such as CBOR or MessagePack as well.
+impl<'de, C: Currency, Safety: safety::Safety> Deserialize<'de> for Amount<C, Safety> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match deserializer.is_human_readable() {
+            true => deserializer.deserialize_any(AmountVisitor::<C, Safety>::new()),
+            false => deserializer.deserialize_str(AmountVisitor::<C, Safety>::new()),
+        }
+    }
+}
+
+/// Serializes an [`Amount`] like its [`Serialize`] impl, but deserializes it from any of the
+/// inputs accepted by its [`Deserialize`] impl regardless of whether the format is
+/// human-readable. For use with `#[serde(with = "currencies::serde::self_describing")]` with
+/// self-describing binary formats such as CBOR or MessagePack, where amounts may have been
+/// written as numbers.
+///
+/// Formats that are not self-describing, such as `bincode`, cannot be read this way.
+pub mod self_describing {
+    use super::*;
+
+    /// Serializes `amount` as a string in the format produced by its [`core::fmt::Display`]
+    /// impl.
+    pub fn serialize<C: Currency, Safety: safety::Safety, S: ::serde::Serializer>(
+        amount: &Amount<C, Safety>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        amount.serialize(serializer)
+    }
+
+    /// Deserializes an [`Amount`] from a string or a number of major units.
+    pub fn deserialize<'de, C: Currency, Safety: safety::Safety, D: Deserializer<'de>>(deserializer: D) -> Result<Amount<C, Safety>, D::Error> {
+        deserializer.deserialize_any(AmountVisitor::new())
+    }
+}
+
+/// Displays a raw [`Backing`] value as a plain decimal number with the given base.
+struct DecimalDisplay<B: Backing>(B, B);
+
+impl<B: Backing> fmt::Display for DecimalDisplay<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::amount::fmt_decimal(f, self.0, self.1)
+    }
+}
+
+impl<B: Backing> Serialize for DecimalDisplay<B> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+/// Serializes and deserializes an [`Amount`] as an integer number of minor units, e.g. `12345`
+/// for `$123.45`. For use with `#[serde(with = "currencies::serde::minor_units")]`.
+///
+/// Backings of up to 64 bits are written as `u64` or `i64` and those of up to 128 bits as
+/// `u128` or `i128`, depending on whether they are signed. Wider backings such as [`U256`]
+/// exceed the integers supported by most formats and are written as strings of digits instead.
+///
+/// Self-describing formats may supply any integer or string of digits when deserializing, as
+/// long as the value fits in the backing of the currency.
+pub mod minor_units {
+    use super::*;
+    use crate::parsing::is_signed;
+    use ::serde::ser::Error as _;
+    use core::mem::size_of;
+    use num_traits::ToPrimitive;
+
+    /// Serializes `amount` as an integer number of minor units.
+    pub fn serialize<C: Currency, Safety: safety::Safety, S: ::serde::Serializer>(
+        amount: &Amount<C, Safety>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let raw = amount.raw_backing();
+        let unrepresentable = || S::Error::custom("amount cannot be represented as a primitive integer");
+        match (size_of::<C::Backing>(), is_signed::<C::Backing>()) {
+            (0..=8, false) => serializer.serialize_u64(raw.to_u64().ok_or_else(unrepresentable)?),
+            (0..=8, true) => serializer.serialize_i64(raw.to_i64().ok_or_else(unrepresentable)?),
+            (9..=16, false) => serializer.serialize_u128(raw.to_u128().ok_or_else(unrepresentable)?),
+            (9..=16, true) => serializer.serialize_i128(raw.to_i128().ok_or_else(unrepresentable)?),
+            _ => serializer.collect_str(&raw),
+        }
+    }
+
+    /// Deserializes an [`Amount`] from an integer number of minor units.
+    pub fn deserialize<'de, C: Currency, Safety: safety::Safety, D: Deserializer<'de>>(deserializer: D) -> Result<Amount<C, Safety>, D::Error> {
+        let visitor = MinorUnitsVisitor(PhantomData);
+        if deserializer.is_human_readable() {
+            return deserializer.deserialize_any(visitor);
+        }
+        match (size_of::<C::Backing>(), is_signed::<C::Backing>()) {
+            (0..=8, false) => deserializer.deserialize_u64(visitor),
+            (0..=8, true) => deserializer.deserialize_i64(visitor),
+            (9..=16, false) => deserializer.deserialize_u128(visitor),
+            (9..=16, true) => deserializer.deserialize_i128(visitor),
+            _ => deserializer.deserialize_str(visitor),
+        }
+    }
+
+    struct MinorUnitsVisitor<C: Currency, Safety: safety::Safety>(PhantomData<(C, Safety)>);
+
+    impl<C: Currency, Safety: safety::Safety> MinorUnitsVisitor<C, Safety> {
+        fn from_magnitude<E: de::Error>(magnitude: U256, negative: bool) -> Result<Amount<C, Safety>, E> {
+            match u256_to_backing(magnitude, negative) {
+                Some(raw) => Ok(Amount::from_raw(raw)),
+                None => Err(E::custom(format_args!("amount does not fit in the backing of {}", C::CODE))),
+            }
+        }
+    }
+
+    impl<'de, C: Currency, Safety: safety::Safety> Visitor<'de> for MinorUnitsVisitor<C, Safety> {
+        type Value = Amount<C, Safety>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an integer number of minor units")
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            Self::from_magnitude(U256::from(v), false)
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            Self::from_magnitude(U256::from(v.unsigned_abs()), v < 0)
+        }
+
+        fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+            Self::from_magnitude(U256::from(v), false)
+        }
+
+        fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+            Self::from_magnitude(U256::from(v.unsigned_abs()), v < 0)
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            Amount::from_minor_str(v).map_err(de::Error::custom)
+        }
+    }
+}
+
+/// Serializes and deserializes an [`Amount`] as the fixed-width little-endian encoding of its
+/// raw [`Backing`] value (see [`LeBytes`]), written as a tuple of bytes. For use
+/// with `#[serde(with = "currencies::serde::le_bytes")]`.
+///
+/// This is the most compact representation for binary formats such as `bincode`, where it takes
+/// exactly as many bytes as the backing, e.g. 16 for [`DOT`](crate::currency::DOT) and 32 for
+/// [`ETH`](crate::currency::ETH).
+pub mod le_bytes {
+    use super::*;
+    use crate::encoding::LeBytes;
+    use ::serde::ser::SerializeTuple;
+
+    /// Serializes `amount` as a tuple of little-endian bytes.
+    pub fn serialize<C: Currency, Safety: safety::Safety, S: ::serde::Serializer>(
+        amount: &Amount<C, Safety>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        C::Backing: LeBytes,
+    {
+        let bytes = amount.to_le_bytes();
+        let mut tuple = serializer.serialize_tuple(bytes.as_ref().len())?;
+        for byte in bytes.as_ref() {
+            tuple.serialize_element(byte)?;
+        }
+        tuple.end()
+    }
+
+    /// Deserializes an [`Amount`] from a tuple of little-endian bytes.
+    pub fn deserialize<'de, C: Currency, Safety: safety::Safety, D: Deserializer<'de>>(deserializer: D) -> Result<Amount<C, Safety>, D::Error>
+    where
+        C::Backing: LeBytes,
+    {
+        let len = <C::Backing as LeBytes>::Bytes::default().as_ref().len();
+        deserializer.deserialize_tuple(len, LeBytesVisitor(PhantomData))
+    }
+
+    struct LeBytesVisitor<C: Currency, Safety: safety::Safety>(PhantomData<(C, Safety)>);
+
+    impl<'de, C: Currency, Safety: safety::Safety> Visitor<'de> for LeBytesVisitor<C, Safety>
+    where
+        C::Backing: LeBytes,
+    {
+        type Value = Amount<C, Safety>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            let len = <C::Backing as LeBytes>::Bytes::default().as_ref().len();
+            write!(formatter, "{} little-endian bytes", len)
+        }
+
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = <C::Backing as LeBytes>::Bytes::default();
+            for (i, byte) in bytes.as_mut().iter_mut().enumerate() {
+                *byte = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+            }
+            Ok(Amount::from_le_bytes(bytes))
+        }
+    }
+}
+
+/// Serializes and deserializes an [`Amount`] as a string containing a plain decimal number of
+/// major units without a currency symbol, e.g. `"123.45"` for `$123.45`. For use with
+/// `#[serde(with = "currencies::serde::decimal_string")]`.
+///
+/// Deserialization accepts anything [`Amount::from_decimal_str`] does, so the decimal point is
+/// optional.
+pub mod decimal_string {
+    use super::*;
+
+    /// Serializes `amount` as a decimal string.
+    pub fn serialize<C: Currency, Safety: safety::Safety, S: ::serde::Serializer>(
+        amount: &Amount<C, Safety>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        DecimalDisplay(amount.raw_backing(), C::BASE).serialize(serializer)
+    }
+
+    /// Deserializes an [`Amount`] from a decimal string.
+    pub fn deserialize<'de, C: Currency, Safety: safety::Safety, D: Deserializer<'de>>(deserializer: D) -> Result<Amount<C, Safety>, D::Error> {
+        deserializer.deserialize_str(DecimalVisitor(PhantomData))
+    }
+
+    pub(super) struct DecimalVisitor<C: Currency, Safety: safety::Safety>(pub(super) PhantomData<(C, Safety)>);
+
+    impl<'de, C: Currency, Safety: safety::Safety> Visitor<'de> for DecimalVisitor<C, Safety> {
+        type Value = Amount<C, Safety>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a decimal string such as \"123.45\"")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            Amount::from_decimal_str(v).map_err(de::Error::custom)
+        }
+    }
+
+    impl<'de, C: Currency, Safety: safety::Safety> de::DeserializeSeed<'de> for DecimalVisitor<C, Safety> {
+        type Value = Amount<C, Safety>;
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+            deserializer.deserialize_str(self)
+        }
+    }
+}
+
+/// Serializes and deserializes an [`Amount`] as an object holding a decimal string and the
+/// currency code, e.g. `{"amount":"123.45","currency":"USD"}` for `$123.45`. For use with
+/// `#[serde(with = "currencies::serde::object")]`.
+///
+/// Deserialization fails if `currency` is not the [`Currency::CODE`] of the expected currency.
+pub mod object {
+    use super::decimal_string::DecimalVisitor;
+    use super::*;
+    use ::serde::ser::SerializeStruct;
+
+    const FIELDS: &[&str] = &["amount", "currency"];
+
+    /// Serializes `amount` as an object.
+    pub fn serialize<C: Currency, Safety: safety::Safety, S: ::serde::Serializer>(
+        amount: &Amount<C, Safety>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Amount", 2)?;
+        state.serialize_field("amount", &DecimalDisplay(amount.raw_backing(), C::BASE))?;
+        state.serialize_field("currency", C::CODE)?;
+        state.end()
+    }
+
+    /// Deserializes an [`Amount`] from an object, verifying its currency code.
+    pub fn deserialize<'de, C: Currency, Safety: safety::Safety, D: Deserializer<'de>>(deserializer: D) -> Result<Amount<C, Safety>, D::Error> {
+        deserializer.deserialize_struct("Amount", FIELDS, ObjectVisitor(PhantomData))
+    }
+
+    enum Field {
+        Amount,
+        Currency,
+        Other,
+    }
+
+    impl<'de> Deserialize<'de> for Field {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct FieldVisitor;
+
+            impl Visitor<'_> for FieldVisitor {
+                type Value = Field;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("a field name")
+                }
+
+                fn visit_str<E: de::Error>(self, v: &str) -> Result<Field, E> {
+                    Ok(match v {
+                        "amount" => Field::Amount,
+                        "currency" => Field::Currency,
+                        _ => Field::Other,
+                    })
+                }
+            }
+
+            deserializer.deserialize_identifier(FieldVisitor)
+        }
+    }
+
+    struct ObjectVisitor<C: Currency, Safety: safety::Safety>(PhantomData<(C, Safety)>);
+
+    impl<'de, C: Currency, Safety: safety::Safety> Visitor<'de> for ObjectVisitor<C, Safety> {
+        type Value = Amount<C, Safety>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an object with `amount` and `currency` fields")
+        }
+
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let amount = seq
+                .next_element_seed(DecimalVisitor(PhantomData))?
+                .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            seq.next_element_seed(CodeSeed::<C>(PhantomData))?
+                .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+            Ok(amount)
+        }
+
+        fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut amount = None;
+            let mut currency = None;
+            while let Some(field) = map.next_key::<Field>()? {
+                match field {
+                    Field::Amount if amount.is_some() => return Err(de::Error::duplicate_field("amount")),
+                    Field::Amount => amount = Some(map.next_value_seed(DecimalVisitor(PhantomData))?),
+                    Field::Currency if currency.is_some() => return Err(de::Error::duplicate_field("currency")),
+                    Field::Currency => currency = Some(map.next_value_seed(CodeSeed::<C>(PhantomData))?),
+                    Field::Other => {
+                        map.next_value::<de::IgnoredAny>()?;
+                    }
+                }
+            }
+            currency.ok_or_else(|| de::Error::missing_field("currency"))?;
+            amount.ok_or_else(|| de::Error::missing_field("amount"))
+        }
+    }
+}
+
+/// Deserializes a currency code, failing unless it is the [`Currency::CODE`] of `C`, ignoring
+/// ASCII case.
+struct CodeSeed<C: Currency>(PhantomData<C>);
+
+impl<'de, C: Currency> de::DeserializeSeed<'de> for CodeSeed<C> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<C: Currency> Visitor<'_> for CodeSeed<C> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the currency code \"{}\"", C::CODE)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<(), E> {
+        match v.eq_ignore_ascii_case(C::CODE) {
+            true => Ok(()),
+            false => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+}
+
+/// Serializes the [`Currency`] `C` as its [`Currency::CODE`], as done by the types generated by
+/// [`define_currency!`](crate::define_currency).
+#[doc(hidden)]
+pub fn serialize_currency<C: Currency, S: ::serde::Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(C::CODE)
+}
+
+/// Deserializes a currency code, failing unless it is the [`Currency::CODE`] of `C`, ignoring
+/// ASCII case.
+#[doc(hidden)]
+pub fn deserialize_currency<'de, C: Currency, D: Deserializer<'de>>(deserializer: D) -> Result<(), D::Error> {
+    de::DeserializeSeed::deserialize(CodeSeed::<C>(PhantomData), deserializer)
+}
+
+const STYLES: &[&str] = &["PrefixAttached", "SuffixAttached", "PrefixSpaced", "SuffixSpaced"];
+
+/// Serializes a [`FormatStyle`] as the name of its variant, e.g. `"SuffixSpaced"`.
+impl Serialize for FormatStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(STYLES[*self as usize])
+    }
+}
+
+impl<'de> Deserialize<'de> for FormatStyle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct StyleVisitor;
+
+        impl Visitor<'_> for StyleVisitor {
+            type Value = FormatStyle;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a format style")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<FormatStyle, E> {
+                match v {
+                    "PrefixAttached" => Ok(FormatStyle::PrefixAttached),
+                    "SuffixAttached" => Ok(FormatStyle::SuffixAttached),
+                    "PrefixSpaced" => Ok(FormatStyle::PrefixSpaced),
+                    "SuffixSpaced" => Ok(FormatStyle::SuffixSpaced),
+                    _ => Err(E::unknown_variant(v, STYLES)),
+                }
+            }
+        }
+
+        deserializer.deserialize_str(StyleVisitor)
+    }
+}
+
+const INFO_FIELDS: &[&str] = &["code", "symbol", "proper_name", "style", "is_iso", "is_crypto", "base", "numeric_code"];
+
+/// Serializes a [`CurrencyInfo`] as an object with one field per [`Currency`] associated
+/// const, with the base as a decimal string, e.g. `{"code":"USD",...,"base":"100"}`.
+impl Serialize for CurrencyInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        use ::serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("CurrencyInfo", INFO_FIELDS.len())?;
+        state.serialize_field("code", self.code)?;
+        state.serialize_field("symbol", self.symbol)?;
+        state.serialize_field("proper_name", self.proper_name)?;
+        state.serialize_field("style", &self.style)?;
+        state.serialize_field("is_iso", &self.is_iso)?;
+        state.serialize_field("is_crypto", &self.is_crypto)?;
+        state.serialize_field("base", &Base(self.base))?;
+        state.serialize_field("numeric_code", &self.numeric_code)?;
+        state.end()
+    }
+}
+
+struct Base(U256);
+
+impl Serialize for Base {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.collect_str(&self.0)
+    }
+}
+
+/// Deserializes a [`CurrencyInfo`] by looking up its `code` in the [`registry`], since the
+/// strings it holds must be `'static`. Unknown codes are rejected.
+///
+/// Only `code` is required. Any other fields that are present must match the registered
+/// currency, so a stale or tampered snapshot is an error rather than silently replaced.
+impl<'de> Deserialize<'de> for CurrencyInfo {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("CurrencyInfo", INFO_FIELDS, CurrencyInfoVisitor)
+    }
+}
+
+/// Captures a string field of a [`CurrencyInfo`], since it can only be compared once the
+/// currency is known.
+struct TextVisitor;
+
+impl<'de> de::DeserializeSeed<'de> for TextVisitor {
+    type Value = StackBuffer;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<StackBuffer, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl Visitor<'_> for TextVisitor {
+    type Value = StackBuffer;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<StackBuffer, E> {
+        ValueVisitor::capture(v)
+    }
+}
+
+/// The fields of a [`CurrencyInfo`] other than `code`, as read from the input.
+#[derive(Default)]
+struct InfoFields {
+    symbol: Option<StackBuffer>,
+    proper_name: Option<StackBuffer>,
+    style: Option<FormatStyle>,
+    is_iso: Option<bool>,
+    is_crypto: Option<bool>,
+    base: Option<StackBuffer>,
+    numeric_code: Option<Option<u16>>,
+}
+
+impl InfoFields {
+    /// Checks every field that was present against the registered `currency`.
+    fn verify<E: de::Error>(self, currency: CurrencyInfo) -> Result<CurrencyInfo, E> {
+        let base = ValueVisitor::capture::<E>(currency.base)?;
+        let mismatch = [
+            ("symbol", self.symbol.is_some_and(|v| v.as_bytes() != currency.symbol.as_bytes())),
+            (
+                "proper_name",
+                self.proper_name.is_some_and(|v| v.as_bytes() != currency.proper_name.as_bytes()),
+            ),
+            ("style", self.style.is_some_and(|v| v != currency.style)),
+            ("is_iso", self.is_iso.is_some_and(|v| v != currency.is_iso)),
+            ("is_crypto", self.is_crypto.is_some_and(|v| v != currency.is_crypto)),
+            ("base", self.base.is_some_and(|v| v.as_bytes() != base.as_bytes())),
+            ("numeric_code", self.numeric_code.is_some_and(|v| v != currency.numeric_code)),
+        ]
+        .into_iter()
+        .find(|(_, mismatch)| *mismatch);
+        match mismatch {
+            Some((field, _)) => Err(E::custom(format_args!(
+                "field `{}` does not match the registered currency `{}`",
+                field, currency.code
+            ))),
+            None => Ok(currency),
+        }
+    }
+}
+
+/// Stores `value` in `slot`, failing if the field was already seen.
+fn set_once<T, E: de::Error>(slot: &mut Option<T>, field: &'static str, value: T) -> Result<(), E> {
+    match slot.replace(value) {
+        Some(_) => Err(E::duplicate_field(field)),
+        None => Ok(()),
+    }
+}
+
+struct CurrencyInfoVisitor;
+
+impl<'de> Visitor<'de> for CurrencyInfoVisitor {
+    type Value = CurrencyInfo;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an object with a `code` field")
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<CurrencyInfo, A::Error> {
+        let currency = seq
+            .next_element_seed(CodeVisitor(None))?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let fields = InfoFields {
+            symbol: Some(seq.next_element_seed(TextVisitor)?.ok_or_else(|| de::Error::invalid_length(1, &self))?),
+            proper_name: Some(seq.next_element_seed(TextVisitor)?.ok_or_else(|| de::Error::invalid_length(2, &self))?),
+            style: Some(seq.next_element()?.ok_or_else(|| de::Error::invalid_length(3, &self))?),
+            is_iso: Some(seq.next_element()?.ok_or_else(|| de::Error::invalid_length(4, &self))?),
+            is_crypto: Some(seq.next_element()?.ok_or_else(|| de::Error::invalid_length(5, &self))?),
+            base: Some(seq.next_element_seed(TextVisitor)?.ok_or_else(|| de::Error::invalid_length(6, &self))?),
+            numeric_code: Some(seq.next_element()?.ok_or_else(|| de::Error::invalid_length(7, &self))?),
+        };
+        fields.verify(currency)
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<CurrencyInfo, A::Error> {
+        let mut currency = None;
+        let mut fields = InfoFields::default();
+        while let Some(key) = map.next_key::<InfoField>()? {
+            match key {
+                InfoField::Code => set_once(&mut currency, "code", map.next_value_seed(CodeVisitor(None))?)?,
+                InfoField::Symbol => set_once(&mut fields.symbol, "symbol", map.next_value_seed(TextVisitor)?)?,
+                InfoField::ProperName => set_once(&mut fields.proper_name, "proper_name", map.next_value_seed(TextVisitor)?)?,
+                InfoField::Style => set_once(&mut fields.style, "style", map.next_value()?)?,
+                InfoField::IsIso => set_once(&mut fields.is_iso, "is_iso", map.next_value()?)?,
+                InfoField::IsCrypto => set_once(&mut fields.is_crypto, "is_crypto", map.next_value()?)?,
+                InfoField::Base => set_once(&mut fields.base, "base", map.next_value_seed(TextVisitor)?)?,
+                InfoField::NumericCode => set_once(&mut fields.numeric_code, "numeric_code", map.next_value()?)?,
+                InfoField::Other => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        fields.verify(currency.ok_or_else(|| de::Error::missing_field("code"))?)
+    }
+}
+
+enum InfoField {
+    Code,
+    Symbol,
+    ProperName,
+    Style,
+    IsIso,
+    IsCrypto,
+    Base,
+    NumericCode,
+    Other,
+}
+
+impl<'de> Deserialize<'de> for InfoField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+            type Value = InfoField;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a field name")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<InfoField, E> {
+                Ok(match v {
+                    "code" => InfoField::Code,
+                    "symbol" => InfoField::Symbol,
+                    "proper_name" => InfoField::ProperName,
+                    "style" => InfoField::Style,
+                    "is_iso" => InfoField::IsIso,
+                    "is_crypto" => InfoField::IsCrypto,
+                    "base" => InfoField::Base,
+                    "numeric_code" => InfoField::NumericCode,
+                    _ => InfoField::Other,
+                })
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+/// Serializes a [`DynAmount`] as an object tagged with its currency code, with the value as a
+/// plain decimal string, e.g. `{"currency":"DOT","value":"12.0000000004"}`.
+impl Serialize for DynAmount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        use ::serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("DynAmount", 2)?;
+        state.serialize_field("currency", self.currency().code)?;
+        state.serialize_field("value", &SignedDecimalDisplay(self))?;
+        state.end()
+    }
+}
+
+/// Displays the value of a [`DynAmount`] as a plain decimal number.
+struct SignedDecimalDisplay<'a>(&'a DynAmount);
+
+impl fmt::Display for SignedDecimalDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_negative() {
+            f.write_str("-")?;
+        }
+        crate::amount::fmt_decimal(f, self.0.raw_backing(), self.0.currency().base)
+    }
+}
+
+impl Serialize for SignedDecimalDisplay<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes a [`DynAmount`] from an object tagged with a currency code, such as
+/// `{"currency":"DOT","value":"12.0000000004"}`, in which the fields may appear in any order.
+///
+/// The currency is looked up by its [`Currency::CODE`] in the [`registry`],
+/// and unknown codes are rejected. The value is read in major units and may be a decimal
+/// string or, in human-readable formats, a number, with the same exactness rules as the
+/// [`Deserialize`] impl of [`Amount`].
+impl<'de> Deserialize<'de> for DynAmount {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_str(AmountVisitor::<C, Safety>::new())
+        deserializer.deserialize_struct("DynAmount", &["currency", "value"], DynAmountVisitor(None))
+    }
+}
+
+impl DynAmount {
+    /// Deserializes a [`DynAmount`] in the format of its [`Deserialize`] impl, but only
+    /// accepts the specified currencies, which need not be in the [`registry`].
+    pub fn deserialize_among<'de, D>(deserializer: D, currencies: &[CurrencyInfo]) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("DynAmount", &["currency", "value"], DynAmountVisitor(Some(currencies)))
+    }
+}
+
+/// Deserializes a value of a type generated by [`currency_set!`](crate::currency_set!), which
+/// only accepts the specified currencies.
+#[doc(hidden)]
+pub fn deserialize_currency_set<'de, T, D>(deserializer: D, currencies: &[CurrencyInfo]) -> Result<T, D::Error>
+where
+    T: TryFrom<DynAmount, Error = ConversionError>,
+    D: Deserializer<'de>,
+{
+    let amount = DynAmount::deserialize_among(deserializer, currencies)?;
+    T::try_from(amount).map_err(|err| de::Error::custom(format_args!("invalid {} amount: {}", amount.currency().code, err)))
+}
+
+/// Looks up the currency with the specified code among `currencies`, or in the
+/// [`registry`](crate::registry) if there are no restrictions.
+fn currency_by_code<E: de::Error>(code: &str, currencies: Option<&[CurrencyInfo]>) -> Result<CurrencyInfo, E> {
+    match currencies {
+        Some(currencies) => currencies
+            .iter()
+            .find(|currency| currency.code.eq_ignore_ascii_case(code))
+            .copied()
+            .ok_or_else(|| {
+                E::custom(format_args!(
+                    "unsupported currency code `{}`, expected one of {}",
+                    code,
+                    Codes(currencies)
+                ))
+            }),
+        None => crate::registry::by_code(code).ok_or_else(|| E::custom(format_args!("unknown currency code `{}`", code))),
+    }
+}
+
+/// Displays the codes of a list of currencies, separated by commas.
+struct Codes<'a>(&'a [CurrencyInfo]);
+
+impl fmt::Display for Codes<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, currency) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(currency.code)?;
+        }
+        Ok(())
+    }
+}
+
+/// Captures the currency code of a tagged [`DynAmount`], looking it up among the currencies it
+/// is restricted to, if any.
+struct CodeVisitor<'a>(Option<&'a [CurrencyInfo]>);
+
+impl<'de> de::DeserializeSeed<'de> for CodeVisitor<'_> {
+    type Value = CurrencyInfo;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<CurrencyInfo, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl Visitor<'_> for CodeVisitor<'_> {
+    type Value = CurrencyInfo;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a currency code")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<CurrencyInfo, E> {
+        currency_by_code(v, self.0)
+    }
+}
+
+/// Captures the value of a tagged [`DynAmount`] as text, since it can only be interpreted once
+/// the currency is known, which may come later.
+struct ValueVisitor;
+
+impl ValueVisitor {
+    fn capture<E: de::Error>(value: impl fmt::Display) -> Result<StackBuffer, E> {
+        let mut buffer = StackBuffer { bytes: [0; 400], len: 0 };
+        fmt::Write::write_fmt(&mut buffer, format_args!("{}", value)).map_err(|_| E::custom("amount value is too long"))?;
+        Ok(buffer)
+    }
+}
+
+impl<'de> de::DeserializeSeed<'de> for ValueVisitor {
+    type Value = StackBuffer;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<StackBuffer, D::Error> {
+        match deserializer.is_human_readable() {
+            true => deserializer.deserialize_any(self),
+            false => deserializer.deserialize_str(self),
+        }
+    }
+}
+
+impl Visitor<'_> for ValueVisitor {
+    type Value = StackBuffer;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a decimal number")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<StackBuffer, E> {
+        Self::capture(v)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<StackBuffer, E> {
+        Self::capture(v)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<StackBuffer, E> {
+        Self::capture(v)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<StackBuffer, E> {
+        Self::capture(v)
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<StackBuffer, E> {
+        Self::capture(v)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<StackBuffer, E> {
+        match v.is_finite() {
+            // see `AmountVisitor::visit_f64`
+            true => Self::capture(v),
+            false => Err(E::invalid_value(de::Unexpected::Float(v), &"a finite number")),
+        }
+    }
+}
+
+/// Visits a tagged [`DynAmount`], restricted to the specified currencies, if any.
+struct DynAmountVisitor<'a>(Option<&'a [CurrencyInfo]>);
+
+impl DynAmountVisitor<'_> {
+    /// Interprets the captured `value` as an amount of `currency`.
+    fn finish<E: de::Error>(currency: CurrencyInfo, value: StackBuffer) -> Result<DynAmount, E> {
+        let text = core::str::from_utf8(&value.bytes[..value.len]).map_err(E::custom)?;
+        let (negative, number) = match text.strip_prefix('-') {
+            Some(number) => (true, number),
+            None => (false, text),
+        };
+        let raw = crate::dynamic::parse_number(number, &currency)
+            .map_err(|err| E::custom(format_args!("invalid {} amount `{}`: {}", currency.code, text, err)))?;
+        let amount = DynAmount::from_raw(currency, raw);
+        Ok(if negative { -amount } else { amount })
+    }
+}
+
+impl<'de> Visitor<'de> for DynAmountVisitor<'_> {
+    type Value = DynAmount;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an object with `currency` and `value` fields")
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<DynAmount, A::Error> {
+        let currency = seq
+            .next_element_seed(CodeVisitor(self.0))?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let value = seq.next_element_seed(ValueVisitor)?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Self::finish(currency, value)
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<DynAmount, A::Error> {
+        let mut currency = None;
+        let mut value = None;
+        while let Some(key) = map.next_key::<TaggedField>()? {
+            match key {
+                TaggedField::Currency if currency.is_some() => return Err(de::Error::duplicate_field("currency")),
+                TaggedField::Currency => currency = Some(map.next_value_seed(CodeVisitor(self.0))?),
+                TaggedField::Value if value.is_some() => return Err(de::Error::duplicate_field("value")),
+                TaggedField::Value => value = Some(map.next_value_seed(ValueVisitor)?),
+                TaggedField::Other => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        let currency = currency.ok_or_else(|| de::Error::missing_field("currency"))?;
+        let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+        Self::finish(currency, value)
+    }
+}
+
+enum TaggedField {
+    Currency,
+    Value,
+    Other,
+}
+
+impl<'de> Deserialize<'de> for TaggedField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+            type Value = TaggedField;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a field name")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<TaggedField, E> {
+                Ok(match v {
+                    "currency" => TaggedField::Currency,
+                    "value" => TaggedField::Value,
+                    _ => TaggedField::Other,
+                })
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
     }
 }
 
@@ -95,3 +1076,308 @@ fn serialize_deserialize_eth() {
     let deserialized: Amount<ETH, Unchecked> = serde_json::from_str(&serialized).unwrap();
     assert_eq!(deserialized, amount);
 }
+
+#[cfg(test)]
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "::serde")]
+struct Representations {
+    #[serde(with = "crate::serde::minor_units")]
+    minor: Amount<USD>,
+    #[serde(with = "crate::serde::minor_units")]
+    signed_minor: Amount<SIGNEDUSD>,
+    #[serde(with = "crate::serde::minor_units")]
+    dot_minor: Amount<DOT>,
+    #[serde(with = "crate::serde::minor_units")]
+    eth_minor: Amount<ETH>,
+    #[serde(with = "crate::serde::decimal_string")]
+    decimal: Amount<EUR, Checked>,
+    #[serde(with = "crate::serde::decimal_string")]
+    signed_decimal: Amount<SIGNEDUSD>,
+    #[serde(with = "crate::serde::object")]
+    object: Amount<USD>,
+}
+
+#[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping, clippy::zero_prefixed_literal)]
+fn representations() -> Representations {
+    Representations {
+        minor: Amount::from_raw(123_45),
+        signed_minor: Amount::from_raw(-5_00),
+        dot_minor: Amount::from_raw(12_0000000004),
+        eth_minor: Amount::from_raw(123456789012345678901234567890u128.into()),
+        decimal: Amount::from_raw(123_45),
+        signed_decimal: Amount::from_raw(-0_05),
+        object: Amount::from_raw(123_45),
+    }
+}
+
+#[test]
+fn serialize_deserialize_representations() {
+    let value = representations();
+    let serialized = serde_json::to_string(&value).unwrap();
+    assert_eq!(
+        serialized,
+        concat!(
+            r#"{"minor":12345,"signed_minor":-500,"dot_minor":120000000004,"#,
+            r#""eth_minor":"123456789012345678901234567890","decimal":"123.45","signed_decimal":"-0.05","#,
+            r#""object":{"amount":"123.45","currency":"USD"}}"#
+        )
+    );
+    assert_eq!(serde_json::from_str::<Representations>(&serialized).unwrap(), value);
+
+    let bytes = bincode::serialize(&value).unwrap();
+    assert_eq!(bincode::deserialize::<Representations>(&bytes).unwrap(), value);
+}
+
+#[test]
+fn deserialize_representations_leniently() {
+    let json = concat!(
+        r#"{"minor":"12345","signed_minor":"-500","dot_minor":120000000004,"eth_minor":"123456789012345678901234567890","#,
+        r#""decimal":"123.45","signed_decimal":"-.05","object":{"note":[1,2],"currency":"USD","amount":"123.45"}}"#
+    );
+    assert_eq!(serde_json::from_str::<Representations>(json).unwrap(), representations());
+}
+
+#[test]
+fn deserialize_representation_errors() {
+    #[derive(Deserialize, Debug)]
+    #[serde(crate = "::serde")]
+    struct Minor(#[serde(with = "crate::serde::minor_units")] Amount<USD>);
+
+    #[derive(Deserialize, Debug)]
+    #[serde(crate = "::serde")]
+    struct Decimal(#[serde(with = "crate::serde::decimal_string")] Amount<USD>);
+
+    #[derive(Deserialize, Debug)]
+    #[serde(crate = "::serde")]
+    struct Object(#[serde(with = "crate::serde::object")] Amount<USD>);
+
+    assert_eq!(serde_json::from_str::<Minor>("42").unwrap().0, Amount::from_raw(42));
+    assert_eq!(serde_json::from_str::<Decimal>(r#""7""#).unwrap().0, Amount::from_raw(7_00));
+    let object = serde_json::from_str::<Object>(r#"[".5","USD"]"#).unwrap();
+    assert_eq!(object.0, Amount::from_raw(50));
+
+    let err = serde_json::from_str::<Minor>("-1").unwrap_err();
+    assert!(err.to_string().starts_with("amount does not fit in the backing of USD"));
+    assert!(serde_json::from_str::<Minor>("18446744073709551616").is_err());
+    assert!(serde_json::from_str::<Minor>("1.5").is_err());
+
+    let err = serde_json::from_str::<Decimal>(r#""1.234""#).unwrap_err();
+    assert!(err.to_string().starts_with("too many decimal digits"));
+    assert!(serde_json::from_str::<Decimal>(r#""$1.23""#).is_err());
+
+    let err = serde_json::from_str::<Object>(r#"{"amount":"1.23","currency":"EUR"}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with(r#"invalid value: string "EUR", expected the currency code "USD""#));
+    let err = serde_json::from_str::<Object>(r#"{"amount":"1.23"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("missing field `currency`"));
+    let err = serde_json::from_str::<Object>(r#"{"amount":"1.23","amount":"1","currency":"USD"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("duplicate field `amount`"));
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping, clippy::zero_prefixed_literal)]
+fn deserialize_from_numbers() {
+    assert_eq!(serde_json::from_str::<Amount<USD>>("1250").unwrap(), Amount::from_raw(1250_00));
+    assert_eq!(serde_json::from_str::<Amount<USD>>("12.5").unwrap(), Amount::from_raw(12_50));
+    assert_eq!(serde_json::from_str::<Amount<USD>>("0.1").unwrap(), Amount::from_raw(0_10));
+    assert_eq!(serde_json::from_str::<Amount<USD>>("-0.0").unwrap(), Amount::from_raw(0));
+    assert_eq!(serde_json::from_str::<Amount<USD>>("1e3").unwrap(), Amount::from_raw(1000_00));
+    assert_eq!(serde_json::from_str::<Amount<SIGNEDUSD>>("-7").unwrap(), Amount::from_raw(-7_00));
+    assert_eq!(serde_json::from_str::<Amount<SIGNEDUSD>>("-0.07").unwrap(), Amount::from_raw(-7));
+    assert_eq!(
+        serde_json::from_str::<Amount<ETH>>("18446744073709551615").unwrap(),
+        Amount::from_raw(U256::from(u64::MAX) * U256::from(10u64.pow(18)))
+    );
+
+    let err = serde_json::from_str::<Amount<USD>>("12.345").unwrap_err();
+    assert!(err.to_string().starts_with("12.345: too many decimal digits"));
+    let err = serde_json::from_str::<Amount<USD>>("-1").unwrap_err();
+    assert!(err.to_string().starts_with("-1 does not fit in an amount of USD"));
+    let err = serde_json::from_str::<Amount<USD>>("184467440737095517").unwrap_err();
+    assert!(err.to_string().starts_with("184467440737095517 does not fit in an amount of USD"));
+    assert!(serde_json::from_str::<Amount<USD>>("-12.5").is_err());
+    assert!(serde_json::from_str::<Amount<USD>>("1e300").is_err());
+    assert!(serde_json::from_str::<Amount<USD>>("true").is_err());
+}
+
+#[test]
+fn deserialize_binary_formats() {
+    let amount = Amount::<USD>::from_raw(12_50);
+
+    let bytes = bincode::serialize(&amount).unwrap();
+    assert_eq!(bincode::deserialize::<Amount<USD>>(&bytes).unwrap(), amount);
+
+    let mut bytes = alloc::vec::Vec::new();
+    ciborium::into_writer(&amount, &mut bytes).unwrap();
+    assert_eq!(ciborium::from_reader::<Amount<USD>, _>(&bytes[..]).unwrap(), amount);
+
+    #[derive(Deserialize, PartialEq, Debug)]
+    #[serde(crate = "::serde")]
+    struct Price(#[serde(with = "crate::serde::self_describing")] Amount<USD>);
+
+    for (value, expected) in [
+        (ciborium::Value::from(12.5), 12_50),
+        (ciborium::Value::from(12), 12_00),
+        (ciborium::Value::from("$0.25"), 25),
+    ] {
+        let mut bytes = alloc::vec::Vec::new();
+        ciborium::into_writer(&value, &mut bytes).unwrap();
+        assert_eq!(ciborium::from_reader::<Price, _>(&bytes[..]).unwrap(), Price(Amount::from_raw(expected)));
+    }
+    assert!(ciborium::from_reader::<Amount<USD>, _>(&[0xf9, 0x4a, 0x40][..]).is_err());
+}
+
+#[test]
+#[allow(clippy::zero_prefixed_literal)]
+fn serialize_deserialize_dyn_amount() {
+    let amounts = [
+        DynAmount::from(Amount::<DOT>::from_raw(12_0000000004)),
+        DynAmount::from(Amount::<USD>::from_raw(5_00)),
+        -DynAmount::from(Amount::<EUR>::from_raw(0_25)),
+        DynAmount::from(Amount::<ETH>::from_raw(123456789012345678901234567890u128.into())),
+    ];
+    let serialized = serde_json::to_string(&amounts).unwrap();
+    assert_eq!(
+        serialized,
+        concat!(
+            r#"[{"currency":"DOT","value":"12.0000000004"},{"currency":"USD","value":"5.00"},"#,
+            r#"{"currency":"EUR","value":"-0.25"},{"currency":"ETH","value":"123456789012.345678901234567890"}]"#
+        )
+    );
+    assert_eq!(serde_json::from_str::<[DynAmount; 4]>(&serialized).unwrap(), amounts);
+
+    let bytes = bincode::serialize(&amounts).unwrap();
+    assert_eq!(bincode::deserialize::<[DynAmount; 4]>(&bytes).unwrap(), amounts);
+}
+
+#[test]
+fn serialize_radix_amounts_as_decimals() {
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    #[serde(crate = "::serde")]
+    struct Ariary(#[serde(with = "crate::serde::decimal_string")] Amount<MGA>);
+
+    let amount = Amount::<MGA>::from_raw(17);
+    assert_eq!(serde_json::to_string(&Ariary(amount)).unwrap(), r#""3.4""#);
+    assert_eq!(serde_json::from_str::<Ariary>(r#""3.4""#).unwrap(), Ariary(amount));
+    let dynamic = DynAmount::from(amount);
+    assert_eq!(serde_json::to_string(&dynamic).unwrap(), r#"{"currency":"MGA","value":"3.4"}"#);
+    assert_eq!(serde_json::from_str::<DynAmount>(r#"{"currency":"MGA","value":"3.4"}"#).unwrap(), dynamic);
+}
+
+#[test]
+fn deserialize_dyn_amount_leniently() {
+    let amount: DynAmount = serde_json::from_str(r#"{"value":"12.0000000004","note":null,"currency":"DOT"}"#).unwrap();
+    assert_eq!(amount, DynAmount::from(Amount::<DOT>::from_raw(12_0000000004)));
+    let amount: DynAmount = serde_json::from_str(r#"{"currency":"usd","value":12.5}"#).unwrap();
+    assert_eq!(amount, DynAmount::from(Amount::<USD>::from_raw(12_50)));
+    let amount: DynAmount = serde_json::from_str(r#"["KWD",-3]"#).unwrap();
+    assert_eq!(amount, -DynAmount::from(Amount::<crate::currency::KWD>::from_raw(3_000)));
+}
+
+#[test]
+fn deserialize_dyn_amount_errors() {
+    let err = serde_json::from_str::<DynAmount>(r#"{"currency":"FOO","value":"1.00"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("unknown currency code `FOO`"));
+    let err = serde_json::from_str::<DynAmount>(r#"{"value":"1.001","currency":"USD"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("invalid USD amount `1.001`: too many decimal digits"));
+    let err = serde_json::from_str::<DynAmount>(r#"{"currency":"USD"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("missing field `value`"));
+    assert!(serde_json::from_str::<DynAmount>(r#"{"currency":"USD","value":"$1"}"#).is_err());
+    assert!(serde_json::from_str::<DynAmount>(r#"{"currency":"USD","value":true}"#).is_err());
+}
+
+#[test]
+fn serialize_deserialize_le_bytes() {
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    #[serde(crate = "::serde")]
+    struct Balances {
+        #[serde(with = "crate::serde::le_bytes")]
+        dot: Amount<DOT>,
+        #[serde(with = "crate::serde::le_bytes")]
+        eth: Amount<ETH>,
+    }
+
+    let balances = Balances {
+        dot: Amount::from_raw(12_0000000004),
+        eth: Amount::from_raw(U256::MAX_VALUE),
+    };
+    let bytes = bincode::serialize(&balances).unwrap();
+    assert_eq!(bytes.len(), 16 + 32);
+    assert_eq!(bytes[..16], 12_0000000004u128.to_le_bytes());
+    assert_eq!(bincode::deserialize::<Balances>(&bytes).unwrap(), balances);
+    assert!(bincode::deserialize::<Balances>(&bytes[..47]).is_err());
+
+    let json = serde_json::to_string(&balances).unwrap();
+    assert_eq!(serde_json::from_str::<Balances>(&json).unwrap(), balances);
+}
+
+#[test]
+fn serialize_deserialize_currency_types() {
+    #[derive(Serialize, Deserialize, PartialEq)]
+    #[serde(crate = "::serde")]
+    struct Config {
+        settlement_currency: EUR,
+    }
+
+    let config = Config { settlement_currency: EUR };
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(json, r#"{"settlement_currency":"EUR"}"#);
+    assert!(serde_json::from_str::<Config>(&json).unwrap() == config);
+    let err = serde_json::from_str::<Config>(r#"{"settlement_currency":"USD"}"#).err().unwrap();
+    assert!(err
+        .to_string()
+        .starts_with(r#"invalid value: string "USD", expected the currency code "EUR""#));
+    assert!(serde_json::from_str::<Config>(r#"{"settlement_currency":"eur"}"#).unwrap() == config);
+
+    assert_eq!(serde_json::to_string(&ETH).unwrap(), "\"ETH\"");
+    assert!(serde_json::from_str::<SIGNEDUSD>("\"SIGNEDUSD\"").is_ok());
+    let bytes = bincode::serialize(&config).unwrap();
+    assert!(bincode::deserialize::<Config>(&bytes).unwrap() == config);
+}
+
+#[test]
+fn serialize_deserialize_currency_info() {
+    let info = CurrencyInfo::of::<USD>();
+    let json = serde_json::to_string(&info).unwrap();
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"code":"USD","symbol":"$","proper_name":"United States Dollar","style":"PrefixAttached","#,
+            r#""is_iso":true,"is_crypto":false,"base":"100","numeric_code":840}"#
+        )
+    );
+    assert_eq!(serde_json::from_str::<CurrencyInfo>(&json).unwrap(), info);
+
+    let info = CurrencyInfo::of::<ETH>();
+    let json = serde_json::to_string(&info).unwrap();
+    assert!(json.ends_with(r#""style":"SuffixSpaced","is_iso":false,"is_crypto":true,"base":"1000000000000000000","numeric_code":null}"#));
+    assert_eq!(serde_json::from_str::<CurrencyInfo>(&json).unwrap(), info);
+    assert_eq!(
+        serde_json::from_str::<CurrencyInfo>(r#"{"base":"1000000000000000000","code":"ETH"}"#).unwrap(),
+        info
+    );
+    let bytes = bincode::serialize(&info).unwrap();
+    assert_eq!(bincode::deserialize::<CurrencyInfo>(&bytes).unwrap(), info);
+}
+
+#[test]
+fn deserialize_currency_info_errors() {
+    let err = serde_json::from_str::<CurrencyInfo>(r#"{"code":"FOO"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("unknown currency code `FOO`"));
+    let err = serde_json::from_str::<CurrencyInfo>(r#"{"symbol":"€","code":"USD"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("field `symbol` does not match the registered currency `USD`"));
+    let err = serde_json::from_str::<CurrencyInfo>(r#"{"code":"USD","base":"1000"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("field `base` does not match the registered currency `USD`"));
+    let err = serde_json::from_str::<CurrencyInfo>(r#"{"code":"USD","numeric_code":null}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("field `numeric_code` does not match the registered currency `USD`"));
+    let err = serde_json::from_str::<CurrencyInfo>(r#"{"code":"USD","style":"Sideways"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("unknown variant `Sideways`"));
+    let err = serde_json::from_str::<CurrencyInfo>(r#"{"code":"USD","code":"USD"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("duplicate field `code`"));
+    let err = serde_json::from_str::<CurrencyInfo>(r#"{"symbol":"$"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("missing field `code`"));
+}
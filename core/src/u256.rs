@@ -8,7 +8,7 @@ use core::{ops::*, str::FromStr};
 use num_integer::Integer;
 use num_traits::*;
 
-use crate::amount::TrailingZeros;
+use crate::amount::{Backing, TrailingZeros};
 
 /// Wraps [`primitive_types::U256`] enhancing it with some extra trait impls needed for
 /// currency manipulation.
@@ -59,6 +59,70 @@ pub const fn u64_to_u256(n: u64) -> U256 {
     U256(primitive_types::U256([n, 0, 0, 0]))
 }
 
+/// Const function constructing a [`U256`] from its little-endian 64-bit limbs, which is how
+/// the `amt!` macros write amounts of currencies backed by [`U256`].
+pub const fn limbs_to_u256(limbs: [u64; 4]) -> U256 {
+    U256(primitive_types::U256(limbs))
+}
+
+/// Const function computing `10^exp` as a [`U256`], useful for specifying
+/// [`Currency::BASE`](crate::Currency::BASE) for currencies with more decimal places than a
+/// [`u64`] can express, such as `u256_pow10(18)` for one ether in wei.
+///
+/// Panics (at compile time, when used in a constant) if `exp` is greater than 77.
+pub const fn u256_pow10(exp: u32) -> U256 {
+    let mut limbs = [1u64, 0, 0, 0];
+    let mut i = 0;
+    while i < exp {
+        let mut carry = 0u128;
+        let mut j = 0;
+        while j < 4 {
+            let product = limbs[j] as u128 * 10 + carry;
+            limbs[j] = product as u64;
+            carry = product >> 64;
+            j += 1;
+        }
+        assert!(carry == 0, "10^exp does not fit in a U256");
+        i += 1;
+    }
+    U256(primitive_types::U256(limbs))
+}
+
+/// Losslessly widens the magnitude (absolute value) of any [`Backing`] value (at most 256 bits
+/// wide) into a [`U256`].
+pub(crate) fn backing_to_u256<B: Backing>(mut value: B) -> U256 {
+    let radix = B::from(1 << 16);
+    let mut result = U256::zero();
+    let mut shift = 0;
+    while !value.is_zero() {
+        // for negative values the remainder is negative as well
+        let chunk = value % radix;
+        let chunk = chunk.to_u64().unwrap_or_else(|| (B::zero() - chunk).to_u64().unwrap());
+        result = result | (U256::from(chunk) << shift);
+        value /= radix;
+        shift += 16;
+    }
+    result
+}
+
+/// Narrows a [`U256`] magnitude into the [`Backing`] `B`, negating it if `negative` is set.
+///
+/// Returns [`None`] if the result does not fit, which includes any non-zero negative value
+/// when `B` is unsigned.
+pub(crate) fn u256_to_backing<B: Backing>(value: U256, negative: bool) -> Option<B> {
+    let radix = B::from(1 << 16);
+    let mut result = B::zero();
+    for i in (0..16).rev() {
+        let chunk = B::from((value.0 >> (i * 16)).low_u32() & 0xffff);
+        result = result.checked_mul(&radix)?;
+        result = match negative {
+            true => result.checked_sub(&chunk)?,
+            false => result.checked_add(&chunk)?,
+        };
+    }
+    Some(result)
+}
+
 impl Zero for U256 {
     fn zero() -> Self {
         U256(primitive_types::U256([0, 0, 0, 0]))
@@ -151,37 +215,31 @@ impl Rem for U256 {
 
 impl CheckedAdd for U256 {
     fn checked_add(&self, rhs: &Self) -> Option<Self> {
-        match self.0.checked_add(rhs.0) {
-            Some(val) => Some(U256(val)),
-            None => None,
-        }
+        self.0.checked_add(rhs.0).map(U256)
     }
 }
 
 impl CheckedSub for U256 {
     fn checked_sub(&self, rhs: &Self) -> Option<Self> {
-        match self.0.checked_sub(rhs.0) {
-            Some(val) => Some(U256(val)),
-            None => None,
-        }
+        self.0.checked_sub(rhs.0).map(U256)
     }
 }
 
 impl CheckedDiv for U256 {
     fn checked_div(&self, rhs: &Self) -> Option<Self> {
-        match self.0.checked_div(rhs.0) {
-            Some(val) => Some(U256(val)),
-            None => None,
-        }
+        self.0.checked_div(rhs.0).map(U256)
+    }
+}
+
+impl CheckedRem for U256 {
+    fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        self.0.checked_rem(rhs.0).map(U256)
     }
 }
 
 impl CheckedMul for U256 {
     fn checked_mul(&self, rhs: &Self) -> Option<Self> {
-        match self.0.checked_mul(rhs.0) {
-            Some(val) => Some(U256(val)),
-            None => None,
-        }
+        self.0.checked_mul(rhs.0).map(U256)
     }
 }
 
@@ -605,3 +663,36 @@ fn test_div_mod_floor_extended() {
     assert_eq!(quotient, U256::from(1)); // This should be 1 because value is just 1 BASE + some remainder.
     assert_eq!(remainder, U256::from(123_456_789_123_456_789u128));
 }
+
+#[test]
+fn test_backing_conversions() {
+    assert_eq!(backing_to_u256(0u64), U256::zero());
+    assert_eq!(backing_to_u256(u64::MAX), U256::from(u64::MAX));
+    assert_eq!(backing_to_u256(u128::MAX), U256::from(u128::MAX));
+    assert_eq!(backing_to_u256(U256::MAX_VALUE), U256::MAX_VALUE);
+    assert_eq!(backing_to_u256(-1i64), U256::one());
+    assert_eq!(backing_to_u256(i64::MIN), U256::from(i64::MAX) + U256::one());
+    assert_eq!(backing_to_u256(i128::MIN), U256::from(i128::MAX) + U256::one());
+    assert_eq!(u256_to_backing::<u64>(U256::from(u64::MAX), false), Some(u64::MAX));
+    assert_eq!(u256_to_backing::<u64>(U256::from(u64::MAX) + U256::one(), false), None);
+    assert_eq!(u256_to_backing::<u128>(U256::from(u128::MAX), false), Some(u128::MAX));
+    assert_eq!(u256_to_backing::<U256>(U256::MAX_VALUE, false), Some(U256::MAX_VALUE));
+    assert_eq!(u256_to_backing::<u64>(U256::one(), true), None);
+    assert_eq!(u256_to_backing::<u64>(U256::zero(), true), Some(0));
+    assert_eq!(u256_to_backing::<i64>(U256::from(i64::MAX) + U256::one(), true), Some(i64::MIN));
+    assert_eq!(u256_to_backing::<i64>(U256::from(i64::MAX) + U256::one(), false), None);
+}
+
+#[test]
+fn test_u256_pow10() {
+    assert_eq!(u256_pow10(0), U256::one());
+    assert_eq!(u256_pow10(18), u64_to_u256(1_000000000000000000));
+    assert_eq!(u256_pow10(38), U256::from(10u128.pow(38)));
+    assert_eq!(format!("{}", u256_pow10(77)), format!("1{:077}", 0));
+}
+
+#[test]
+#[should_panic(expected = "10^exp does not fit in a U256")]
+fn test_u256_pow10_overflow() {
+    u256_pow10(78);
+}
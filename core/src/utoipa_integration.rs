@@ -0,0 +1,202 @@
+use std::borrow::Cow;
+
+use utoipa::openapi::extensions::{Extensions, ExtensionsBuilder};
+use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder, Schema, SchemaFormat, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::amount::Amount;
+use crate::currency::Currency;
+use crate::encoding;
+use crate::parsing::is_signed;
+use crate::safety;
+use crate::schema::{self, DecimalString, LeBytes, MinorUnits, Object};
+
+/// Returns the [`Currency::CODE`] and [`Currency::decimal_digits`] of `C` as the `x-currency`
+/// and `x-decimal-digits` extensions.
+fn metadata<C: Currency>() -> Option<Extensions> {
+    Some(
+        ExtensionsBuilder::new()
+            .add("x-currency", C::CODE)
+            .add("x-decimal-digits", C::decimal_digits())
+            .build(),
+    )
+}
+
+/// Describes an [`Amount`] as serialized by its [`Serialize`](::serde::Serialize) impl: a
+/// string with the symbol of its [`Currency`], such as `"$123.45"`.
+impl<C: Currency, Safety: safety::Safety> PartialSchema for Amount<C, Safety> {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .pattern(Some(schema::display_pattern::<C>()))
+            .description(Some(schema::description::<C>("a string with its symbol")))
+            .examples([schema::display_example::<C>()])
+            .extensions(metadata::<C>())
+            .into()
+    }
+}
+
+impl<C: Currency, Safety: safety::Safety> ToSchema for Amount<C, Safety> {
+    fn name() -> Cow<'static, str> {
+        schema::name::<C>("")
+    }
+}
+
+/// Describes an [`Amount`] as serialized by
+/// [`minor_units`](crate::serde_integration::minor_units): an integer, or a string of digits
+/// for backings wider than 128 bits.
+impl<C: Currency> PartialSchema for MinorUnits<C> {
+    fn schema() -> RefOr<Schema> {
+        let (integer_example, digits_example) = schema::minor_units_example::<C>();
+        let builder = ObjectBuilder::new()
+            .description(Some(schema::description::<C>("a number of minor units")))
+            .extensions(metadata::<C>());
+        let builder = match schema::minor_units_format::<C>() {
+            Some(format) => builder
+                .schema_type(Type::Integer)
+                .format(Some(SchemaFormat::Custom(format.into())))
+                .minimum((!is_signed::<C::Backing>()).then_some(0))
+                .examples(integer_example),
+            None => builder
+                .schema_type(Type::String)
+                .pattern(Some(schema::digits_pattern::<C>()))
+                .examples([digits_example]),
+        };
+        builder.into()
+    }
+}
+
+impl<C: Currency> ToSchema for MinorUnits<C> {
+    fn name() -> Cow<'static, str> {
+        schema::name::<C>("MinorUnits")
+    }
+}
+
+/// Describes an [`Amount`] as serialized by
+/// [`decimal_string`](crate::serde_integration::decimal_string), such as `"123.45"`.
+impl<C: Currency> PartialSchema for DecimalString<C> {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .pattern(Some(schema::decimal_pattern::<C>()))
+            .description(Some(schema::description::<C>("a decimal number of major units")))
+            .examples([schema::decimal_example::<C>()])
+            .extensions(metadata::<C>())
+            .into()
+    }
+}
+
+impl<C: Currency> ToSchema for DecimalString<C> {
+    fn name() -> Cow<'static, str> {
+        schema::name::<C>("DecimalString")
+    }
+}
+
+/// Describes an [`Amount`] as serialized by [`object`](crate::serde_integration::object), such
+/// as `{"amount":"123.45","currency":"USD"}`.
+impl<C: Currency> PartialSchema for Object<C> {
+    fn schema() -> RefOr<Schema> {
+        let example = serde_json::json!({ "amount": schema::decimal_example::<C>(), "currency": C::CODE });
+        ObjectBuilder::new()
+            .schema_type(Type::Object)
+            .property("amount", DecimalString::<C>::schema())
+            .property("currency", ObjectBuilder::new().schema_type(Type::String).enum_values(Some([C::CODE])))
+            .required("amount")
+            .required("currency")
+            .description(Some(schema::description::<C>("an object with its currency code")))
+            .examples([example])
+            .extensions(metadata::<C>())
+            .into()
+    }
+}
+
+impl<C: Currency> ToSchema for Object<C> {
+    fn name() -> Cow<'static, str> {
+        schema::name::<C>("Object")
+    }
+}
+
+/// Describes an [`Amount`] as serialized by [`le_bytes`](crate::serde_integration::le_bytes):
+/// an array of as many bytes as the backing of its [`Currency`] has.
+impl<C: Currency> PartialSchema for LeBytes<C>
+where
+    C::Backing: encoding::LeBytes,
+{
+    fn schema() -> RefOr<Schema> {
+        let len = schema::le_bytes_len::<C>();
+        ArrayBuilder::new()
+            .items(ObjectBuilder::new().schema_type(Type::Integer).minimum(Some(0)).maximum(Some(255)))
+            .min_items(Some(len))
+            .max_items(Some(len))
+            .description(Some(schema::description::<C>("the little-endian bytes of its number of minor units")))
+            .extensions(metadata::<C>())
+            .into()
+    }
+}
+
+impl<C: Currency> ToSchema for LeBytes<C>
+where
+    C::Backing: encoding::LeBytes,
+{
+    fn name() -> Cow<'static, str> {
+        schema::name::<C>("LeBytes")
+    }
+}
+
+#[cfg(test)]
+use crate::currency::{ETH, EUR, JPY, SIGNEDUSD, USD};
+
+#[cfg(test)]
+use serde_json::json;
+
+#[cfg(test)]
+fn schema_value<T: PartialSchema>() -> serde_json::Value {
+    serde_json::to_value(T::schema()).unwrap()
+}
+
+#[test]
+fn test_amount_openapi_schema() {
+    assert_eq!(<Amount<USD> as ToSchema>::name(), "AmountUSD");
+    assert_eq!(
+        schema_value::<Amount<USD>>(),
+        json!({
+            "type": "string",
+            "pattern": "^\\$[0-9]+\\.[0-9]{2}$",
+            "description": "An amount of United States Dollar (USD) written as a string with its symbol",
+            "examples": ["$123.50"],
+            "x-currency": "USD",
+            "x-decimal-digits": 2,
+        })
+    );
+    assert_eq!(schema_value::<Amount<EUR>>()["pattern"], "^[0-9]+\\.[0-9]{2}€$");
+    assert_eq!(schema_value::<Amount<SIGNEDUSD>>()["pattern"], "^-?\\$[0-9]+\\.[0-9]{2}$");
+    assert_eq!(schema_value::<Amount<JPY>>()["pattern"], "^[0-9]+¥$");
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)]
+fn test_representation_openapi_schemas() {
+    let schema = schema_value::<MinorUnits<USD>>();
+    assert_eq!(schema["type"], "integer");
+    assert_eq!(schema["format"], "uint64");
+    assert_eq!(schema["minimum"], 0);
+    assert_eq!(schema["examples"], json!([123_50]));
+    assert_eq!(schema_value::<MinorUnits<SIGNEDUSD>>().get("minimum"), None);
+    let schema = schema_value::<MinorUnits<ETH>>();
+    assert_eq!(schema["type"], "string");
+    assert_eq!(schema["examples"], json!(["123500000000000000000"]));
+
+    assert_eq!(schema_value::<DecimalString<SIGNEDUSD>>()["pattern"], "^-?[0-9]+\\.[0-9]{2}$");
+
+    let schema = schema_value::<Object<USD>>();
+    assert_eq!(schema["properties"]["amount"]["pattern"], "^[0-9]+\\.[0-9]{2}$");
+    assert_eq!(schema["properties"]["currency"], json!({ "type": "string", "enum": ["USD"] }));
+    assert_eq!(schema["required"], json!(["amount", "currency"]));
+
+    let schema = schema_value::<LeBytes<ETH>>();
+    assert_eq!(schema["type"], "array");
+    assert_eq!(schema["minItems"], 32);
+    assert_eq!(schema["maxItems"], 32);
+    assert_eq!(schema["x-currency"], "ETH");
+}
@@ -1,21 +1,71 @@
-use currencies_core::{
-    currency::*,
-    safety::{Checked, Unchecked},
-    Currency, ParsedAmount,
-};
 use derive_syn_parse::Parse;
 use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
-use syn::{parse2, Error, Ident, LitStr, Result, Token};
+use proc_macro2::{Delimiter, Spacing, TokenStream as TokenStream2, TokenTree};
+use quote::{quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse2, BinOp, DeriveInput, Error, Expr, Ident, Lit, LitInt, LitStr, Result, Token, Type, UnOp,
+};
 
 #[derive(Parse)]
 struct AmountInput {
     currency: Ident,
     _comma: Token![,],
-    amount: LitStr,
+    amount: AmountLiteral,
+}
+
+/// The amount passed to `amt!`: either a string such as `"$3.24"` or `"3.24 USD"`, or a
+/// numeric literal such as `3.24` or `-5`, whose digits are kept as a string to be parsed
+/// exactly.
+struct AmountLiteral {
+    text: LitStr,
+    numeric: bool,
 }
 
+impl Parse for AmountLiteral {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(LitStr) {
+            return Ok(AmountLiteral {
+                text: input.parse()?,
+                numeric: false,
+            });
+        }
+        let minus: Option<Token![-]> = input.parse()?;
+        let (digits, suffix, span) = match input.parse::<Lit>()? {
+            Lit::Int(lit) => (lit.base10_digits().to_string(), lit.suffix().to_string(), lit.span()),
+            Lit::Float(lit) => (lit.base10_digits().to_string(), lit.suffix().to_string(), lit.span()),
+            lit => return Err(Error::new(lit.span(), "expected a string or numeric amount")),
+        };
+        if !suffix.is_empty() {
+            return Err(Error::new(span, "numeric amounts must not have a type suffix"));
+        }
+        let sign = if minus.is_some() { "-" } else { "" };
+        Ok(AmountLiteral {
+            text: LitStr::new(&format!("{}{}", sign, digits), span),
+            numeric: true,
+        })
+    }
+}
+
+/// Parses an amount of the currency `currency`, written in one of these forms:
+/// - a string in the format produced by the `Display` impl of `Amount`, such as `"$3.24"`;
+/// - a string holding a decimal number followed by a space and the currency code, such as
+///   `"3.24 USD"`;
+/// - a numeric literal, such as `3.24` or `-5`, which is read as a number of major units.
+///
+/// The expansion only calls `const fn`s, so it can initialize a `const` for any currency
+/// backed by `u32`, `u64`, `u128`, `i64`, `i128` or `U256`. Invalid amounts, including those
+/// that do not fit in the backing of the currency, are compile errors:
+///
+/// ```ignore
+/// const FEE: Amount<USD> = amt!(USD, "$0.30");
+/// const GAS: Amount<ETH> = amt!(ETH, "0.000021 ETH");
+/// assert_eq!(amt!(USD, 3.24), amt!(USD, "3.24 USD"));
+/// ```
+///
+/// The currency may also be one defined outside of this crate, including one that shares the
+/// name of a built-in currency, as the amount is always parsed as an amount of the specified
+/// type.
 #[proc_macro]
 pub fn amt(input: TokenStream) -> TokenStream {
     match amt_internal::<false>(input) {
@@ -24,6 +74,7 @@ pub fn amt(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Like [`amt!`], but produces an `Amount` with `Checked` safety.
 #[proc_macro]
 pub fn amt_checked(input: TokenStream) -> TokenStream {
     match amt_internal::<true>(input) {
@@ -32,224 +83,475 @@ pub fn amt_checked(input: TokenStream) -> TokenStream {
     }
 }
 
-fn filter_error(err: impl ToString) -> String {
-    format!("invalid amount: {}", err.to_string().trim_start_matches("error: "))
+fn safety_tokens<const SAFE: bool>() -> TokenStream2 {
+    match SAFE {
+        true => quote!(::currencies::safety::Checked),
+        false => quote!(::currencies::safety::Unchecked),
+    }
 }
 
-fn parse_amount<C: Currency, const SAFE: bool>(amount: &LitStr, currency_ident: &Ident) -> Result<TokenStream2> {
+/// Emits code that parses `amount` as an amount of `currency_ident` in a constant. An invalid
+/// literal makes the constant panic, which the compiler reports as an error at the literal.
+fn parse_amount_const<const SAFE: bool>(amount: &AmountLiteral, currency_ident: &Ident) -> TokenStream2 {
     let krate = quote!(::currencies);
-    if SAFE {
-        let amount: ParsedAmount<C, Checked> = amount.value().parse().map_err(|err| Error::new(amount.span(), filter_error(err)))?;
-        let amount = amount.amount;
-        let amount: TokenStream2 = format!("{:?}", amount.raw_backing()).parse().unwrap();
-        Ok(quote! {
-            #krate::Amount::<#currency_ident, #krate::safety::Checked>::from_raw(#amount)
-        })
-    } else {
-        let amount: ParsedAmount<C, Unchecked> = amount.value().parse().map_err(|err| Error::new(amount.span(), filter_error(err)))?;
-        let amount = amount.amount;
-        let amount: TokenStream2 = format!("{:?}", amount.raw_backing()).parse().unwrap();
-        Ok(quote! {
-            #krate::Amount::<#currency_ident, #krate::safety::Unchecked>::from_raw(#amount)
-        })
-    }
+    let safety = safety_tokens::<SAFE>();
+    let (text, numeric) = (&amount.text, amount.numeric);
+    let backing = quote!(<#currency_ident as #krate::Currency>::Backing);
+    let raw = quote_spanned! {text.span()=>
+        const RAW: #backing = match <#backing as #krate::literal::FromLiteral<#currency_ident, Text>>::PARSED {
+            ::core::result::Result::Ok(raw) => raw,
+            ::core::result::Result::Err(kind) => ::core::panic!("{}", #krate::literal::message(kind)),
+        };
+    };
+    quote! {{
+        struct Text;
+        impl #krate::literal::Literal for Text {
+            const TEXT: &'static str = #text;
+            const NUMERIC: bool = #numeric;
+        }
+        #raw
+        #krate::Amount::<#currency_ident, #safety>::from_raw(RAW)
+    }}
 }
 
 fn amt_internal<const SAFE: bool>(tokens: impl Into<TokenStream2>) -> Result<TokenStream2> {
     let input = parse2::<AmountInput>(tokens.into())?;
     let currency = input.currency;
     let amount = input.amount;
-    let output = match currency.to_string().to_uppercase().as_str() {
-        "USDC" => parse_amount::<USDC, SAFE>(&amount, &currency)?,
-        "BTC" => parse_amount::<BTC, SAFE>(&amount, &currency)?,
-        "ETH" => parse_amount::<ETH, SAFE>(&amount, &currency)?,
-        "SOL" => parse_amount::<SOL, SAFE>(&amount, &currency)?,
-        "ALGO" => parse_amount::<SOL, SAFE>(&amount, &currency)?,
-        "ORCA" => parse_amount::<SOL, SAFE>(&amount, &currency)?,
-        "AVAX" => parse_amount::<SOL, SAFE>(&amount, &currency)?,
-        "ZEC" => parse_amount::<SOL, SAFE>(&amount, &currency)?,
-        "XMR" => parse_amount::<SOL, SAFE>(&amount, &currency)?,
-        "DOGE" => parse_amount::<SOL, SAFE>(&amount, &currency)?,
-        "LTC" => parse_amount::<SOL, SAFE>(&amount, &currency)?,
-        "MATIC" => parse_amount::<SOL, SAFE>(&amount, &currency)?,
-        "XLM" => parse_amount::<SOL, SAFE>(&amount, &currency)?,
-        "TAO" => parse_amount::<SOL, SAFE>(&amount, &currency)?,
-        "NEAR" => parse_amount::<SOL, SAFE>(&amount, &currency)?,
-        "AAVE" => parse_amount::<AAVE, SAFE>(&amount, &currency)?,
-        "ADA" => parse_amount::<ADA, SAFE>(&amount, &currency)?,
-        "BOOK" => parse_amount::<BOOK, SAFE>(&amount, &currency)?,
-        "DOT" => parse_amount::<DOT, SAFE>(&amount, &currency)?,
-        "KSM" => parse_amount::<KSM, SAFE>(&amount, &currency)?,
-        "USD" => parse_amount::<USD, SAFE>(&amount, &currency)?,
-        "BAM" => parse_amount::<BAM, SAFE>(&amount, &currency)?,
-        "AED" => parse_amount::<AED, SAFE>(&amount, &currency)?,
-        "AFN" => parse_amount::<AFN, SAFE>(&amount, &currency)?,
-        "ALL" => parse_amount::<ALL, SAFE>(&amount, &currency)?,
-        "AMD" => parse_amount::<AMD, SAFE>(&amount, &currency)?,
-        "ANG" => parse_amount::<ANG, SAFE>(&amount, &currency)?,
-        "AOA" => parse_amount::<AOA, SAFE>(&amount, &currency)?,
-        "ARS" => parse_amount::<ARS, SAFE>(&amount, &currency)?,
-        "AUD" => parse_amount::<AUD, SAFE>(&amount, &currency)?,
-        "AWG" => parse_amount::<AWG, SAFE>(&amount, &currency)?,
-        "AZN" => parse_amount::<AZN, SAFE>(&amount, &currency)?,
-        "BBD" => parse_amount::<BBD, SAFE>(&amount, &currency)?,
-        "BDT" => parse_amount::<BDT, SAFE>(&amount, &currency)?,
-        "BGN" => parse_amount::<BGN, SAFE>(&amount, &currency)?,
-        "BHD" => parse_amount::<BHD, SAFE>(&amount, &currency)?,
-        "BIF" => parse_amount::<BIF, SAFE>(&amount, &currency)?,
-        "BMD" => parse_amount::<BMD, SAFE>(&amount, &currency)?,
-        "BND" => parse_amount::<BND, SAFE>(&amount, &currency)?,
-        "BOB" => parse_amount::<BOB, SAFE>(&amount, &currency)?,
-        "BOV" => parse_amount::<BOV, SAFE>(&amount, &currency)?,
-        "BRL" => parse_amount::<BRL, SAFE>(&amount, &currency)?,
-        "BSD" => parse_amount::<BSD, SAFE>(&amount, &currency)?,
-        "BTN" => parse_amount::<BTN, SAFE>(&amount, &currency)?,
-        "BWP" => parse_amount::<BWP, SAFE>(&amount, &currency)?,
-        "BYN" => parse_amount::<BYN, SAFE>(&amount, &currency)?,
-        "BZD" => parse_amount::<BZD, SAFE>(&amount, &currency)?,
-        "CAD" => parse_amount::<CAD, SAFE>(&amount, &currency)?,
-        "CDF" => parse_amount::<CDF, SAFE>(&amount, &currency)?,
-        "CHE" => parse_amount::<CHE, SAFE>(&amount, &currency)?,
-        "CHF" => parse_amount::<CHF, SAFE>(&amount, &currency)?,
-        "CHW" => parse_amount::<CHW, SAFE>(&amount, &currency)?,
-        "CLF" => parse_amount::<CLF, SAFE>(&amount, &currency)?,
-        "CLP" => parse_amount::<CLP, SAFE>(&amount, &currency)?,
-        "COP" => parse_amount::<COP, SAFE>(&amount, &currency)?,
-        "COU" => parse_amount::<COU, SAFE>(&amount, &currency)?,
-        "CRC" => parse_amount::<CRC, SAFE>(&amount, &currency)?,
-        "CUC" => parse_amount::<CUC, SAFE>(&amount, &currency)?,
-        "CUP" => parse_amount::<CUP, SAFE>(&amount, &currency)?,
-        "CVE" => parse_amount::<CVE, SAFE>(&amount, &currency)?,
-        "CZK" => parse_amount::<CZK, SAFE>(&amount, &currency)?,
-        "DJF" => parse_amount::<DJF, SAFE>(&amount, &currency)?,
-        "DKK" => parse_amount::<DKK, SAFE>(&amount, &currency)?,
-        "DOP" => parse_amount::<DOP, SAFE>(&amount, &currency)?,
-        "DZD" => parse_amount::<DZD, SAFE>(&amount, &currency)?,
-        "EGP" => parse_amount::<EGP, SAFE>(&amount, &currency)?,
-        "ERN" => parse_amount::<ERN, SAFE>(&amount, &currency)?,
-        "ETB" => parse_amount::<ETB, SAFE>(&amount, &currency)?,
-        "EUR" => parse_amount::<EUR, SAFE>(&amount, &currency)?,
-        "FJD" => parse_amount::<FJD, SAFE>(&amount, &currency)?,
-        "FKP" => parse_amount::<FKP, SAFE>(&amount, &currency)?,
-        "GBP" => parse_amount::<GBP, SAFE>(&amount, &currency)?,
-        "GEL" => parse_amount::<GEL, SAFE>(&amount, &currency)?,
-        "GHS" => parse_amount::<GHS, SAFE>(&amount, &currency)?,
-        "GIP" => parse_amount::<GIP, SAFE>(&amount, &currency)?,
-        "GMD" => parse_amount::<GMD, SAFE>(&amount, &currency)?,
-        "GNF" => parse_amount::<GNF, SAFE>(&amount, &currency)?,
-        "GTQ" => parse_amount::<GTQ, SAFE>(&amount, &currency)?,
-        "HKD" => parse_amount::<HKD, SAFE>(&amount, &currency)?,
-        "HNL" => parse_amount::<HNL, SAFE>(&amount, &currency)?,
-        "HTG" => parse_amount::<HTG, SAFE>(&amount, &currency)?,
-        "HUF" => parse_amount::<HUF, SAFE>(&amount, &currency)?,
-        "IDR" => parse_amount::<IDR, SAFE>(&amount, &currency)?,
-        "ILS" => parse_amount::<ILS, SAFE>(&amount, &currency)?,
-        "INR" => parse_amount::<INR, SAFE>(&amount, &currency)?,
-        "IQD" => parse_amount::<IQD, SAFE>(&amount, &currency)?,
-        "IRR" => parse_amount::<IRR, SAFE>(&amount, &currency)?,
-        "ISK" => parse_amount::<ISK, SAFE>(&amount, &currency)?,
-        "JMD" => parse_amount::<JMD, SAFE>(&amount, &currency)?,
-        "JOD" => parse_amount::<JOD, SAFE>(&amount, &currency)?,
-        "JPY" => parse_amount::<JPY, SAFE>(&amount, &currency)?,
-        "KES" => parse_amount::<KES, SAFE>(&amount, &currency)?,
-        "KGS" => parse_amount::<KGS, SAFE>(&amount, &currency)?,
-        "KHR" => parse_amount::<KHR, SAFE>(&amount, &currency)?,
-        "KMF" => parse_amount::<KMF, SAFE>(&amount, &currency)?,
-        "KPW" => parse_amount::<KPW, SAFE>(&amount, &currency)?,
-        "KRW" => parse_amount::<KRW, SAFE>(&amount, &currency)?,
-        "KWD" => parse_amount::<KWD, SAFE>(&amount, &currency)?,
-        "KYD" => parse_amount::<KYD, SAFE>(&amount, &currency)?,
-        "KZT" => parse_amount::<KZT, SAFE>(&amount, &currency)?,
-        "LAK" => parse_amount::<LAK, SAFE>(&amount, &currency)?,
-        "LBP" => parse_amount::<LBP, SAFE>(&amount, &currency)?,
-        "LKR" => parse_amount::<LKR, SAFE>(&amount, &currency)?,
-        "LRD" => parse_amount::<LRD, SAFE>(&amount, &currency)?,
-        "LSL" => parse_amount::<LSL, SAFE>(&amount, &currency)?,
-        "LYD" => parse_amount::<LYD, SAFE>(&amount, &currency)?,
-        "MAD" => parse_amount::<MAD, SAFE>(&amount, &currency)?,
-        "MDL" => parse_amount::<MDL, SAFE>(&amount, &currency)?,
-        "MGA" => parse_amount::<MGA, SAFE>(&amount, &currency)?,
-        "MKD" => parse_amount::<MKD, SAFE>(&amount, &currency)?,
-        "MMK" => parse_amount::<MMK, SAFE>(&amount, &currency)?,
-        "MNT" => parse_amount::<MNT, SAFE>(&amount, &currency)?,
-        "MOP" => parse_amount::<MOP, SAFE>(&amount, &currency)?,
-        "MRU" => parse_amount::<MRU, SAFE>(&amount, &currency)?,
-        "MUR" => parse_amount::<MUR, SAFE>(&amount, &currency)?,
-        "MVR" => parse_amount::<MVR, SAFE>(&amount, &currency)?,
-        "MWK" => parse_amount::<MWK, SAFE>(&amount, &currency)?,
-        "MXN" => parse_amount::<MXN, SAFE>(&amount, &currency)?,
-        "MXV" => parse_amount::<MXV, SAFE>(&amount, &currency)?,
-        "MYR" => parse_amount::<MYR, SAFE>(&amount, &currency)?,
-        "MZN" => parse_amount::<MZN, SAFE>(&amount, &currency)?,
-        "NAD" => parse_amount::<NAD, SAFE>(&amount, &currency)?,
-        "NGN" => parse_amount::<NGN, SAFE>(&amount, &currency)?,
-        "NIO" => parse_amount::<NIO, SAFE>(&amount, &currency)?,
-        "NOK" => parse_amount::<NOK, SAFE>(&amount, &currency)?,
-        "NPR" => parse_amount::<NPR, SAFE>(&amount, &currency)?,
-        "NZD" => parse_amount::<NZD, SAFE>(&amount, &currency)?,
-        "OMR" => parse_amount::<OMR, SAFE>(&amount, &currency)?,
-        "PAB" => parse_amount::<PAB, SAFE>(&amount, &currency)?,
-        "PEN" => parse_amount::<PEN, SAFE>(&amount, &currency)?,
-        "PGK" => parse_amount::<PGK, SAFE>(&amount, &currency)?,
-        "PHP" => parse_amount::<PHP, SAFE>(&amount, &currency)?,
-        "PKR" => parse_amount::<PKR, SAFE>(&amount, &currency)?,
-        "PLN" => parse_amount::<PLN, SAFE>(&amount, &currency)?,
-        "PYG" => parse_amount::<PYG, SAFE>(&amount, &currency)?,
-        "QAR" => parse_amount::<QAR, SAFE>(&amount, &currency)?,
-        "RON" => parse_amount::<RON, SAFE>(&amount, &currency)?,
-        "RSD" => parse_amount::<RSD, SAFE>(&amount, &currency)?,
-        "CNY" => parse_amount::<CNY, SAFE>(&amount, &currency)?,
-        "RUB" => parse_amount::<RUB, SAFE>(&amount, &currency)?,
-        "RWF" => parse_amount::<RWF, SAFE>(&amount, &currency)?,
-        "SAR" => parse_amount::<SAR, SAFE>(&amount, &currency)?,
-        "SBD" => parse_amount::<SBD, SAFE>(&amount, &currency)?,
-        "SCR" => parse_amount::<SCR, SAFE>(&amount, &currency)?,
-        "SDG" => parse_amount::<SDG, SAFE>(&amount, &currency)?,
-        "SEK" => parse_amount::<SEK, SAFE>(&amount, &currency)?,
-        "SGD" => parse_amount::<SGD, SAFE>(&amount, &currency)?,
-        "SHP" => parse_amount::<SHP, SAFE>(&amount, &currency)?,
-        "SLE" => parse_amount::<SLE, SAFE>(&amount, &currency)?,
-        "SOS" => parse_amount::<SOS, SAFE>(&amount, &currency)?,
-        "SRD" => parse_amount::<SRD, SAFE>(&amount, &currency)?,
-        "SSP" => parse_amount::<SSP, SAFE>(&amount, &currency)?,
-        "STN" => parse_amount::<STN, SAFE>(&amount, &currency)?,
-        "SYP" => parse_amount::<SYP, SAFE>(&amount, &currency)?,
-        "SZL" => parse_amount::<SZL, SAFE>(&amount, &currency)?,
-        "THB" => parse_amount::<THB, SAFE>(&amount, &currency)?,
-        "TJS" => parse_amount::<TJS, SAFE>(&amount, &currency)?,
-        "TMT" => parse_amount::<TMT, SAFE>(&amount, &currency)?,
-        "TND" => parse_amount::<TND, SAFE>(&amount, &currency)?,
-        "TOP" => parse_amount::<TOP, SAFE>(&amount, &currency)?,
-        "TRY" => parse_amount::<TRY, SAFE>(&amount, &currency)?,
-        "TTD" => parse_amount::<TTD, SAFE>(&amount, &currency)?,
-        "TWD" => parse_amount::<TWD, SAFE>(&amount, &currency)?,
-        "TZS" => parse_amount::<TZS, SAFE>(&amount, &currency)?,
-        "UAH" => parse_amount::<UAH, SAFE>(&amount, &currency)?,
-        "UGX" => parse_amount::<UGX, SAFE>(&amount, &currency)?,
-        "UYU" => parse_amount::<UYU, SAFE>(&amount, &currency)?,
-        "UZS" => parse_amount::<UZS, SAFE>(&amount, &currency)?,
-        "VED" => parse_amount::<VED, SAFE>(&amount, &currency)?,
-        "VES" => parse_amount::<VES, SAFE>(&amount, &currency)?,
-        "VND" => parse_amount::<VND, SAFE>(&amount, &currency)?,
-        "VUV" => parse_amount::<VUV, SAFE>(&amount, &currency)?,
-        "WST" => parse_amount::<WST, SAFE>(&amount, &currency)?,
-        "XAF" => parse_amount::<XAF, SAFE>(&amount, &currency)?,
-        "XAG" => parse_amount::<XAG, SAFE>(&amount, &currency)?,
-        "XAU" => parse_amount::<XAU, SAFE>(&amount, &currency)?,
-        "XCD" => parse_amount::<XCD, SAFE>(&amount, &currency)?,
-        "XOF" => parse_amount::<XOF, SAFE>(&amount, &currency)?,
-        "XPD" => parse_amount::<XPD, SAFE>(&amount, &currency)?,
-        "XPF" => parse_amount::<XPF, SAFE>(&amount, &currency)?,
-        "XPT" => parse_amount::<XPT, SAFE>(&amount, &currency)?,
-        "YER" => parse_amount::<YER, SAFE>(&amount, &currency)?,
-        "ZAR" => parse_amount::<ZAR, SAFE>(&amount, &currency)?,
-        "ZMW" => parse_amount::<ZMW, SAFE>(&amount, &currency)?,
-        _ => {
-            return Err(Error::new(
-                currency.span(),
-                "Unsupported currency. The `amt!` macro only works for types defined by the `currencies` crate.",
-            ))
-        }
+    Ok(parse_amount_const::<SAFE>(&amount, &currency))
+}
+
+/// Implements `Currency` for an existing type, configured by a `#[currency(...)]` attribute:
+///
+/// ```ignore
+/// #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Currency)]
+/// #[currency(code = "USDT", backing = u64, decimals = 6, symbol = "₮", style = SuffixSpaced, crypto)]
+/// pub struct Tether;
+/// ```
+///
+/// Only `decimals` is required, from which `BASE` is computed as `10^decimals`. The other
+/// options are:
+/// - `code`: the `CODE` of the currency, which defaults to the name of the type and must
+///   consist of ASCII uppercase letters and digits.
+/// - `backing`: the `Backing` type, which defaults to `u64`.
+/// - `symbol`: the `SYMBOL`, which defaults to the code.
+/// - `name`: the `PROPER_NAME`, which defaults to the code.
+/// - `style`: a `FormatStyle` variant such as `SuffixSpaced`, which defaults to
+///   `PrefixAttached`.
+/// - `iso` and `crypto`: flags setting `IS_ISO` and `IS_CRYPTO`. The code of an `iso`
+///   currency must be a known ISO-4217 code, whose numeric code becomes its `NUMERIC_CODE`.
+///
+/// Invalid options, including a number of decimals that the backing cannot hold, are
+/// reported as compile errors at the offending option.
+#[proc_macro_derive(Currency, attributes(currency))]
+pub fn derive_currency(input: TokenStream) -> TokenStream {
+    match derive_currency_internal(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// The options of a `#[currency(...)]` attribute, see [`derive_currency`].
+#[derive(Default)]
+struct CurrencyOptions {
+    code: Option<LitStr>,
+    backing: Option<Type>,
+    decimals: Option<LitInt>,
+    symbol: Option<LitStr>,
+    name: Option<LitStr>,
+    style: Option<Ident>,
+    iso: bool,
+    crypto: bool,
+}
+
+/// The variants of `FormatStyle`, which the `style` option must name.
+const FORMAT_STYLES: [&str; 4] = ["PrefixAttached", "SuffixAttached", "PrefixSpaced", "SuffixSpaced"];
+
+/// Returns the most decimal places that the backing named `backing` can hold, i.e. the
+/// largest `n` such that `10^n` fits in it, or [`None`] if it isn't a known backing.
+fn max_decimals(backing: &Type) -> Option<u32> {
+    let Type::Path(path) = backing else {
+        return None;
+    };
+    match path.path.segments.last()?.ident.to_string().as_str() {
+        "u32" => Some(9),
+        "i64" => Some(18),
+        "u64" => Some(19),
+        "i128" | "u128" => Some(38),
+        "U256" => Some(77),
+        _ => None,
+    }
+}
+
+fn is_u256(backing: &Type) -> bool {
+    matches!(backing, Type::Path(path) if path.path.segments.last().is_some_and(|segment| segment.ident == "U256"))
+}
+
+fn set_once<T>(slot: &mut Option<T>, value: T, meta: &syn::meta::ParseNestedMeta) -> Result<()> {
+    if slot.is_some() {
+        return Err(meta.error("duplicate currency option"));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+fn set_flag(flag: &mut bool, meta: &syn::meta::ParseNestedMeta) -> Result<()> {
+    if *flag {
+        return Err(meta.error("duplicate currency option"));
+    }
+    *flag = true;
+    Ok(())
+}
+
+fn parse_currency_options(input: &DeriveInput) -> Result<CurrencyOptions> {
+    let mut options = CurrencyOptions::default();
+    let mut found = false;
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("currency")) {
+        found = true;
+        attr.parse_nested_meta(|meta| {
+            let key = meta.path.get_ident().map(ToString::to_string).unwrap_or_default();
+            match key.as_str() {
+                "code" => set_once(&mut options.code, meta.value()?.parse()?, &meta),
+                "backing" => set_once(&mut options.backing, meta.value()?.parse()?, &meta),
+                "decimals" => set_once(&mut options.decimals, meta.value()?.parse()?, &meta),
+                "symbol" => set_once(&mut options.symbol, meta.value()?.parse()?, &meta),
+                "name" => set_once(&mut options.name, meta.value()?.parse()?, &meta),
+                "style" => set_once(&mut options.style, meta.value()?.parse()?, &meta),
+                "iso" => set_flag(&mut options.iso, &meta),
+                "crypto" => set_flag(&mut options.crypto, &meta),
+                _ => Err(meta
+                    .error("unknown currency option, expected one of `code`, `backing`, `decimals`, `symbol`, `name`, `style`, `iso` or `crypto`")),
+            }
+        })?;
+    }
+    if !found {
+        return Err(Error::new_spanned(&input.ident, "missing `#[currency(decimals = ...)]` attribute"));
+    }
+    Ok(options)
+}
+
+fn derive_currency_internal(tokens: impl Into<TokenStream2>) -> Result<TokenStream2> {
+    let input = parse2::<DeriveInput>(tokens.into())?;
+    let options = parse_currency_options(&input)?;
+    let krate = quote!(::currencies);
+
+    let code = options.code.unwrap_or_else(|| LitStr::new(&input.ident.to_string(), input.ident.span()));
+    let code_value = code.value();
+    if code_value.is_empty() || !code_value.bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit()) {
+        return Err(Error::new(
+            code.span(),
+            "currency codes must consist of ASCII uppercase letters and digits",
+        ));
+    }
+    if options.iso && !currencies_core::iso4217::is_iso_code(&code_value) {
+        return Err(Error::new(code.span(), format!("`{}` is not an ISO-4217 currency code", code_value)));
+    }
+    let symbol = options.symbol.unwrap_or_else(|| code.clone());
+    if symbol.value().is_empty() {
+        return Err(Error::new(symbol.span(), "currency symbols must not be empty"));
+    }
+    let name = options.name.unwrap_or_else(|| code.clone());
+    let style = options
+        .style
+        .unwrap_or_else(|| Ident::new("PrefixAttached", proc_macro2::Span::call_site()));
+    if !FORMAT_STYLES.iter().any(|variant| style == variant) {
+        return Err(Error::new(
+            style.span(),
+            format!("unknown format style, expected one of `{}`", FORMAT_STYLES.join("`, `")),
+        ));
+    }
+    let backing = options.backing.unwrap_or_else(|| syn::parse_quote!(u64));
+    let Some(decimals_lit) = options.decimals else {
+        return Err(Error::new_spanned(&input.ident, "missing required currency option `decimals`"));
     };
-    Ok(output)
+    let decimals: u32 = decimals_lit.base10_parse()?;
+    if let Some(max) = max_decimals(&backing).filter(|&max| decimals > max) {
+        return Err(Error::new(
+            decimals_lit.span(),
+            format!("`{}` can hold at most {} decimal places", quote!(#backing), max),
+        ));
+    }
+    let base = match is_u256(&backing) {
+        true => quote!(#krate::u256::u256_pow10(#decimals)),
+        false => quote!(<#backing>::pow(10, #decimals)),
+    };
+    let (iso, crypto) = (options.iso, options.crypto);
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #krate::Currency for #ident #ty_generics #where_clause {
+            type Backing = #backing;
+            const BASE: Self::Backing = #base;
+            const CODE: &'static str = #code;
+            const SYMBOL: &'static str = #symbol;
+            const PROPER_NAME: &'static str = #name;
+            const STYLE: #krate::currency::FormatStyle = #krate::currency::FormatStyle::#style;
+            const IS_ISO: bool = #iso;
+            const IS_CRYPTO: bool = #crypto;
+        }
+    })
+}
+
+/// Evaluates an arithmetic expression over amounts with checked operations, returning
+/// `Result<_, ArithmeticError>` instead of panicking or wrapping:
+///
+/// ```ignore
+/// let total = checked!(subtotal + item * 3 - discount)?;
+/// ```
+///
+/// Every `+`, `-`, `*`, `/`, `%` and unary `-` in the expression is rewritten into the
+/// corresponding method of the `TryAdd`, `TrySub`, `TryMul`, `TryDiv`, `TryRem` and `TryNeg`
+/// traits, respecting the usual operator precedence and parentheses. Any other expression,
+/// such as a variable, a method call or an `amt!` literal, is evaluated as is. If an operation
+/// overflows or divides by zero, the `ArithmeticError` names the subexpression that failed.
+/// Use `.ok()` on the result to get an `Option` instead.
+#[proc_macro]
+pub fn checked(input: TokenStream) -> TokenStream {
+    match checked_internal(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn checked_internal(tokens: impl Into<TokenStream2>) -> Result<TokenStream2> {
+    let expr = parse2::<Expr>(tokens.into())?;
+    rewrite_checked(&expr)
+}
+
+/// Rewrites `expr` into code evaluating to `Result<_, ArithmeticError>`, see [`checked`].
+fn rewrite_checked(expr: &Expr) -> Result<TokenStream2> {
+    let krate = quote!(::currencies);
+    let text = describe(quote!(#expr));
+    match expr {
+        Expr::Binary(binary) => {
+            let op = match binary.op {
+                BinOp::Add(_) => quote!(#krate::checked::TryAdd::try_add),
+                BinOp::Sub(_) => quote!(#krate::checked::TrySub::try_sub),
+                BinOp::Mul(_) => quote!(#krate::checked::TryMul::try_mul),
+                BinOp::Div(_) => quote!(#krate::checked::TryDiv::try_div),
+                BinOp::Rem(_) => quote!(#krate::checked::TryRem::try_rem),
+                op => {
+                    return Err(Error::new_spanned(
+                        op,
+                        "unsupported operator, `checked!` only supports `+`, `-`, `*`, `/`, `%` and unary `-`",
+                    ))
+                }
+            };
+            let lhs = rewrite_checked(&binary.left)?;
+            let rhs = rewrite_checked(&binary.right)?;
+            Ok(quote!(#krate::checked::binary(#lhs, #rhs, #op, #text)))
+        }
+        // negative literals are scalars, not negated amounts
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) && !matches!(*unary.expr, Expr::Lit(_)) => {
+            let value = rewrite_checked(&unary.expr)?;
+            Ok(quote!(#krate::checked::unary(#value, #krate::checked::TryNeg::try_neg, #text)))
+        }
+        Expr::Paren(paren) => rewrite_checked(&paren.expr),
+        Expr::Group(group) => rewrite_checked(&group.expr),
+        _ => Ok(quote!(::core::result::Result::<_, #krate::checked::ArithmeticError>::Ok(#expr))),
+    }
+}
+
+/// Renders `tokens` as source text for error messages, spacing them the way `rustfmt` would
+/// in simple expressions, e.g. `amt!(USD, "$1.00") * -2` rather than the
+/// `amt ! (USD , "$1.00") * - 2` of [`TokenStream2`]'s `Display` impl.
+fn describe(tokens: TokenStream2) -> String {
+    let mut text = String::new();
+    write_tokens(&mut text, tokens);
+    text
+}
+
+fn write_tokens(text: &mut String, tokens: TokenStream2) {
+    // whether the previous token ends an operand, e.g. making a following `-` binary
+    let mut operand = false;
+    // whether a space may separate the previous token from the next one
+    let mut space = false;
+    for token in tokens {
+        let (space_before, space_after) = match &token {
+            TokenTree::Punct(punct) => match punct.as_char() {
+                ',' | ';' | '?' => (false, true),
+                '.' | ':' => (false, false),
+                '!' if operand => (false, false),
+                '-' | '!' | '&' | '*' if !operand => (true, false),
+                _ => (true, punct.spacing() == Spacing::Alone),
+            },
+            TokenTree::Group(group) => match group.delimiter() {
+                Delimiter::Parenthesis | Delimiter::Bracket => (!operand, true),
+                _ => (true, true),
+            },
+            _ => (true, true),
+        };
+        if space && space_before {
+            text.push(' ');
+        }
+        match &token {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{ ", " }"),
+                    Delimiter::None => ("", ""),
+                };
+                text.push_str(open);
+                write_tokens(text, group.stream());
+                text.push_str(close);
+            }
+            token => text.push_str(&token.to_string()),
+        }
+        operand = match &token {
+            TokenTree::Punct(punct) => punct.as_char() == '?' || (punct.as_char() == '!' && operand),
+            _ => true,
+        };
+        space = space_after;
+    }
+}
+
+#[test]
+fn test_amount_literals() {
+    let literal = parse2::<AmountLiteral>(quote!(3.24)).unwrap();
+    assert_eq!((literal.text.value(), literal.numeric), ("3.24".to_string(), true));
+    let literal = parse2::<AmountLiteral>(quote!(-1_000)).unwrap();
+    assert_eq!((literal.text.value(), literal.numeric), ("-1000".to_string(), true));
+    let literal = parse2::<AmountLiteral>(quote!("3.24 USD")).unwrap();
+    assert_eq!((literal.text.value(), literal.numeric), ("3.24 USD".to_string(), false));
+    let err = parse2::<AmountLiteral>(quote!(3.24f64)).map(|_| ()).unwrap_err();
+    assert_eq!(err.to_string(), "numeric amounts must not have a type suffix");
+    assert!(parse2::<AmountLiteral>(quote!(true)).is_err());
+
+    let output = amt_internal::<false>(quote!(USD, 3.24)).unwrap().to_string();
+    assert!(output.contains("FromLiteral < USD , Text >"), "{}", output);
+    assert!(output.contains("Amount :: < USD , :: currencies :: safety :: Unchecked >"), "{}", output);
+    let output = amt_internal::<true>(quote!(ETH, "1.5 ETH")).unwrap().to_string();
+    assert!(output.contains("FromLiteral < ETH , Text >"), "{}", output);
+    assert!(output.contains("Amount :: < ETH , :: currencies :: safety :: Checked >"), "{}", output);
+}
+
+#[cfg(test)]
+fn derive_error(input: TokenStream2) -> String {
+    derive_currency_internal(input).unwrap_err().to_string()
+}
+
+#[test]
+fn test_derive_currency_options() {
+    let output = derive_currency_internal(quote! {
+        #[currency(code = "USDT", backing = u64, decimals = 6, symbol = "₮", style = SuffixSpaced, crypto)]
+        struct Tether;
+    })
+    .unwrap()
+    .to_string();
+    assert!(output.contains("const BASE : Self :: Backing = < u64 > :: pow (10 , 6u32)"), "{}", output);
+    assert!(output.contains("const CODE : & 'static str = \"USDT\""), "{}", output);
+    assert!(output.contains("FormatStyle :: SuffixSpaced"), "{}", output);
+    assert!(output.contains("const IS_CRYPTO : bool = true"), "{}", output);
+
+    let output = derive_currency_internal(quote!(
+        #[currency(decimals = 18, backing = U256)]
+        struct WEI;
+    ))
+    .unwrap()
+    .to_string();
+    assert!(output.contains("u256_pow10 (18u32)"), "{}", output);
+}
+
+#[test]
+fn test_derive_currency_errors() {
+    assert_eq!(
+        derive_error(quote!(
+            struct Tether;
+        )),
+        "missing `#[currency(decimals = ...)]` attribute"
+    );
+    assert_eq!(
+        derive_error(quote!(
+            #[currency(code = "USDT")]
+            struct Tether;
+        )),
+        "missing required currency option `decimals`"
+    );
+    assert_eq!(
+        derive_error(quote!(
+            #[currency(decimals = 2)]
+            struct Tether;
+        )),
+        "currency codes must consist of ASCII uppercase letters and digits"
+    );
+    assert_eq!(
+        derive_error(quote!(
+            #[currency(decimals = 2, code = "usd-t")]
+            struct Tether;
+        )),
+        "currency codes must consist of ASCII uppercase letters and digits"
+    );
+    assert_eq!(
+        derive_error(quote!(
+            #[currency(decimals = 2, decimals = 3)]
+            struct USDT;
+        )),
+        "duplicate currency option"
+    );
+    assert_eq!(
+        derive_error(quote!(
+            #[currency(decimals = 2, crypto, crypto)]
+            struct USDT;
+        )),
+        "duplicate currency option"
+    );
+    assert!(derive_error(quote!(
+        #[currency(decimals = 2, colour = "red")]
+        struct USDT;
+    ))
+    .starts_with("unknown currency option"));
+    assert!(derive_error(quote!(
+        #[currency(decimals = 2, style = Sideways)]
+        struct USDT;
+    ))
+    .starts_with("unknown format style"));
+    assert_eq!(
+        derive_error(quote!(
+            #[currency(decimals = 2, symbol = "")]
+            struct USDT;
+        )),
+        "currency symbols must not be empty"
+    );
+    assert_eq!(
+        derive_error(quote!(
+            #[currency(decimals = 10, backing = u32)]
+            struct USDT;
+        )),
+        "`u32` can hold at most 9 decimal places"
+    );
+    assert_eq!(
+        derive_error(quote!(
+            #[currency(decimals = 78, backing = U256)]
+            struct USDT;
+        )),
+        "`U256` can hold at most 77 decimal places"
+    );
+}
+
+#[test]
+fn test_describe() {
+    assert_eq!(describe(quote!(a + b * 3 - fee)), "a + b * 3 - fee");
+    assert_eq!(describe(quote!(amt!(USD, "$1.00") * -2)), "amt!(USD, \"$1.00\") * -2");
+    assert_eq!(describe(quote!(-(a - b) / order.items[0].price())), "-(a - b) / order.items[0].price()");
+    assert_eq!(
+        describe(quote!(::currencies::Amount::from_raw(5) % x?)),
+        "::currencies::Amount::from_raw(5) % x?"
+    );
+}
+
+#[test]
+fn test_checked_rewrites_operators() {
+    let output = checked_internal(quote!(a + b * 3)).unwrap().to_string();
+    // the operands of the outer sum are evaluated first, so the product comes first
+    let sum = output.find("TryAdd").unwrap();
+    let product = output.find("TryMul").unwrap();
+    assert!(product < sum, "{}", output);
+    assert!(output.contains("\"a + b * 3\"") && output.contains("\"b * 3\""), "{}", output);
+    let output = checked_internal(quote!(-(a) * -3)).unwrap().to_string();
+    assert_eq!(output.matches("TryNeg").count(), 1, "{}", output);
+    let err = checked_internal(quote!(a + (b << 2))).unwrap_err();
+    assert!(err.to_string().starts_with("unsupported operator"));
+    assert!(checked_internal(quote!(a+)).is_err());
 }
@@ -30,6 +30,22 @@
 //! assert_eq!(format!("{}", total), "115244897682.0000000008 DOT");
 //!```
 //!
+//! ### Constants and literal forms
+//! [`amt!`] only expands to `const` code, so it can declare constants of any currency backed
+//! by `u32`, `u64`, `u128`, `i64`, `i128` or [`U256`]. Besides the format produced by
+//! [`core::fmt::Display`], it accepts a number followed by the currency code, and numeric
+//! literals.
+//! ```
+//! use currencies::{*, currency::*};
+//!
+//! const FEE: Amount<USD> = amt!(USD, "$0.30");
+//! const GAS: Amount<ETH> = amt!(ETH, "0.000021 ETH");
+//!
+//! assert_eq!(amt!(USD, "3.24 USD"), amt!(USD, "$3.24"));
+//! assert_eq!(amt!(USD, 3.24) - FEE, amt!(USD, 2.94));
+//! assert_eq!(GAS.raw_backing(), U256::from(21_000000000000u64));
+//! ```
+//!
 //! ### Checked Math
 //! ```
 //! use currencies::{*, currency::*, safety::*};
@@ -42,10 +58,69 @@
 //! let drink_cost = amt_checked!(USD, "$6.29");
 //! let movie_cost = Amount::<USD, Checked>::from_raw(24_99);
 //! let Some(outing_cost) = drink_cost + movie_cost else {
-//! 	unimplemented!("compiler forces you to handle this!")
+//!     unimplemented!("compiler forces you to handle this!")
 //! };
 //! assert_eq!(format!("{}", outing_cost), "$31.28");
 //! ```
+//!
+//! Longer expressions can be written with [`checked!`], which rewrites each operator into its
+//! checked counterpart and reports the subexpression that failed, if any.
+//! ```
+//! use currencies::{*, currency::*};
+//!
+//! let ticket = amt_checked!(USD, "$12.50");
+//! let discount = amt_checked!(USD, "$5.00");
+//! assert_eq!(checked!(ticket * 3 - discount), Ok(amt_checked!(USD, "$32.50")));
+//!
+//! let error = checked!(ticket - discount * 3).unwrap_err();
+//! assert_eq!(error.expression(), "ticket - discount * 3");
+//! ```
+//!
+//! ### Currency sets
+//! [`currency_set!`] declares an enum of amounts restricted to a closed set of currencies, which
+//! converts from and to their [`Amount`]s, and with the `parsing` and `serde` features only
+//! parses and deserializes amounts of those currencies.
+//! ```
+//! use currencies::{*, currency::*};
+//!
+//! currency_set!(pub Settlement = USD | EUR | GBP);
+//!
+//! let amount = Settlement::from(amt!(EUR, "12.50€"));
+//! let fee: Settlement = match amount {
+//!     Settlement::USD(_) => amt!(USD, "$0.30").into(),
+//!     Settlement::EUR(_) => amt!(EUR, "0.25€").into(),
+//!     Settlement::GBP(_) => amt!(GBP, "£0.20").into(),
+//! };
+//! assert_eq!(Amount::<EUR>::try_from(fee), Ok(amt!(EUR, "0.25€")));
+//! assert_eq!(Amount::<USD>::try_from(amount), Err(ConversionError::CurrencyMismatch));
+//! assert_eq!(Settlement::CODES, ["USD", "EUR", "GBP"]);
+//! ```
+//!
+//! ### Custom currencies
+//! [`amt!`] and [`amt_checked!`] also accept currencies defined outside of this crate. Their
+//! literals are parsed in a constant, so an invalid one is still a compile error.
+//! ```
+//! use currencies::{*, currency::*};
+//!
+//! define_currency!(GEM, i64, 1_000, "gem", "Gem", SuffixSpaced, false, false);
+//!
+//! const PRICE: Amount<GEM> = amt!(GEM, "1.250 gem");
+//! assert_eq!(PRICE.raw_backing(), 1_250);
+//! assert_eq!(format!("{}", amt!(GEM, "-0.5 gem")), "-0.500 gem");
+//! ```
+//!
+//! Existing types can become currencies via `#[derive(Currency)]`, which is configured with a
+//! `#[currency(...)]` attribute and computes [`Currency::BASE`] from a number of decimals.
+//! ```
+//! use currencies::{*, currency::*};
+//!
+//! #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Currency)]
+//! #[currency(code = "USDT", decimals = 6, symbol = "₮", style = SuffixSpaced, crypto)]
+//! pub struct Tether;
+//!
+//! assert_eq!(Tether::BASE, 1_000000);
+//! assert_eq!(format!("{}", amt!(Tether, "2.5 ₮")), "2.500000 ₮");
+//! ```
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
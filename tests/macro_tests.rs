@@ -27,3 +27,175 @@ fn test_amt_btc_unchecked() {
     let amount = amt!(BTC, "0.00000001 BTC");
     assert_eq!(format!("{}", amount), "0.00000001 BTC");
 }
+
+currencies::define_currency!(GEM, i64, 1_000, "gem", "Gem", SuffixSpaced, false, false);
+currencies::define_currency!(CREDIT, u32, 1_00, "₢", "Credit", PrefixSpaced, false, false);
+currencies::define_currency!(
+    WEI256,
+    currencies::U256,
+    currencies::u256::u64_to_u256(1_000000000000000000),
+    "Ξ",
+    "Ether",
+    PrefixAttached,
+    false,
+    true
+);
+
+#[test]
+fn test_amt_custom_currency() {
+    let amount = amt!(GEM, "1.250 gem");
+    assert_eq!(amount, Amount::<GEM>::from_raw(1_250));
+    assert_eq!(amt!(GEM, "(1,000.5 GEM)"), Amount::from_raw(-1_000_500));
+    assert_eq!(amt!(GEM, "-9223372036854775.808 gem"), Amount::from_raw(i64::MIN));
+    assert_eq!(amt!(CREDIT, "₢ 42949672.95"), Amount::from_raw(u32::MAX));
+    assert_eq!(amt!(WEI256, "Ξ0.000000000000000001"), Amount::from_raw(currencies::U256::from(1u8)));
+    assert_eq!(format!("{}", amt!(WEI256, "Ξ1.5")), "Ξ1.500000000000000000");
+}
+
+#[test]
+fn test_amt_checked_custom_currency() {
+    let amount: Amount<GEM, Checked> = amt_checked!(GEM, "12.345 gem");
+    assert_eq!(format!("{}", amount), "12.345 gem");
+    let Some(total) = amount + amt_checked!(GEM, "0.655 gem") else {
+        unreachable!()
+    };
+    assert_eq!(total, Amount::from_raw(13_000));
+}
+
+#[test]
+fn test_amt_custom_currency_const() {
+    const FEE: Amount<CREDIT> = amt!(CREDIT, "₢ 0.25");
+    const LIMIT: Amount<GEM, Checked> = amt_checked!(GEM, "5.0 gem");
+    assert_eq!(FEE.raw_backing(), 25);
+    assert_eq!(LIMIT.raw_backing(), 5_000);
+}
+
+#[test]
+fn test_amt_uses_each_currency_precision() {
+    assert_eq!(amt!(ALGO, "1.000001 ALGO"), Amount::<ALGO>::from_raw(1_000001));
+    assert_eq!(amt!(TAO, "2.5 TAO"), Amount::<TAO>::from_raw(2_500000000));
+    assert_eq!(amt!(MATIC, "0.000000000000000001 MATIC"), Amount::<MATIC>::from_raw(1));
+    assert_eq!(amt!(XLM, "1.0000001 XLM"), Amount::<XLM>::from_raw(1_0000001));
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, currencies::Currency)]
+#[currency(code = "USDT", backing = u64, decimals = 6, symbol = "₮", style = SuffixSpaced, crypto)]
+struct Tether;
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, currencies::Currency)]
+#[currency(code = "WETH", backing = currencies::U256, decimals = 18, name = "Wrapped Ether", symbol = "WETH", style = SuffixSpaced)]
+enum WrappedEther {}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, currencies::Currency)]
+#[currency(code = "XTS", decimals = 0, iso)]
+struct Sandbox;
+
+#[test]
+fn test_derive_currency() {
+    use currencies::Currency;
+
+    assert_eq!(Tether::CODE, "USDT");
+    assert_eq!(Tether::BASE, 1_000000);
+    assert_eq!(Tether::SYMBOL, "₮");
+    assert_eq!(Tether::PROPER_NAME, "USDT");
+    assert_eq!((Tether::IS_ISO, Tether::IS_CRYPTO), (false, true));
+    assert_eq!(format!("{}", Amount::<Tether>::from_raw(1_500000)), "1.500000 ₮");
+    assert_eq!(amt!(Tether, "2.25 ₮"), Amount::<Tether>::from_raw(2_250000));
+
+    assert_eq!(WrappedEther::BASE, ETH::BASE);
+    assert_eq!(WrappedEther::PROPER_NAME, "Wrapped Ether");
+    assert_eq!(WrappedEther::decimal_digits(), 18);
+
+    assert_eq!(Sandbox::BASE, 1);
+    assert_eq!(Sandbox::SYMBOL, "XTS");
+    assert_eq!((Sandbox::IS_ISO, Sandbox::IS_CRYPTO), (true, false));
+    assert_eq!((Sandbox::NUMERIC_CODE, Tether::NUMERIC_CODE), (Some(963), None));
+    assert_eq!(format!("{}", amt!(Sandbox, "XTS12")), "XTS12");
+}
+
+#[test]
+fn test_amt_shadowing_builtin_currency() {
+    use currencies::Currency;
+
+    #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, currencies::Currency)]
+    #[currency(code = "ETH", decimals = 2, style = SuffixSpaced)]
+    struct Eth;
+
+    assert_eq!(Eth::BASE, 1_00);
+    assert_eq!(amt!(Eth, "1.50 ETH"), Amount::<Eth>::from_raw(1_50));
+    assert_eq!(amt_checked!(Eth, 2), Amount::<Eth, Checked>::from_raw(2_00));
+
+    // amounts the built-in JPY, which has no decimal digits, would reject
+    #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, currencies::Currency)]
+    #[currency(code = "JPY", decimals = 2, symbol = "¥", style = SuffixAttached)]
+    struct Jpy;
+
+    assert_eq!(amt!(Jpy, 1.50), Amount::<Jpy>::from_raw(1_50));
+    assert_eq!(amt!(Jpy, "0.25¥"), Amount::<Jpy>::from_raw(25));
+    assert_eq!(amt!(Jpy, "3.01 JPY"), Amount::<Jpy>::from_raw(3_01));
+}
+
+#[test]
+fn test_checked_expression() {
+    use currencies::{checked, ArithmeticError};
+
+    let a = amt_checked!(USD, "$10.00");
+    let b = amt_checked!(USD, "$2.50");
+    let fee = amt_checked!(USD, "$0.75");
+    assert_eq!(checked!(a + b * 3 - fee), Ok(amt_checked!(USD, "$16.75")));
+    assert_eq!(checked!((a + b) * 2), Ok(amt_checked!(USD, "$25.00")));
+    assert_eq!(checked!(a * b), Ok(amt_checked!(USD, "$25.00")));
+    assert_eq!(checked!(a / b), Ok(4));
+    assert_eq!(checked!(a % b + fee / 3), Ok(amt_checked!(USD, "$0.25")));
+    assert_eq!(checked!(3u8 * a - amt_checked!(USD, "$5.00")), Ok(amt_checked!(USD, "$25.00")));
+
+    let error = checked!(a + b * 3 - (fee - a)).unwrap_err();
+    assert_eq!(error, ArithmeticError::new("fee - a"));
+    let error = checked!(a + b * u64::MAX).unwrap_err();
+    assert_eq!(error.expression(), "b * u64::MAX");
+    assert_eq!(checked!(a / (fee - fee)).unwrap_err().expression(), "a / (fee - fee)");
+    assert_eq!(checked!(b * -1).unwrap_err().expression(), "b * -1");
+    assert_eq!(checked!(a - b * 5).ok(), None);
+
+    let gem = amt!(GEM, "1.500 gem");
+    assert_eq!(checked!(-gem * 2 + gem), Ok(amt!(GEM, "-1.500 gem")));
+    assert_eq!(checked!(-(gem - gem)), Ok(amt!(GEM, "0.0 gem")));
+}
+
+const FEE: Amount<USD> = amt!(USD, "$0.30");
+const STAKE: Amount<DOT> = amt!(DOT, "1.5 DOT");
+const GAS: Amount<ETH, Checked> = amt_checked!(ETH, "0.000021 ETH");
+const MAX_ETH: Amount<ETH> = amt!(ETH, 115792089237316195423570985008687907853269984665640564039457.584007913129639935);
+const BONUS: Amount<GEM> = amt!(GEM, -2.5);
+
+#[test]
+fn test_amt_in_constants() {
+    assert_eq!(FEE, Amount::from_raw(30));
+    assert_eq!(STAKE, Amount::from_raw(1_5000000000));
+    assert_eq!(GAS, Amount::from_raw(currencies::U256::from(21_000000000000u64)));
+    assert_eq!(MAX_ETH, Amount::from_raw(currencies::U256::MAX_VALUE));
+    assert_eq!(BONUS, Amount::from_raw(-2_500));
+}
+
+#[test]
+fn test_amt_numeric_literals() {
+    assert_eq!(amt!(USD, 3.24), amt!(USD, "$3.24"));
+    assert_eq!(amt!(USD, 3), amt!(USD, "$3.00"));
+    assert_eq!(amt!(USD, 1_000.5), amt!(USD, "$1,000.50"));
+    assert_eq!(amt!(BTC, 0.00000001), Amount::<BTC>::from_raw(1));
+    assert_eq!(amt!(ETH, 1.5), amt!(ETH, "1.5 ETH"));
+    assert_eq!(amt_checked!(TAO, 2.5), Amount::<TAO, Checked>::from_raw(2_500000000));
+    assert_eq!(amt!(GEM, 12.345), Amount::<GEM>::from_raw(12_345));
+    assert_eq!(amt!(CREDIT, 42949672.95), Amount::<CREDIT>::from_raw(u32::MAX));
+    assert_eq!(amt!(WEI256, 0.000000000000000001), Amount::from_raw(currencies::U256::from(1u8)));
+    assert_eq!(amt!(Tether, 2.25), Amount::<Tether>::from_raw(2_250000));
+}
+
+#[test]
+fn test_amt_code_suffixed_literals() {
+    assert_eq!(amt!(USD, "3.24 USD"), amt!(USD, "$3.24"));
+    assert_eq!(amt!(EUR, "1,000.50 eur"), amt!(EUR, "1,000.50€"));
+    assert_eq!(amt!(GEM, "-1.5 GEM"), Amount::<GEM>::from_raw(-1_500));
+    assert_eq!(amt!(CREDIT, "3 CREDIT"), Amount::<CREDIT>::from_raw(3_00));
+    assert_eq!(amt!(Tether, "2.5 USDT"), amt!(Tether, "2.5 ₮"));
+}